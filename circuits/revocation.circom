@@ -0,0 +1,35 @@
+pragma circom 2.0.0;
+
+include "node_modules/circomlib/circuits/smt/smtverifier.circom";
+
+// Non-revocation proof against a DAO's revocation tree (membership-tree
+// `enable_strict_revocation`). The tree is a circomlib sparse Merkle tree keyed by
+// commitment: leaves are Poseidon(commitment, 1, 1), branches Poseidon(left, right),
+// and a commitment's path follows its bits from the least significant one.
+//
+// Witness from the tree contract's `revocation_proof(dao_id, commitment)`:
+// - siblings: the returned siblings (already zero-padded to nLevels)
+// - oldKey/isOld0: the returned leaf commitment, or 0 and isOld0 = 1 when the path
+//   ends at an empty subtree
+//
+// Fails for a revoked commitment, since the only leaf on its path is its own.
+template NonRevocation(nLevels) {
+    signal input revocationRoot;
+    signal input commitment;
+    signal input siblings[nLevels];
+    signal input oldKey;
+    signal input isOld0;
+
+    component smt = SMTVerifier(nLevels);
+    smt.enabled <== 1;
+    smt.fnc <== 1;              // 1 = exclusion proof
+    smt.root <== revocationRoot;
+    for (var i = 0; i < nLevels; i++) {
+        smt.siblings[i] <== siblings[i];
+    }
+    smt.oldKey <== oldKey;
+    smt.oldValue <== 1;         // Every revoked leaf carries value 1
+    smt.isOld0 <== isOld0;
+    smt.key <== commitment;
+    smt.value <== 1;
+}
@@ -0,0 +1,7 @@
+pragma circom 2.0.0;
+
+include "vote_core.circom";
+
+// Tree depth of 24 (supports ~16.7M members)
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice] - 5 signals
+component main {public [root, nullifier, daoId, proposalId, voteChoice]} = Vote(24);
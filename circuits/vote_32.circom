@@ -0,0 +1,7 @@
+pragma circom 2.0.0;
+
+include "vote_core.circom";
+
+// Tree depth of 32 (supports ~4.3B members)
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice] - 5 signals
+component main {public [root, nullifier, daoId, proposalId, voteChoice]} = Vote(32);
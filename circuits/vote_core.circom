@@ -0,0 +1,245 @@
+pragma circom 2.0.0;
+
+include "node_modules/circomlib/circuits/poseidon.circom";
+include "node_modules/circomlib/circuits/comparators.circom";
+include "merkle_tree.circom";
+include "revocation.circom";
+
+// DaoVote Anonymous Vote Circuit (template only)
+//
+// Main components live in vote.circom (depth 18) and vote_<depth>.circom for larger
+// trees. Each depth needs its own trusted setup and VK, registered on-chain with
+// set_depth_vk before growing a tree to that depth.
+//
+// Proves:
+// 1. Voter knows secret & salt that hash to a commitment (leaf) in the Merkle tree
+// 2. Nullifier is correctly derived from secret, daoId, and proposalId (domain-separated)
+// 3. Vote choice is binary (0 or 1)
+//
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice]
+// Private signals: secret, salt, pathElements, pathIndices
+//
+// PRIVACY: Commitment is NOT exposed publicly. Votes are fully unlinkable across proposals.
+// Revocation is enforced via Merkle tree updates (zeroing leaves) rather than on-chain checks.
+template Vote(levels) {
+    // Public inputs
+    signal input root;              // Merkle tree root (verified on-chain)
+    signal input nullifier;         // Prevents double voting (domain-separated)
+    signal input daoId;             // DAO identifier (for domain separation)
+    signal input proposalId;        // Which proposal this vote is for
+    signal input voteChoice;        // 0 = against, 1 = for
+
+    // Private inputs
+    signal input secret;            // Voter's secret (like password)
+    signal input salt;              // Random salt for commitment
+    signal input pathElements[levels];  // Merkle proof siblings
+    signal input pathIndices[levels];   // Merkle proof path (0=left, 1=right)
+
+    // 1. Compute identity commitment: Poseidon(secret, salt)
+    // This is used as the leaf in the Merkle tree
+    component commitmentHasher = Poseidon(2);
+    commitmentHasher.inputs[0] <== secret;
+    commitmentHasher.inputs[1] <== salt;
+
+    // Commitment is computed internally (private) - not exposed as public signal
+    signal commitment;
+    commitment <== commitmentHasher.out;
+
+    // 2. Verify Merkle tree inclusion
+    component merkleProof = MerkleTreeInclusionProof(levels);
+    merkleProof.leaf <== commitment;
+    for (var i = 0; i < levels; i++) {
+        merkleProof.pathElements[i] <== pathElements[i];
+        merkleProof.pathIndices[i] <== pathIndices[i];
+    }
+
+    // Constrain computed root to match public root
+    root === merkleProof.root;
+
+    // 3. Compute nullifier: Poseidon(secret, daoId, proposalId)
+    // Domain separation: includes daoId to prevent cross-DAO nullifier linkability
+    // This ensures a voter can't be linked across DAOs even if reusing the same secret
+    component nullifierHasher = Poseidon(3);
+    nullifierHasher.inputs[0] <== secret;
+    nullifierHasher.inputs[1] <== daoId;
+    nullifierHasher.inputs[2] <== proposalId;
+
+    // Constrain computed nullifier to match public nullifier
+    nullifier === nullifierHasher.out;
+
+    // 4. Verify vote choice is binary (0 or 1)
+    voteChoice * (voteChoice - 1) === 0;
+}
+
+// Vote circuit for DAOs whose tree leaves commit to a membership expiry
+// (membership-tree `init_expiring_tree`). Main component lives in vote_expiry.circom.
+//
+// Proves everything Vote does, with leaf = Poseidon(Poseidon(secret, salt), expiresAt),
+// plus that the membership had not lapsed when the proposal was created:
+// snapshotTime < expiresAt (the SBT contract treats now >= expiresAt as expired).
+// Permanent members carry expiresAt = 2^64 - 1.
+//
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice, snapshotTime]
+// Private signals: secret, salt, expiresAt, pathElements, pathIndices
+//
+// The expiry stays private; only the proposal's creation time is public, so a vote
+// reveals nothing about when the voter's membership ends.
+template ExpiringVote(levels) {
+    // Public inputs
+    signal input root;              // Merkle tree root (verified on-chain)
+    signal input nullifier;         // Prevents double voting (domain-separated)
+    signal input daoId;             // DAO identifier (for domain separation)
+    signal input proposalId;        // Which proposal this vote is for
+    signal input voteChoice;        // 0 = against, 1 = for
+    signal input snapshotTime;      // Proposal creation time (supplied by the contract)
+
+    // Private inputs
+    signal input secret;            // Voter's secret (like password)
+    signal input salt;              // Random salt for commitment
+    signal input expiresAt;         // Expiry baked into the leaf by the tree contract
+    signal input pathElements[levels];  // Merkle proof siblings
+    signal input pathIndices[levels];   // Merkle proof path (0=left, 1=right)
+
+    // 1. Leaf = Poseidon(Poseidon(secret, salt), expiresAt)
+    component commitmentHasher = Poseidon(2);
+    commitmentHasher.inputs[0] <== secret;
+    commitmentHasher.inputs[1] <== salt;
+
+    component leafHasher = Poseidon(2);
+    leafHasher.inputs[0] <== commitmentHasher.out;
+    leafHasher.inputs[1] <== expiresAt;
+
+    // 2. Verify Merkle tree inclusion
+    component merkleProof = MerkleTreeInclusionProof(levels);
+    merkleProof.leaf <== leafHasher.out;
+    for (var i = 0; i < levels; i++) {
+        merkleProof.pathElements[i] <== pathElements[i];
+        merkleProof.pathIndices[i] <== pathIndices[i];
+    }
+    root === merkleProof.root;
+
+    // 3. Nullifier: Poseidon(secret, daoId, proposalId), same as Vote
+    component nullifierHasher = Poseidon(3);
+    nullifierHasher.inputs[0] <== secret;
+    nullifierHasher.inputs[1] <== daoId;
+    nullifierHasher.inputs[2] <== proposalId;
+    nullifier === nullifierHasher.out;
+
+    // 4. Verify vote choice is binary (0 or 1)
+    voteChoice * (voteChoice - 1) === 0;
+
+    // 5. Membership unexpired at the snapshot: snapshotTime < expiresAt
+    // Both are u64 timestamps; range-check them so LessThan(64) is sound
+    component snapshotBits = Num2Bits(64);
+    snapshotBits.in <== snapshotTime;
+    component expiryBits = Num2Bits(64);
+    expiryBits.in <== expiresAt;
+
+    component unexpired = LessThan(64);
+    unexpired.in[0] <== snapshotTime;
+    unexpired.in[1] <== expiresAt;
+    unexpired.out === 1;
+}
+
+// Vote circuit for DAOs with strict revocation (membership-tree
+// `enable_strict_revocation`). Main component lives in vote_strict.circom.
+//
+// Proves everything Vote does, plus that Poseidon(secret, salt) is not in the DAO's
+// revocation tree (see revocation.circom). The contract supplies the tree's current
+// root at vote time, so a member removed after the proposal's snapshot can no longer
+// vote even though their leaf is still in the snapshot root.
+//
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice, revocationRoot]
+template StrictVote(levels, revocationLevels) {
+    signal input root;
+    signal input nullifier;
+    signal input daoId;
+    signal input proposalId;
+    signal input voteChoice;
+    signal input revocationRoot;    // Revocation tree root (supplied by the contract)
+
+    signal input secret;
+    signal input salt;
+    signal input pathElements[levels];
+    signal input pathIndices[levels];
+    signal input revocationSiblings[revocationLevels];
+    signal input revocationOldKey;
+    signal input revocationIsOld0;
+
+    component vote = Vote(levels);
+    vote.root <== root;
+    vote.nullifier <== nullifier;
+    vote.daoId <== daoId;
+    vote.proposalId <== proposalId;
+    vote.voteChoice <== voteChoice;
+    vote.secret <== secret;
+    vote.salt <== salt;
+    for (var i = 0; i < levels; i++) {
+        vote.pathElements[i] <== pathElements[i];
+        vote.pathIndices[i] <== pathIndices[i];
+    }
+
+    component commitmentHasher = Poseidon(2);
+    commitmentHasher.inputs[0] <== secret;
+    commitmentHasher.inputs[1] <== salt;
+
+    component nonRevoked = NonRevocation(revocationLevels);
+    nonRevoked.revocationRoot <== revocationRoot;
+    nonRevoked.commitment <== commitmentHasher.out;
+    for (var i = 0; i < revocationLevels; i++) {
+        nonRevoked.siblings[i] <== revocationSiblings[i];
+    }
+    nonRevoked.oldKey <== revocationOldKey;
+    nonRevoked.isOld0 <== revocationIsOld0;
+}
+
+// ExpiringVote with strict revocation. Main component lives in vote_expiry_strict.circom.
+// The revocation tree is keyed by the commitment behind the leaf, Poseidon(secret, salt).
+//
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice, snapshotTime, revocationRoot]
+template StrictExpiringVote(levels, revocationLevels) {
+    signal input root;
+    signal input nullifier;
+    signal input daoId;
+    signal input proposalId;
+    signal input voteChoice;
+    signal input snapshotTime;
+    signal input revocationRoot;
+
+    signal input secret;
+    signal input salt;
+    signal input expiresAt;
+    signal input pathElements[levels];
+    signal input pathIndices[levels];
+    signal input revocationSiblings[revocationLevels];
+    signal input revocationOldKey;
+    signal input revocationIsOld0;
+
+    component vote = ExpiringVote(levels);
+    vote.root <== root;
+    vote.nullifier <== nullifier;
+    vote.daoId <== daoId;
+    vote.proposalId <== proposalId;
+    vote.voteChoice <== voteChoice;
+    vote.snapshotTime <== snapshotTime;
+    vote.secret <== secret;
+    vote.salt <== salt;
+    vote.expiresAt <== expiresAt;
+    for (var i = 0; i < levels; i++) {
+        vote.pathElements[i] <== pathElements[i];
+        vote.pathIndices[i] <== pathIndices[i];
+    }
+
+    component commitmentHasher = Poseidon(2);
+    commitmentHasher.inputs[0] <== secret;
+    commitmentHasher.inputs[1] <== salt;
+
+    component nonRevoked = NonRevocation(revocationLevels);
+    nonRevoked.revocationRoot <== revocationRoot;
+    nonRevoked.commitment <== commitmentHasher.out;
+    for (var i = 0; i < revocationLevels; i++) {
+        nonRevoked.siblings[i] <== revocationSiblings[i];
+    }
+    nonRevoked.oldKey <== revocationOldKey;
+    nonRevoked.isOld0 <== revocationIsOld0;
+}
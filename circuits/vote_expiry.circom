@@ -0,0 +1,8 @@
+pragma circom 2.0.0;
+
+include "vote_core.circom";
+
+// Depth 18 vote circuit for trees with expiring leaves (membership-tree init_expiring_tree)
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice, snapshotTime] - 6 signals
+// Leaf is Poseidon(Poseidon(secret, salt), expiresAt); expiresAt stays private
+component main {public [root, nullifier, daoId, proposalId, voteChoice, snapshotTime]} = ExpiringVote(18);
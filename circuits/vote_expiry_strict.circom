@@ -0,0 +1,7 @@
+pragma circom 2.0.0;
+
+include "vote_core.circom";
+
+// Depth 18 vote circuit for expiring trees with strict revocation
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice, snapshotTime, revocationRoot] - 7 signals
+component main {public [root, nullifier, daoId, proposalId, voteChoice, snapshotTime, revocationRoot]} = StrictExpiringVote(18, 64);
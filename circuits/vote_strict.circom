@@ -0,0 +1,8 @@
+pragma circom 2.0.0;
+
+include "vote_core.circom";
+
+// Depth 18 vote circuit for DAOs with strict revocation (membership-tree enable_strict_revocation)
+// Public signals: [root, nullifier, daoId, proposalId, voteChoice, revocationRoot] - 6 signals
+// Proves the commitment is absent from the depth-64 revocation tree
+component main {public [root, nullifier, daoId, proposalId, voteChoice, revocationRoot]} = StrictVote(18, 64);
@@ -19,8 +19,8 @@
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype,
     crypto::bn254::{Fr, G1Affine, G2Affine},
-    panic_with_error, symbol_short, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
-    U256,
+    panic_with_error, symbol_short, Address, Bytes, BytesN, Env, IntoVal, String, Symbol,
+    TryFromVal, Val, Vec, U256,
 };
 
 // Re-export shared Groth16 types and utilities
@@ -183,16 +183,62 @@ impl Comments {
         }
     }
 
-    /// Get VK from voting contract (single source of truth)
-    fn get_vk_from_voting(env: &Env, dao_id: u64) -> VerificationKey {
+    /// Get the proposal's pinned VK from voting contract (single source of truth)
+    fn get_vk_from_voting(env: &Env, dao_id: u64, proposal_id: u64) -> VerificationKey {
         let voting_contract: Address = Self::voting_contract(env.clone());
         env.invoke_contract(
             &voting_contract,
-            &symbol_short!("get_vk"),
-            soroban_sdk::vec![env, dao_id.into_val(env)],
+            &Symbol::new(env, "get_proposal_vk"),
+            soroban_sdk::vec![env, dao_id.into_val(env), proposal_id.into_val(env)],
         )
     }
 
+    /// Add the signals the DAO's tree mode adds to the vote circuit, in vote order
+    /// Expiring trees append the proposal's creation time (the expiry snapshot); strict
+    /// proposals (per the proposal's snapshot, as in `vote`) append the current revocation
+    /// root. A VK that does not expect exactly these signals is rejected.
+    fn push_circuit_signals(
+        env: &Env,
+        dao_id: u64,
+        proposal_id: u64,
+        vk: &VerificationKey,
+        pub_signals: &mut Vec<U256>,
+    ) {
+        let tree_contract = Self::tree_contract(env.clone());
+        let expiring: bool = env.invoke_contract(
+            &tree_contract,
+            &Symbol::new(env, "expiring"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        );
+        let strict: bool = env.invoke_contract(
+            &Self::voting_contract(env.clone()),
+            &Symbol::new(env, "get_strict"),
+            soroban_sdk::vec![env, dao_id.into_val(env), proposal_id.into_val(env)],
+        );
+        if !expiring && !strict {
+            return;
+        }
+        if expiring {
+            let created_at: u64 = env.invoke_contract(
+                &Self::voting_contract(env.clone()),
+                &Symbol::new(env, "get_created_at"),
+                soroban_sdk::vec![env, dao_id.into_val(env), proposal_id.into_val(env)],
+            );
+            pub_signals.push_back(U256::from_u128(env, created_at as u128));
+        }
+        if strict {
+            let revocation_root: U256 = env.invoke_contract(
+                &tree_contract,
+                &Symbol::new(env, "revocation_root"),
+                soroban_sdk::vec![env, dao_id.into_val(env)],
+            );
+            pub_signals.push_back(revocation_root);
+        }
+        if vk.ic.len() != pub_signals.len() + 1 {
+            panic_with_error!(env, CommentsError::InvalidProof);
+        }
+    }
+
     /// Get proposal info from voting contract for eligibility checks
     /// Returns: (vote_mode, eligible_root, earliest_root_index)
     fn get_proposal_eligibility(env: &Env, dao_id: u64, proposal_id: u64) -> (VoteMode, U256, u32) {
@@ -227,8 +273,6 @@ impl Comments {
         let (vote_mode, eligible_root, earliest_root_index) =
             Self::get_proposal_eligibility(env, dao_id, proposal_id);
 
-        let tree_contract: Address = Self::tree_contract(env.clone());
-
         match vote_mode {
             VoteMode::Fixed => {
                 // Fixed mode: root must exactly match the snapshot at proposal creation
@@ -237,21 +281,34 @@ impl Comments {
                 }
             }
             VoteMode::Trailing => {
-                // Trailing mode: check root is in valid history
-                let root_valid: bool = env.invoke_contract(
-                    &tree_contract,
-                    &symbol_short!("root_ok"),
-                    soroban_sdk::vec![env, dao_id.into_val(env), root.clone().into_val(env)],
+                // Trailing mode: check root is in valid history of the proposal's member group
+                let voting_contract: Address = Self::voting_contract(env.clone());
+                let group_id: u32 = env.invoke_contract(
+                    &voting_contract,
+                    &symbol_short!("get_group"),
+                    soroban_sdk::vec![env, dao_id.into_val(env), proposal_id.into_val(env)],
+                );
+
+                let root_valid: bool = Self::tree_query(
+                    env,
+                    dao_id,
+                    group_id,
+                    symbol_short!("root_ok"),
+                    "group_root_ok",
+                    Some(root.clone().into_val(env)),
                 );
                 if !root_valid {
                     panic_with_error!(env, CommentsError::RootNotInHistory);
                 }
 
                 // Check root index >= earliest_root_index
-                let root_index: u32 = env.invoke_contract(
-                    &tree_contract,
-                    &symbol_short!("root_idx"),
-                    soroban_sdk::vec![env, dao_id.into_val(env), root.clone().into_val(env)],
+                let root_index: u32 = Self::tree_query(
+                    env,
+                    dao_id,
+                    group_id,
+                    symbol_short!("root_idx"),
+                    "group_root_idx",
+                    Some(root.clone().into_val(env)),
                 );
                 if root_index < earliest_root_index {
                     panic_with_error!(env, CommentsError::RootPredatesProposal);
@@ -259,10 +316,13 @@ impl Comments {
 
                 // SECURITY: Check root index >= min_valid_root_index
                 // This prevents revoked members from commenting using pre-revocation roots
-                let min_valid_root: u32 = env.invoke_contract(
-                    &tree_contract,
-                    &symbol_short!("min_root"),
-                    soroban_sdk::vec![env, dao_id.into_val(env)],
+                let min_valid_root: u32 = Self::tree_query(
+                    env,
+                    dao_id,
+                    group_id,
+                    symbol_short!("min_root"),
+                    "group_min_root",
+                    None,
                 );
                 if root_index < min_valid_root {
                     panic_with_error!(env, CommentsError::RootPredatesRemoval);
@@ -271,8 +331,33 @@ impl Comments {
         }
     }
 
-    fn assert_admin(env: &Env, dao_id: u64, admin: &Address) {
-        // Use cached registry address (set at constructor) - only 1 cross-contract call
+    // Internal: Call a tree view for a member group
+    // The general group (0) uses the original single-tree entrypoints, other groups the
+    // `group_*` variants that take the group id after the DAO id.
+    fn tree_query<T: TryFromVal<Env, Val>>(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        general_fn: Symbol,
+        group_fn: &str,
+        extra: Option<Val>,
+    ) -> T {
+        let tree_contract: Address = Self::tree_contract(env.clone());
+        let mut args: Vec<Val> = soroban_sdk::vec![env, dao_id.into_val(env)];
+        let func = if group_id == 0 {
+            general_fn
+        } else {
+            args.push_back(group_id.into_val(env));
+            Symbol::new(env, group_fn)
+        };
+        if let Some(arg) = extra {
+            args.push_back(arg);
+        }
+        env.invoke_contract(&tree_contract, &func, args)
+    }
+
+    /// Admin check that also admits SBT holders with the role the registry assigns to `action`
+    fn assert_admin_or_role(env: &Env, dao_id: u64, caller: &Address, action: Symbol) {
         let registry: Address = env.storage().instance().get(&REGISTRY).unwrap();
 
         let dao_admin: Address = env.invoke_contract(
@@ -281,11 +366,45 @@ impl Comments {
             soroban_sdk::vec![env, dao_id.into_val(env)],
         );
 
-        if &dao_admin != admin {
+        if &dao_admin != caller && !Self::has_action_role(env, &registry, dao_id, caller, action) {
             panic_with_error!(env, CommentsError::NotAdmin);
         }
     }
 
+    fn has_action_role(
+        env: &Env,
+        registry: &Address,
+        dao_id: u64,
+        member: &Address,
+        action: Symbol,
+    ) -> bool {
+        let min_role: Option<u32> = env.invoke_contract(
+            registry,
+            &Symbol::new(env, "action_role"),
+            soroban_sdk::vec![env, dao_id.into_val(env), action.into_val(env)],
+        );
+        let Some(min_role) = min_role else {
+            return false;
+        };
+
+        let tree_contract: Address = Self::tree_contract(env.clone());
+        let sbt_contract: Address = env.invoke_contract(
+            &tree_contract,
+            &symbol_short!("sbt_contr"),
+            soroban_sdk::vec![env],
+        );
+        env.invoke_contract(
+            &sbt_contract,
+            &symbol_short!("has_role"),
+            soroban_sdk::vec![
+                env,
+                dao_id.into_val(env),
+                member.clone().into_val(env),
+                min_role.into_val(env),
+            ],
+        )
+    }
+
     /// Add a public comment (author is visible)
     pub fn add_comment(
         env: Env,
@@ -396,7 +515,7 @@ impl Comments {
         Self::validate_root_eligibility(&env, dao_id, proposal_id, &root);
 
         // Get VK from voting contract (single source of truth)
-        let vk = Self::get_vk_from_voting(&env, dao_id);
+        let vk = Self::get_vk_from_voting(&env, dao_id, proposal_id);
 
         // Public signals: [root, nullifier, daoId, proposalId, voteChoice]
         // Same as vote circuit - we just ignore voteChoice value for comments
@@ -409,7 +528,7 @@ impl Comments {
             U256::from_u32(&env, 0)
         };
 
-        let pub_signals = soroban_sdk::vec![
+        let mut pub_signals = soroban_sdk::vec![
             &env,
             root.clone(),
             nullifier.clone(),
@@ -417,6 +536,7 @@ impl Comments {
             proposal_signal,
             choice_signal
         ];
+        Self::push_circuit_signals(&env, dao_id, proposal_id, &vk, &mut pub_signals);
 
         // Ignore commitment parameter - it's not used for verification since the vote
         // circuit computes commitment internally from secret+salt (private inputs)
@@ -555,7 +675,7 @@ impl Comments {
         }
 
         // Verify ZK proof using VK from voting contract
-        let vk = Self::get_vk_from_voting(&env, dao_id);
+        let vk = Self::get_vk_from_voting(&env, dao_id, proposal_id);
 
         // Public signals: [root, nullifier, daoId, proposalId, voteChoice]
         // commitment is computed internally in the circuit (private input)
@@ -567,7 +687,7 @@ impl Comments {
             U256::from_u32(&env, 0)
         };
 
-        let pub_signals = soroban_sdk::vec![
+        let mut pub_signals = soroban_sdk::vec![
             &env,
             root.clone(),
             nullifier.clone(),
@@ -575,6 +695,7 @@ impl Comments {
             proposal_signal,
             choice_signal
         ];
+        Self::push_circuit_signals(&env, dao_id, proposal_id, &vk, &mut pub_signals);
 
         // Ignore commitment parameter - it's not used for verification
         let _ = commitment;
@@ -675,7 +796,7 @@ impl Comments {
         }
 
         // Verify ZK proof using VK from voting contract
-        let vk = Self::get_vk_from_voting(&env, dao_id);
+        let vk = Self::get_vk_from_voting(&env, dao_id, proposal_id);
 
         // Public signals: [root, nullifier, daoId, proposalId, voteChoice]
         // commitment is computed internally in the circuit (private input)
@@ -687,7 +808,7 @@ impl Comments {
             U256::from_u32(&env, 0)
         };
 
-        let pub_signals = soroban_sdk::vec![
+        let mut pub_signals = soroban_sdk::vec![
             &env,
             root.clone(),
             nullifier.clone(),
@@ -695,6 +816,7 @@ impl Comments {
             proposal_signal,
             choice_signal
         ];
+        Self::push_circuit_signals(&env, dao_id, proposal_id, &vk, &mut pub_signals);
 
         // Ignore commitment parameter - it's not used for verification
         let _ = commitment;
@@ -718,7 +840,7 @@ impl Comments {
         .publish(&env);
     }
 
-    /// Admin delete any comment
+    /// Admin delete any comment (also open to SBT holders of the DAO's `moderate` action role)
     pub fn admin_delete_comment(
         env: Env,
         dao_id: u64,
@@ -727,7 +849,7 @@ impl Comments {
         admin: Address,
     ) {
         admin.require_auth();
-        Self::assert_admin(&env, dao_id, &admin);
+        Self::assert_admin_or_role(&env, dao_id, &admin, symbol_short!("moderate"));
 
         let key = DataKey::Comment(dao_id, proposal_id, comment_id);
         let mut comment: CommentInfo = env
@@ -926,6 +1048,10 @@ mod test {
                     .unwrap()
             }
 
+            pub fn expiring(_env: Env, _dao_id: u64) -> bool {
+                false
+            }
+
             pub fn set_root(env: Env, dao_id: u64, root: U256) {
                 env.storage()
                     .persistent()
@@ -987,6 +1113,7 @@ mod test {
         #[contracttype]
         pub enum DataKey {
             Member(u64, Address),
+            Role(u64, Address),
         }
 
         #[contract]
@@ -1011,15 +1138,30 @@ mod test {
                 let key = DataKey::Member(dao_id, of);
                 env.storage().persistent().get(&key).unwrap_or(false)
             }
+
+            pub fn set_role(env: Env, dao_id: u64, member: Address, role: u32) {
+                let key = DataKey::Role(dao_id, member);
+                env.storage().persistent().set(&key, &role);
+            }
+
+            pub fn has_role(env: Env, dao_id: u64, member: Address, min_role: u32) -> bool {
+                let role: u32 = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Role(dao_id, member.clone()))
+                    .unwrap_or(0);
+                Self::has(env, dao_id, member) && role >= min_role
+            }
         }
     }
 
     mod mock_registry {
-        use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+        use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
 
         #[contracttype]
         pub enum DataKey {
             Admin(u64),
+            ActionRole(u64, Symbol),
         }
 
         #[contract]
@@ -1039,6 +1181,18 @@ mod test {
                     .get(&DataKey::Admin(dao_id))
                     .unwrap()
             }
+
+            pub fn set_action_role(env: Env, dao_id: u64, action: Symbol, min_role: u32) {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::ActionRole(dao_id, action), &min_role);
+            }
+
+            pub fn action_role(env: Env, dao_id: u64, action: Symbol) -> Option<u32> {
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::ActionRole(dao_id, action))
+            }
         }
     }
 
@@ -1065,13 +1219,17 @@ mod test {
                 env.storage().persistent().set(&DataKey::VK(dao_id), &vk);
             }
 
-            pub fn get_vk(env: Env, dao_id: u64) -> VerificationKey {
+            pub fn get_proposal_vk(env: Env, dao_id: u64, _proposal_id: u64) -> VerificationKey {
                 env.storage()
                     .persistent()
                     .get(&DataKey::VK(dao_id))
                     .unwrap()
             }
 
+            pub fn get_strict(_env: Env, _dao_id: u64, _proposal_id: u64) -> bool {
+                false
+            }
+
             pub fn set_proposal_count(env: Env, dao_id: u64, count: u64) {
                 env.storage()
                     .persistent()
@@ -1091,6 +1249,10 @@ mod test {
                     .set(&DataKey::VoteMode(dao_id, proposal_id), &mode);
             }
 
+            pub fn get_group(_env: Env, _dao_id: u64, _proposal_id: u64) -> u32 {
+                0
+            }
+
             pub fn get_vote_mode(env: Env, dao_id: u64, proposal_id: u64) -> VoteMode {
                 env.storage()
                     .persistent()
@@ -1668,6 +1830,58 @@ mod test {
         comments_client.admin_delete_comment(&dao_id, &proposal_id, &comment_id, &non_admin);
     }
 
+    #[test]
+    fn test_moderate_role_can_admin_delete() {
+        let (env, comments_id, voting_id, tree_id, sbt_id, registry_id, member) = setup_env();
+        let comments_client = CommentsClient::new(&env, &comments_id);
+        let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+        let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+        let dao_id = 1u64;
+        let proposal_id = 1u64;
+        let admin = Address::generate(&env);
+        let moderator = Address::generate(&env);
+
+        setup_dao_and_proposal(
+            &env,
+            &voting_id,
+            &tree_id,
+            &registry_id,
+            &admin,
+            dao_id,
+            proposal_id,
+            VoteMode::Fixed,
+        );
+        sbt_client.set_member(&dao_id, &member, &true);
+        sbt_client.set_member(&dao_id, &moderator, &true);
+        sbt_client.set_role(&dao_id, &moderator, &1u32);
+
+        let content_cid = String::from_str(&env, "QmComment");
+        let comment_id =
+            comments_client.add_comment(&dao_id, &proposal_id, &content_cid, &None, &member);
+
+        // A role is not enough until the DAO opens moderation to it
+        let result = comments_client.try_admin_delete_comment(
+            &dao_id,
+            &proposal_id,
+            &comment_id,
+            &moderator,
+        );
+        assert_eq!(result, Err(Ok(CommentsError::NotAdmin.into())));
+
+        registry_client.set_action_role(&dao_id, &symbol_short!("moderate"), &1u32);
+        let result =
+            comments_client.try_admin_delete_comment(&dao_id, &proposal_id, &comment_id, &member);
+        assert_eq!(result, Err(Ok(CommentsError::NotAdmin.into())));
+
+        comments_client.admin_delete_comment(&dao_id, &proposal_id, &comment_id, &moderator);
+        assert!(
+            comments_client
+                .get_comment(&dao_id, &proposal_id, &comment_id)
+                .deleted
+        );
+    }
+
     // ========================================================================
     // Field Modulus Validation Tests
     // ========================================================================
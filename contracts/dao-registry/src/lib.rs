@@ -4,7 +4,17 @@ use soroban_sdk::{
     BytesN, Env, String, Symbol, Vec,
 };
 
+pub use zkvote_admission::{Dues, DuesConfig, Gate, TokenGate};
+
 const DAO_COUNT: Symbol = symbol_short!("dao_cnt");
+
+/// Actions a DAO can open up to SBT holders of a minimum role (see `set_action_role`)
+/// Create proposals while `members_can_propose` is off
+pub const ACTION_PROPOSE: Symbol = symbol_short!("propose");
+/// Close and archive proposals
+pub const ACTION_CLOSE: Symbol = symbol_short!("close");
+/// Delete other members' comments
+pub const ACTION_MODERATE: Symbol = symbol_short!("moderate");
 const VERSION: u32 = 1;
 const VERSION_KEY: Symbol = symbol_short!("ver");
 
@@ -15,6 +25,8 @@ pub enum RegistryError {
     DaoNotFound = 2,
     NotAdmin = 3,
     MetadataCidTooLong = 4,
+    InvalidDues = 5,
+    InvalidTokenGate = 6,
 }
 
 // Size limit to prevent DoS attacks
@@ -33,6 +45,10 @@ pub struct DaoInfo {
     pub members_can_propose: bool,
     /// IPFS CID for extended metadata (description, images, links)
     pub metadata_cid: Option<String>,
+    /// Fees for self-service membership (see `set_dues`)
+    pub dues: Dues,
+    /// Token holding that admits `self_join` while closed (see `set_token_gate`)
+    pub token_gate: Gate,
 }
 
 /// Groth16 Verification Key for BN254
@@ -65,6 +81,32 @@ pub struct AdminXferEvent {
     pub new_admin: Address,
 }
 
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionRoleEvent {
+    #[topic]
+    pub dao_id: u64,
+    #[topic]
+    pub action: Symbol,
+    pub min_role: Option<u32>,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuesEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub config: Option<DuesConfig>,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenGateEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub gate: Option<TokenGate>,
+}
+
 #[soroban_sdk::contractevent]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ContractUpgraded {
@@ -115,6 +157,8 @@ impl DaoRegistry {
             membership_open,
             members_can_propose,
             metadata_cid,
+            dues: Dues::Free,
+            token_gate: Gate::Ungated,
         };
 
         let key = Self::dao_key(dao_id);
@@ -210,6 +254,114 @@ impl DaoRegistry {
         env.storage().persistent().set(&key, &info);
     }
 
+    /// Let SBT holders with at least `min_role` perform an admin action (admin only).
+    /// Actions are the `ACTION_*` symbols; `None` makes the action admin-only again.
+    /// Roles are assigned on the SBT contract (`set_role`), which the gated contracts query.
+    pub fn set_action_role(
+        env: Env,
+        dao_id: u64,
+        action: Symbol,
+        min_role: Option<u32>,
+        admin: Address,
+    ) {
+        admin.require_auth();
+
+        // Only admin can delegate actions
+        if admin != Self::get_admin(env.clone(), dao_id) {
+            panic_with_error!(&env, RegistryError::NotAdmin);
+        }
+
+        let key = Self::action_role_key(dao_id, &action);
+        match min_role {
+            Some(role) => env.storage().persistent().set(&key, &role),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        ActionRoleEvent {
+            dao_id,
+            action,
+            min_role,
+        }
+        .publish(&env);
+    }
+
+    /// Minimum SBT role allowed to perform an action besides the admin (None = admin only)
+    pub fn action_role(env: Env, dao_id: u64, action: Symbol) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&Self::action_role_key(dao_id, &action))
+    }
+
+    /// Set the join fee and periodic dues for self-service membership (admin only).
+    /// The SBT contract charges them on `self_join` / `join_with_invite` and `pay_dues`.
+    /// `None` makes joining free again; admin mints are never charged.
+    pub fn set_dues(env: Env, dao_id: u64, config: Option<DuesConfig>, admin: Address) {
+        admin.require_auth();
+
+        let key = Self::dao_key(dao_id);
+        let mut info: DaoInfo = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, RegistryError::DaoNotFound));
+
+        // Only admin can change fees
+        if admin != info.admin {
+            panic_with_error!(&env, RegistryError::NotAdmin);
+        }
+
+        if let Some(dues) = &config {
+            if dues.join_fee < 0 || dues.dues < 0 || (dues.dues > 0 && dues.period == 0) {
+                panic_with_error!(&env, RegistryError::InvalidDues);
+            }
+        }
+        info.dues = config.clone().into();
+        env.storage().persistent().set(&key, &info);
+
+        DuesEvent { dao_id, config }.publish(&env);
+    }
+
+    /// Get the DAO's membership fees (None = free)
+    pub fn dues(env: Env, dao_id: u64) -> Option<DuesConfig> {
+        Self::get_dao(env, dao_id).dues.into()
+    }
+
+    /// Let token holders `self_join` while membership is closed (admin only).
+    /// The SBT contract checks `balance(member) >= min_balance` on the token at join time.
+    /// One balance can be handed between addresses to admit each of them in turn, so gate
+    /// members who drop below `min_balance` can be revoked by anyone with the SBT's
+    /// `revoke_below_gate`. That only bounds recycling to what nobody has revoked yet: a
+    /// gate is not one-person-one-vote, and DAOs that need that should admit by invite or
+    /// application. `None` removes the gate.
+    pub fn set_token_gate(env: Env, dao_id: u64, gate: Option<TokenGate>, admin: Address) {
+        admin.require_auth();
+
+        let key = Self::dao_key(dao_id);
+        let mut info: DaoInfo = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, RegistryError::DaoNotFound));
+
+        // Only admin can change who may join
+        if admin != info.admin {
+            panic_with_error!(&env, RegistryError::NotAdmin);
+        }
+
+        if gate.as_ref().is_some_and(|gate| gate.min_balance <= 0) {
+            panic_with_error!(&env, RegistryError::InvalidTokenGate);
+        }
+        info.token_gate = gate.clone().into();
+        env.storage().persistent().set(&key, &info);
+
+        TokenGateEvent { dao_id, gate }.publish(&env);
+    }
+
+    /// Get the DAO's token gate (None = no token-gated joining)
+    pub fn token_gate(env: Env, dao_id: u64) -> Option<TokenGate> {
+        Self::get_dao(env, dao_id).token_gate.into()
+    }
+
     /// Set membership open/closed (admin only).
     /// If `membership_open` is true, users can join (mint SBT) themselves.
     /// If false, only the admin can add members.
@@ -335,6 +487,8 @@ impl DaoRegistry {
             membership_open,
             members_can_propose,
             metadata_cid,
+            dues: Dues::Free,
+            token_gate: Gate::Ungated,
         };
 
         let key = Self::dao_key(dao_id);
@@ -415,6 +569,8 @@ impl DaoRegistry {
             membership_open,
             members_can_propose,
             metadata_cid: None,
+            dues: Dues::Free,
+            token_gate: Gate::Ungated,
         };
 
         let key = Self::dao_key(dao_id);
@@ -500,6 +656,10 @@ impl DaoRegistry {
     fn dao_key(dao_id: u64) -> (Symbol, u64) {
         (symbol_short!("dao"), dao_id)
     }
+
+    fn action_role_key(dao_id: u64, action: &Symbol) -> (Symbol, u64, Symbol) {
+        (symbol_short!("role"), dao_id, action.clone())
+    }
 }
 
 #[cfg(test)]
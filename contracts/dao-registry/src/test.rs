@@ -411,3 +411,117 @@ fn test_set_proposal_mode_non_admin_fails() {
     // Non-admin tries to change proposal mode - should fail with NotAdmin error (code #3)
     client.set_proposal_mode(&dao_id, &false, &non_admin);
 }
+
+#[test]
+fn test_set_action_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DaoRegistry, ());
+    let client = DaoRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = client.create_dao(
+        &String::from_str(&env, "Test"),
+        &admin,
+        &false,
+        &false,
+        &None,
+    );
+    assert_eq!(client.action_role(&dao_id, &ACTION_CLOSE), None);
+
+    client.set_action_role(&dao_id, &ACTION_CLOSE, &Some(2u32), &admin);
+    client.set_action_role(&dao_id, &ACTION_MODERATE, &Some(1u32), &admin);
+    assert_eq!(client.action_role(&dao_id, &ACTION_CLOSE), Some(2));
+    assert_eq!(client.action_role(&dao_id, &ACTION_MODERATE), Some(1));
+    assert_eq!(client.action_role(&dao_id, &ACTION_PROPOSE), None);
+
+    client.set_action_role(&dao_id, &ACTION_CLOSE, &None, &admin);
+    assert_eq!(client.action_role(&dao_id, &ACTION_CLOSE), None);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_set_action_role(&dao_id, &ACTION_CLOSE, &Some(0u32), &outsider),
+        Err(Ok(RegistryError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_set_dues() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DaoRegistry, ());
+    let client = DaoRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = client.create_dao(&String::from_str(&env, "Test"), &admin, &true, &true, &None);
+    assert_eq!(client.dues(&dao_id), None);
+
+    let mut config = DuesConfig {
+        token: Address::generate(&env),
+        treasury: Address::generate(&env),
+        join_fee: 100,
+        dues: 10,
+        period: 0,
+    };
+    // Dues need a period to pay for
+    assert_eq!(
+        client.try_set_dues(&dao_id, &Some(config.clone()), &admin),
+        Err(Ok(RegistryError::InvalidDues.into()))
+    );
+
+    config.period = 2_592_000;
+    client.set_dues(&dao_id, &Some(config.clone()), &admin);
+    assert_eq!(client.dues(&dao_id), Some(config.clone()));
+    assert_eq!(client.get_dao(&dao_id).dues, Dues::Paid(config.clone()));
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_set_dues(&dao_id, &None, &outsider),
+        Err(Ok(RegistryError::NotAdmin.into()))
+    );
+
+    client.set_dues(&dao_id, &None, &admin);
+    assert_eq!(client.dues(&dao_id), None);
+    assert_eq!(client.get_dao(&dao_id).dues, Dues::Free);
+}
+
+#[test]
+fn test_set_token_gate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DaoRegistry, ());
+    let client = DaoRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = client.create_dao(
+        &String::from_str(&env, "Test"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    assert_eq!(client.token_gate(&dao_id), None);
+
+    let mut gate = TokenGate {
+        token: Address::generate(&env),
+        min_balance: 0,
+    };
+    assert_eq!(
+        client.try_set_token_gate(&dao_id, &Some(gate.clone()), &admin),
+        Err(Ok(RegistryError::InvalidTokenGate.into()))
+    );
+
+    gate.min_balance = 1_000;
+    client.set_token_gate(&dao_id, &Some(gate.clone()), &admin);
+    assert_eq!(client.token_gate(&dao_id), Some(gate.clone()));
+    assert_eq!(client.get_dao(&dao_id).token_gate, Gate::Token(gate));
+    // Gating does not open membership to everyone
+    assert!(!client.is_membership_open(&dao_id));
+
+    client.set_token_gate(&dao_id, &None, &admin);
+    assert_eq!(client.token_gate(&dao_id), None);
+    assert_eq!(client.get_dao(&dao_id).token_gate, Gate::Ungated);
+}
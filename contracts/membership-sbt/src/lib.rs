@@ -1,13 +1,44 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, Address,
-    Env, IntoVal, Symbol,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec, U256,
 };
 
+pub use zkvote_admission::{DuesConfig, TokenGate};
+
 const REGISTRY: Symbol = symbol_short!("registry");
+const TREE: Symbol = symbol_short!("tree");
+const DEPLOYER: Symbol = symbol_short!("deployer");
 const VERSION: u32 = 1;
 const VERSION_KEY: Symbol = symbol_short!("ver");
 
+/// Role of a freshly minted SBT. Roles are ordered tiers: a higher value includes what the
+/// lower ones may do, and values above `ROLE_COUNCIL` are free for custom tiers.
+pub const ROLE_MEMBER: u32 = 0;
+pub const ROLE_MODERATOR: u32 = 1;
+pub const ROLE_COUNCIL: u32 = 2;
+
+// Longest allowlist proof accepted by `join_with_invite` (2^32 invitees)
+const MAX_INVITE_PROOF: u32 = 32;
+
+// Pending applications per DAO; further `apply` calls fail until the admin decides some
+const MAX_PENDING_APPLICATIONS: u32 = 100;
+// Applications per `reject_batch` call. The 50-entry write limit binds first: each
+// rejection removes the application and its slot, and moves the last application into
+// the freed slot, up to five writes.
+const MAX_REJECT_BATCH: u32 = 8;
+const MAX_APPLICATION_CID_LEN: u32 = 64;
+
+const MAX_GUARDIANS: u32 = 10;
+
+// Entries per `mint_batch` call. A first mint writes its member flag plus a slot and an
+// index in the active list and a slot in the historical list, and each written entry
+// counts twice against the 100-entry footprint. An alias under a published alias key and
+// a membership term add three writes per mint, so only six such mints fit.
+const MAX_MINT_BATCH: u32 = 10;
+// Slots per `compact_active` call
+const MAX_COMPACT_BATCH: u32 = 6;
+
 #[contracterror]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum SbtError {
@@ -16,16 +47,110 @@ pub enum SbtError {
     NotMember = 3,
     NotOpenMembership = 4,
     AlreadyInitialized = 5,
+    InvalidExpiry = 6,
+    TreeNotBound = 7,
+    InvitesDisabled = 8,
+    InvalidInvite = 9,
+    InviteUsed = 10,
+    ApplicationExists = 11,
+    NoApplication = 12,
+    ApplicationQueueFull = 13,
+    InvalidApplication = 14,
+    DuesNotRequired = 15,
+    NotLapsed = 16,
+    BelowTokenGate = 17,
+    InvalidGuardians = 18,
+    RecoveryNotApproved = 19,
+    MemberIndexMismatch = 20,
+    BatchTooLarge = 21,
+    NotBelowTokenGate = 22,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Member(u64, Address),    // (dao_id, address)
-    Alias(u64, Address),     // (dao_id, address) -> encrypted alias
-    Revoked(u64, Address),   // (dao_id, address) -> bool (revocation flag)
-    MemberCount(u64),        // dao_id -> total member count
-    MemberAtIndex(u64, u64), // (dao_id, index) -> Address
+    Member(u64, Address),          // (dao_id, address)
+    Alias(u64, Address),           // (dao_id, address) -> encrypted alias
+    Revoked(u64, Address), // (dao_id, address) -> bool (revocation flag; false = marked lapsed)
+    MemberCount(u64),      // dao_id -> count of everyone who ever joined
+    MemberAtIndex(u64, u64), // (dao_id, index) -> Address (historical, never compacted)
+    Expiry(u64, Address),  // (dao_id, address) -> timestamp the membership lapses at
+    Term(u64),             // dao_id -> membership length in seconds for new SBTs (0 = permanent)
+    Role(u64, Address),    // (dao_id, address) -> role tier (absent = ROLE_MEMBER)
+    Invites(u64),          // dao_id -> InviteConfig
+    InviteUsed(u64, BytesN<32>), // (dao_id, allowlist leaf or invite nonce) -> consumed
+    Application(u64, Address), // (dao_id, applicant) -> pending Application
+    ApplicationCount(u64), // dao_id -> number of pending applications
+    ApplicationAt(u64, u32), // (dao_id, slot) -> applicant; the last slot moves into a freed one
+    ApplicationSlot(u64, Address), // (dao_id, applicant) -> slot in the pending list
+    Guardians(u64, Address), // (dao_id, member) -> Guardians who may approve a recovery
+    ActiveCount(u64),      // dao_id -> number of active members (not revoked or marked lapsed)
+    ActiveLen(u64),        // dao_id -> slots in the active list, tombstones included
+    ActiveAt(u64, u64),    // (dao_id, slot) -> Address; a tombstone once the member is inactive
+    ActiveIndex(u64, Address), // (dao_id, address) -> slot in the active list
+    AliasKey(u64),         // dao_id -> current AliasKey
+    AliasKeyVersion(u64, Address), // (dao_id, address) -> AliasKey version the alias is encrypted to
+    GateJoined(u64, Address), // (dao_id, address) -> admitted by the token gate (see `revoke_below_gate`)
+}
+
+/// Addresses a member trusts to approve moving their SBT to a new address (see `recover`)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Guardians {
+    pub guardians: Vec<Address>,
+    /// Distinct guardians needed to stand in for the lost address
+    pub threshold: u32,
+}
+
+/// Public key a DAO's member aliases are encrypted to (see `set_alias_key`)
+/// Aliases are base64 `crypto_box_seal` ciphertexts to this X25519 key.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AliasKey {
+    /// Starts at 1 and increases on every rotation
+    pub version: u32,
+    pub public_key: BytesN<32>,
+    pub set_at: u64,
+}
+
+/// An encrypted alias together with the alias key it was encrypted to
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AliasRecord {
+    pub encrypted_alias: String,
+    /// `AliasKey::version` current when the alias was written; 0 if the DAO had no key yet
+    pub key_version: u32,
+}
+
+/// How a DAO admits members by invite (see `join_with_invite`)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InviteConfig {
+    /// Root of a sorted-pair sha256 Merkle tree over `sha256(xdr(address))` leaves
+    pub allowlist_root: Option<BytesN<32>>,
+    /// ed25519 public key that signs one-time invites
+    pub signer: Option<BytesN<32>>,
+}
+
+/// A pending request to join a DAO (see `apply`)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Application {
+    pub applicant: Address,
+    pub application_cid: String,
+    /// Registered in the tree on approval when given
+    pub commitment: Option<U256>,
+    pub applied_at: u64,
+}
+
+/// Proof of an invite, consumed on use
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Invite {
+    /// Merkle proof that the joining address is on the allowlist
+    Allowlist(Vec<BytesN<32>>),
+    /// (nonce, signature) over `sha256(xdr((sbt_contract, dao_id, member, nonce)))`
+    Signed(BytesN<32>, BytesN<64>),
 }
 
 // Typed Events
@@ -53,6 +178,98 @@ pub struct SbtLeaveEvent {
     pub member: Address,
 }
 
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtRenewEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub expires_at: u64,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtLapsedEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub expired_at: u64,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtGuardiansEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub guardians: Option<Guardians>,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtRecoveredEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub old_address: Address,
+    pub new_address: Address,
+    pub by_guardians: bool,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtRoleEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub role: u32,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtInviteConfigEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub config: InviteConfig,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtInviteUsedEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub invite_id: BytesN<32>,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtApplicationEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub applicant: Address,
+    pub application_cid: String,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtApplicationDecidedEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub applicant: Address,
+    pub approved: bool,
+}
+
+/// Published when a DAO sets or rotates its alias key; clients re-encrypt aliases
+/// whose `key_version` is older than `version`
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbtAliasKeyEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub version: u32,
+    pub public_key: BytesN<32>,
+}
+
 #[soroban_sdk::contractevent]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ContractUpgraded {
@@ -66,7 +283,8 @@ pub struct MembershipSbt;
 #[contractimpl]
 impl MembershipSbt {
     /// Constructor: Initialize contract with DAO Registry address
-    pub fn __constructor(env: Env, registry: Address) {
+    /// Only `deployer` may bind the membership tree afterwards (see `bind_tree`).
+    pub fn __constructor(env: Env, registry: Address, deployer: Address) {
         if env.storage().instance().has(&VERSION_KEY) {
             panic_with_error!(&env, SbtError::AlreadyInitialized);
         }
@@ -78,6 +296,30 @@ impl MembershipSbt {
         .publish(&env);
 
         env.storage().instance().set(&REGISTRY, &registry);
+        env.storage().instance().set(&DEPLOYER, &deployer);
+    }
+
+    /// Bind the membership tree contract (deployer only, once, right after deploying the tree)
+    /// `leave` then zeroes the departing member's tree leaves in the same transaction.
+    /// The bound tree may revoke, reinstate, mint and recover SBTs, so nobody but the
+    /// deployer can claim that role between the SBT and tree deployments.
+    pub fn bind_tree(env: Env, tree: Address) {
+        if env.storage().instance().has(&TREE) {
+            panic_with_error!(&env, SbtError::AlreadyInitialized);
+        }
+        let deployer: Address = env
+            .storage()
+            .instance()
+            .get(&DEPLOYER)
+            .unwrap_or_else(|| panic_with_error!(&env, SbtError::AlreadyInitialized));
+        deployer.require_auth();
+        env.storage().instance().set(&TREE, &tree);
+        env.storage().instance().remove(&DEPLOYER);
+    }
+
+    /// Get the bound membership tree contract, if any
+    pub fn tree(env: Env) -> Option<Address> {
+        env.storage().instance().get(&TREE)
     }
 
     fn registry_addr(env: &Env) -> Address {
@@ -102,6 +344,109 @@ impl MembershipSbt {
             .set(&count_key, &(current_count + 1));
     }
 
+    /// Helper: Whether member counts as active (SBT held, not revoked or marked lapsed)
+    fn is_active(env: &Env, dao_id: u64, member: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Member(dao_id, member.clone()))
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::Revoked(dao_id, member.clone()))
+    }
+
+    /// Helper: Count member as active again, clearing any revocation or lapse mark
+    /// Call before setting the member entry of a new SBT. Members whose tombstone was
+    /// not compacted yet get their old slot back.
+    fn activate(env: &Env, dao_id: u64, member: &Address) {
+        if Self::is_active(env, dao_id, member) {
+            return;
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Revoked(dao_id, member.clone()));
+        Self::count_active(env, dao_id);
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ActiveIndex(dao_id, member.clone()))
+        {
+            Self::add_active_slot(env, dao_id, member);
+        }
+    }
+
+    /// Helper: Count one more active member
+    fn count_active(env: &Env, dao_id: u64) {
+        let count_key = DataKey::ActiveCount(dao_id);
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(&count_key, &(count + 1));
+    }
+
+    /// Helper: Append member to the active list
+    fn add_active_slot(env: &Env, dao_id: u64, member: &Address) {
+        let len_key = DataKey::ActiveLen(dao_id);
+        let len: u64 = env.storage().persistent().get(&len_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActiveAt(dao_id, len), member);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActiveIndex(dao_id, member.clone()), &len);
+        env.storage().persistent().set(&len_key, &(len + 1));
+    }
+
+    /// Helper: Revoke (`revoked`) or mark lapsed a member, uncounting them if active
+    /// Callers check that the member holds an SBT. Only the count changes; the member's
+    /// slot stays behind as a tombstone for `compact_active`, which keeps removals
+    /// through the tree within footprint limits.
+    fn deactivate(env: &Env, dao_id: u64, member: &Address, revoked: bool) {
+        let revoked_key = DataKey::Revoked(dao_id, member.clone());
+        let was_active = !env.storage().persistent().has(&revoked_key);
+        env.storage().persistent().set(&revoked_key, &revoked);
+        if !was_active {
+            return;
+        }
+
+        let count_key = DataKey::ActiveCount(dao_id);
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&count_key, &count.saturating_sub(1));
+    }
+
+    /// Helper: Start a fresh membership term (or clear a stale expiry) on mint
+    fn start_term(env: &Env, dao_id: u64, member: &Address) {
+        let expiry_key = DataKey::Expiry(dao_id, member.clone());
+        match Self::term_end(env, dao_id) {
+            Some(expires_at) => env.storage().persistent().set(&expiry_key, &expires_at),
+            None => env.storage().persistent().remove(&expiry_key),
+        }
+    }
+
+    /// Helper: Expiry of a term starting now (None while the DAO's terms are permanent)
+    fn term_end(env: &Env, dao_id: u64) -> Option<u64> {
+        let term: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Term(dao_id))
+            .unwrap_or(0);
+        (term > 0).then(|| env.ledger().timestamp().saturating_add(term))
+    }
+
+    fn assert_dao_admin(env: &Env, dao_id: u64, admin: &Address) {
+        let registry: Address = Self::registry_addr(env);
+        let dao_admin: Address = env.invoke_contract(
+            &registry,
+            &symbol_short!("get_admin"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        );
+
+        if &dao_admin != admin {
+            panic_with_error!(env, SbtError::NotDaoAdmin);
+        }
+    }
+
     /// Mint SBT to address for a specific DAO
     /// Only DAO admin can mint (verified via registry)
     /// Optionally stores an encrypted alias for the member
@@ -128,37 +473,35 @@ impl MembershipSbt {
             panic_with_error!(&env, SbtError::NotDaoAdmin);
         }
 
-        // Check if already has active SBT (not revoked)
-        if Self::has(env.clone(), dao_id, to.clone()) {
-            panic_with_error!(&env, SbtError::AlreadyMinted);
-        }
-
-        let member_key = DataKey::Member(dao_id, to.clone());
-        let revoked_key = DataKey::Revoked(dao_id, to.clone());
-
-        // Check if this is a new member (not just re-minting)
-        let is_new_member = !env.storage().persistent().has(&member_key);
-
-        // Set member
-        env.storage().persistent().set(&member_key, &true);
+        Self::mint_member(&env, dao_id, &to, encrypted_alias);
+    }
 
-        // Clear revoked flag if it exists (allows re-minting)
-        if env.storage().persistent().has(&revoked_key) {
-            env.storage().persistent().remove(&revoked_key);
-        }
+    /// Mint SBTs to many addresses with a single admin check (admin only)
+    /// Addresses that already hold an active SBT are skipped and returned; the rest are
+    /// minted as by `mint`. At most 10 entries per call to stay within ledger footprint
+    /// limits; with aliases and a membership term only 6 fit (see `MAX_MINT_BATCH`).
+    pub fn mint_batch(
+        env: Env,
+        dao_id: u64,
+        members: Vec<(Address, Option<soroban_sdk::String>)>,
+        admin: Address,
+    ) -> Vec<Address> {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        // Store encrypted alias if provided
-        if let Some(alias) = encrypted_alias {
-            let alias_key = DataKey::Alias(dao_id, to.clone());
-            env.storage().persistent().set(&alias_key, &alias);
+        if members.len() > MAX_MINT_BATCH {
+            panic_with_error!(&env, SbtError::BatchTooLarge);
         }
 
-        // Add to enumeration list if new member
-        if is_new_member {
-            Self::add_member_to_list(&env, dao_id, &to);
+        let mut skipped = Vec::new(&env);
+        for (to, encrypted_alias) in members.iter() {
+            if Self::has(env.clone(), dao_id, to.clone()) {
+                skipped.push_back(to);
+                continue;
+            }
+            Self::mint_member(&env, dao_id, &to, encrypted_alias);
         }
-
-        SbtMintEvent { dao_id, to }.publish(&env);
+        skipped
     }
 
     /// Mint SBT from registry during DAO initialization
@@ -170,8 +513,13 @@ impl MembershipSbt {
             panic_with_error!(&env, SbtError::AlreadyMinted);
         }
 
+        Self::activate(&env, dao_id, &to);
         let key = DataKey::Member(dao_id, to.clone());
         env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Role(dao_id, to.clone()));
+        Self::start_term(&env, dao_id, &to);
 
         // Add to enumeration list
         Self::add_member_to_list(&env, dao_id, &to);
@@ -179,143 +527,1082 @@ impl MembershipSbt {
         SbtMintEvent { dao_id, to }.publish(&env);
     }
 
-    /// Check if address has SBT for a specific DAO (and is not revoked)
+    /// Check if address has SBT for a specific DAO (and is not revoked or expired)
     pub fn has(env: Env, dao_id: u64, of: Address) -> bool {
         let member_key = DataKey::Member(dao_id, of.clone());
-        let revoked_key = DataKey::Revoked(dao_id, of);
+        let revoked_key = DataKey::Revoked(dao_id, of.clone());
 
-        // Must have SBT AND not be revoked
+        // Must have SBT AND not be revoked; without one nothing else is read, which
+        // keeps first mints in `mint_batch` to their own entries
         let has_sbt = env.storage().persistent().get(&member_key).unwrap_or(false);
+        if !has_sbt {
+            return false;
+        }
         let is_revoked = env
             .storage()
             .persistent()
             .get(&revoked_key)
             .unwrap_or(false);
 
-        has_sbt && !is_revoked
+        !is_revoked && !Self::is_expired(&env, dao_id, &of)
     }
 
-    /// Get registry address
-    pub fn registry(env: Env) -> Address {
+    fn is_expired(env: &Env, dao_id: u64, member: &Address) -> bool {
         env.storage()
-            .instance()
-            .get(&REGISTRY)
-            .unwrap_or_else(|| panic_with_error!(&env, SbtError::NotDaoAdmin))
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::Expiry(dao_id, member.clone()))
+            .is_some_and(|expires_at| env.ledger().timestamp() >= expires_at)
     }
 
-    /// Get encrypted alias for a member (if set)
-    pub fn get_alias(env: Env, dao_id: u64, member: Address) -> Option<soroban_sdk::String> {
-        let key = DataKey::Alias(dao_id, member);
-        env.storage().persistent().get(&key)
+    /// Timestamp a member's SBT lapses at (None = permanent)
+    pub fn expires_at(env: Env, dao_id: u64, member: Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Expiry(dao_id, member))
     }
 
-    /// Revoke an SBT (admin only)
-    /// Sets revocation flag, keeping member entry and alias intact
-    pub fn revoke(env: Env, dao_id: u64, member: Address, admin: Address) {
-        // Verify admin authorization
+    /// Set the membership term applied to SBTs minted from now on (admin only)
+    /// `term_secs` of 0 makes new memberships permanent. Existing SBTs keep their expiry.
+    pub fn set_term(env: Env, dao_id: u64, term_secs: u64, admin: Address) {
         admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        // Verify this admin owns the DAO (cross-contract call to registry)
-        let registry: Address = Self::registry_addr(&env);
-        let dao_admin: Address = env.invoke_contract(
-            &registry,
-            &symbol_short!("get_admin"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
-        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::Term(dao_id), &term_secs);
+    }
 
-        if dao_admin != admin {
-            panic_with_error!(&env, SbtError::NotDaoAdmin);
-        }
+    /// Get the membership term for new SBTs in seconds (0 = permanent)
+    pub fn get_term(env: Env, dao_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Term(dao_id))
+            .unwrap_or(0)
+    }
+
+    /// Renew a membership until `expires_at` (admin only)
+    /// Works on lapsed SBTs too; revoked SBTs must be re-minted instead.
+    /// Call `renew_leaf` on the tree contract afterwards to refresh the member's leaf.
+    pub fn renew(env: Env, dao_id: u64, member: Address, expires_at: u64, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        // Member must exist
         let member_key = DataKey::Member(dao_id, member.clone());
-        if !env.storage().persistent().has(&member_key) {
+        let revoked_key = DataKey::Revoked(dao_id, member.clone());
+        let has_sbt: bool = env.storage().persistent().get(&member_key).unwrap_or(false);
+        let is_revoked: bool = env
+            .storage()
+            .persistent()
+            .get(&revoked_key)
+            .unwrap_or(false);
+        if !has_sbt || is_revoked {
             panic_with_error!(&env, SbtError::NotMember);
         }
 
-        // Set revoked flag
-        let revoked_key = DataKey::Revoked(dao_id, member.clone());
-        env.storage().persistent().set(&revoked_key, &true);
+        if expires_at <= env.ledger().timestamp() {
+            panic_with_error!(&env, SbtError::InvalidExpiry);
+        }
 
-        SbtRevokeEvent { dao_id, member }.publish(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Expiry(dao_id, member.clone()), &expires_at);
+        Self::activate(&env, dao_id, &member);
+
+        SbtRenewEvent {
+            dao_id,
+            member,
+            expires_at,
+        }
+        .publish(&env);
     }
 
-    /// Leave DAO voluntarily (member self-revokes)
-    /// Sets revocation flag, keeping member entry and alias intact
-    pub fn leave(env: Env, dao_id: u64, member: Address) {
-        // Member must authorize their own departure
+    /// Pay dues for `periods` periods, extending the membership from its expiry (or now)
+    /// Works on lapsed SBTs too; revoked SBTs must be re-minted instead.
+    /// Call `renew_leaf` on the tree contract afterwards to refresh the member's leaf.
+    pub fn pay_dues(env: Env, dao_id: u64, member: Address, periods: u32) -> u64 {
         member.require_auth();
 
-        // Member must exist
-        let member_key = DataKey::Member(dao_id, member.clone());
-        if !env.storage().persistent().has(&member_key) {
-            panic_with_error!(&env, SbtError::NotMember);
+        let dues = Self::dues_config(&env, dao_id)
+            .filter(|dues| dues.dues > 0)
+            .unwrap_or_else(|| panic_with_error!(&env, SbtError::DuesNotRequired));
+        if periods == 0 {
+            panic_with_error!(&env, SbtError::InvalidExpiry);
         }
 
-        // Set revoked flag
-        let revoked_key = DataKey::Revoked(dao_id, member.clone());
-        env.storage().persistent().set(&revoked_key, &true);
-
-        SbtLeaveEvent { dao_id, member }.publish(&env);
-    }
+        if !Self::is_holder(&env, dao_id, &member) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
 
-    /// Self-join a DAO with open membership
-    /// Allows users to mint their own SBT if the DAO allows open membership
-    pub fn self_join(
-        env: Env,
-        dao_id: u64,
-        member: Address,
-        encrypted_alias: Option<soroban_sdk::String>,
-    ) {
-        // Member must authorize
-        member.require_auth();
+        let amount = dues
+            .dues
+            .checked_mul(periods.into())
+            .unwrap_or_else(|| panic_with_error!(&env, SbtError::InvalidExpiry));
+        token::Client::new(&env, &dues.token).transfer(&member, &dues.treasury, &amount);
 
-        // Check with registry if this DAO has open membership
-        let registry: Address = Self::registry_addr(&env);
-        let membership_open: bool = env.invoke_contract(
-            &registry,
-            &Symbol::new(&env, "is_membership_open"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
-        );
+        let now = env.ledger().timestamp();
+        let paid_from = Self::expires_at(env.clone(), dao_id, member.clone())
+            .map_or(now, |expires_at| expires_at.max(now));
+        let expires_at = paid_from.saturating_add(dues.period.saturating_mul(periods.into()));
+        env.storage()
+            .persistent()
+            .set(&DataKey::Expiry(dao_id, member.clone()), &expires_at);
+        Self::activate(&env, dao_id, &member);
 
-        if !membership_open {
-            panic_with_error!(&env, SbtError::NotOpenMembership);
+        SbtRenewEvent {
+            dao_id,
+            member,
+            expires_at,
         }
+        .publish(&env);
+        expires_at
+    }
 
-        // Check if already has active SBT (not revoked)
-        if Self::has(env.clone(), dao_id, member.clone()) {
-            panic_with_error!(&env, SbtError::AlreadyMinted);
+    /// Record that a member's SBT has lapsed (unpaid dues or an ended term)
+    /// Permissionless. `has` already treats the SBT as inactive; this removes it from the
+    /// active member count and emits `SbtLapsedEvent` so indexers and the admin see the
+    /// lapse. Renewing or paying dues counts the member as active again.
+    pub fn mark_lapsed(env: Env, dao_id: u64, member: Address) {
+        if !Self::is_holder(&env, dao_id, &member) || !Self::is_expired(&env, dao_id, &member) {
+            panic_with_error!(&env, SbtError::NotLapsed);
         }
+        Self::deactivate(&env, dao_id, &member, false);
 
-        let member_key = DataKey::Member(dao_id, member.clone());
+        let expired_at = Self::expires_at(env.clone(), dao_id, member.clone()).unwrap_or(0);
+        SbtLapsedEvent {
+            dao_id,
+            member,
+            expired_at,
+        }
+        .publish(&env);
+    }
+
+    fn dues_config(env: &Env, dao_id: u64) -> Option<DuesConfig> {
+        env.invoke_contract(
+            &Self::registry_addr(env),
+            &symbol_short!("dues"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        )
+    }
+
+    // Internal: Collect the join fee and first period of dues from a self-service joiner
+    // With dues set, the paid period replaces the DAO's membership term.
+    fn charge_joining(env: &Env, dao_id: u64, member: &Address) {
+        let Some(dues) = Self::dues_config(env, dao_id) else {
+            return;
+        };
+
+        let amount = dues.join_fee.saturating_add(dues.dues);
+        if amount > 0 {
+            token::Client::new(env, &dues.token).transfer(member, &dues.treasury, &amount);
+        }
+        if dues.dues > 0 {
+            let expires_at = env.ledger().timestamp().saturating_add(dues.period);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Expiry(dao_id, member.clone()), &expires_at);
+        }
+    }
+
+    /// Set the role tier of an active member (admin only)
+    /// Voting, comments and the registry gate actions on the minimum role the registry
+    /// assigns to them (`action_role`). Reinstatement keeps the role; re-minting resets it.
+    pub fn set_role(env: Env, dao_id: u64, member: Address, role: u32, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        if !Self::has(env.clone(), dao_id, member.clone()) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
+
+        let key = DataKey::Role(dao_id, member.clone());
+        if role == ROLE_MEMBER {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &role);
+        }
+
+        SbtRoleEvent {
+            dao_id,
+            member,
+            role,
+        }
+        .publish(&env);
+    }
+
+    /// Get a member's role tier (None without an active SBT)
+    pub fn role(env: Env, dao_id: u64, member: Address) -> Option<u32> {
+        if !Self::has(env.clone(), dao_id, member.clone()) {
+            return None;
+        }
+        Some(
+            env.storage()
+                .persistent()
+                .get(&DataKey::Role(dao_id, member))
+                .unwrap_or(ROLE_MEMBER),
+        )
+    }
+
+    /// Check if a member holds an active SBT with at least `min_role`
+    pub fn has_role(env: Env, dao_id: u64, member: Address, min_role: u32) -> bool {
+        Self::role(env, dao_id, member).is_some_and(|role| role >= min_role)
+    }
+
+    /// Get registry address
+    pub fn registry(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&REGISTRY)
+            .unwrap_or_else(|| panic_with_error!(&env, SbtError::NotDaoAdmin))
+    }
+
+    /// Get encrypted alias for a member (if set)
+    pub fn get_alias(env: Env, dao_id: u64, member: Address) -> Option<soroban_sdk::String> {
+        let key = DataKey::Alias(dao_id, member);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Get a member's encrypted alias with the alias key version it was encrypted to
+    pub fn get_alias_record(env: Env, dao_id: u64, member: Address) -> Option<AliasRecord> {
+        let encrypted_alias: String = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Alias(dao_id, member.clone()))?;
+        let key_version = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AliasKeyVersion(dao_id, member))
+            .unwrap_or(0);
+        Some(AliasRecord {
+            encrypted_alias,
+            key_version,
+        })
+    }
+
+    /// Publish or rotate the public key member aliases are encrypted to (admin only)
+    /// Returns the new key version. Existing aliases keep their old version until the
+    /// admin re-submits them re-encrypted via `update_alias`.
+    pub fn set_alias_key(env: Env, dao_id: u64, public_key: BytesN<32>, admin: Address) -> u32 {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        let key = DataKey::AliasKey(dao_id);
+        let version = env
+            .storage()
+            .persistent()
+            .get::<DataKey, AliasKey>(&key)
+            .map_or(1, |current| current.version + 1);
+        env.storage().persistent().set(
+            &key,
+            &AliasKey {
+                version,
+                public_key: public_key.clone(),
+                set_at: env.ledger().timestamp(),
+            },
+        );
+
+        SbtAliasKeyEvent {
+            dao_id,
+            version,
+            public_key,
+        }
+        .publish(&env);
+        version
+    }
+
+    /// Get the DAO's current alias key, if one was published
+    pub fn alias_key(env: Env, dao_id: u64) -> Option<AliasKey> {
+        env.storage().persistent().get(&DataKey::AliasKey(dao_id))
+    }
+
+    /// Helper: Store an alias, recording the DAO's current alias key version
+    fn store_alias(env: &Env, dao_id: u64, member: &Address, encrypted_alias: &String) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Alias(dao_id, member.clone()), encrypted_alias);
+        // Aliases written before the DAO published a key have no version entry
+        if let Some(key) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, AliasKey>(&DataKey::AliasKey(dao_id))
+        {
+            env.storage().persistent().set(
+                &DataKey::AliasKeyVersion(dao_id, member.clone()),
+                &key.version,
+            );
+        }
+    }
+
+    /// Revoke an SBT (admin only)
+    /// Sets revocation flag, keeping member entry and alias intact
+    pub fn revoke(env: Env, dao_id: u64, member: Address, admin: Address) {
+        // Verify admin authorization
+        admin.require_auth();
+
+        // Verify this admin owns the DAO (cross-contract call to registry)
+        let registry: Address = Self::registry_addr(&env);
+        let dao_admin: Address = env.invoke_contract(
+            &registry,
+            &symbol_short!("get_admin"),
+            soroban_sdk::vec![&env, dao_id.into_val(&env)],
+        );
+
+        if dao_admin != admin {
+            panic_with_error!(&env, SbtError::NotDaoAdmin);
+        }
+
+        // Member must exist
+        let member_key = DataKey::Member(dao_id, member.clone());
+        if !env.storage().persistent().has(&member_key) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
+
+        // Set revoked flag
+        Self::deactivate(&env, dao_id, &member, true);
+
+        SbtRevokeEvent { dao_id, member }.publish(&env);
+    }
+
+    /// Revoke a member's SBT on behalf of the bound tree
+    /// Called by the tree's `remove_member`, which checks the DAO admin; removals queued
+    /// during a membership freeze are applied later without the admin's signature.
+    pub fn revoke_from_tree(env: Env, dao_id: u64, member: Address) {
+        let tree = Self::tree(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, SbtError::TreeNotBound));
+        tree.require_auth();
+
+        // Member must exist
+        let member_key = DataKey::Member(dao_id, member.clone());
+        if !env.storage().persistent().has(&member_key) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
+
+        Self::deactivate(&env, dao_id, &member, true);
+
+        SbtRevokeEvent { dao_id, member }.publish(&env);
+    }
+
+    /// Clear a member's revocation (bound tree only)
+    /// Called by the tree's `reinstate_member`, which checks the DAO admin and emits the
+    /// reinstatement event. Alias and expiry are kept; a lapsed SBT still needs `renew`.
+    pub fn reinstate(env: Env, dao_id: u64, member: Address) {
+        let tree = Self::tree(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, SbtError::TreeNotBound));
+        tree.require_auth();
+
+        // Member must exist
+        let member_key = DataKey::Member(dao_id, member.clone());
+        if !env.storage().persistent().has(&member_key) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
+
+        // Clears the revoked flag
+        Self::activate(&env, dao_id, &member);
+    }
+
+    /// Leave DAO voluntarily (member self-revokes)
+    /// Sets revocation flag, keeping member entry and alias intact, and zeroes the
+    /// member's leaves in the bound tree
+    pub fn leave(env: Env, dao_id: u64, member: Address) {
+        // Member must authorize their own departure
+        member.require_auth();
+
+        // Member must exist
+        let member_key = DataKey::Member(dao_id, member.clone());
+        if !env.storage().persistent().has(&member_key) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
+
+        // Set revoked flag
+        Self::deactivate(&env, dao_id, &member, true);
+
+        // Zero the member's tree leaves so old roots stop proving membership
+        if let Some(tree) = Self::tree(env.clone()) {
+            env.invoke_contract::<()>(
+                &tree,
+                &Symbol::new(&env, "remove_departed"),
+                soroban_sdk::vec![&env, dao_id.into_val(&env), member.clone().into_val(&env)],
+            );
+        }
+
+        SbtLeaveEvent { dao_id, member }.publish(&env);
+    }
+
+    /// Set (or clear with `None`) the guardians who can approve recovering this SBT
+    /// At most 10 guardians; the threshold must be between 1 and their number.
+    pub fn set_guardians(env: Env, dao_id: u64, member: Address, guardians: Option<Guardians>) {
+        member.require_auth();
+
+        if !Self::is_holder(&env, dao_id, &member) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
+
+        let key = DataKey::Guardians(dao_id, member.clone());
+        match &guardians {
+            Some(config) => {
+                let count = config.guardians.len();
+                if count > MAX_GUARDIANS
+                    || config.threshold == 0
+                    || config.threshold > count
+                    || config.guardians.contains(&member)
+                {
+                    panic_with_error!(&env, SbtError::InvalidGuardians);
+                }
+                for (i, guardian) in config.guardians.iter().enumerate() {
+                    if config.guardians.first_index_of(&guardian) != Some(i as u32) {
+                        panic_with_error!(&env, SbtError::InvalidGuardians);
+                    }
+                }
+                env.storage().persistent().set(&key, config);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        SbtGuardiansEvent {
+            dao_id,
+            member,
+            guardians,
+        }
+        .publish(&env);
+    }
+
+    /// Get a member's recovery guardians
+    pub fn guardians(env: Env, dao_id: u64, member: Address) -> Option<Guardians> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Guardians(dao_id, member))
+    }
+
+    /// Move an SBT to a new address (admin plus the old address or a guardian quorum)
+    /// `approvers` is either `[old_address]` or at least `threshold` of the member's guardians,
+    /// each of whom must authorize. `member_index` is the old address's position in
+    /// `get_members`. Alias, expiry, role, guardians and the enumeration slot move over, and
+    /// the bound tree re-keys the member's leaves, keeping every commitment and root.
+    pub fn recover(
+        env: Env,
+        dao_id: u64,
+        old_address: Address,
+        new_address: Address,
+        member_index: u64,
+        approvers: Vec<Address>,
+        admin: Address,
+    ) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        let by_guardians = if approvers.len() == 1 && approvers.contains(&old_address) {
+            old_address.require_auth();
+            false
+        } else {
+            let Some(config) = Self::guardians(env.clone(), dao_id, old_address.clone()) else {
+                panic_with_error!(&env, SbtError::RecoveryNotApproved);
+            };
+            for (i, approver) in approvers.iter().enumerate() {
+                let duplicate = approvers.first_index_of(&approver) != Some(i as u32);
+                if duplicate || !config.guardians.contains(&approver) {
+                    panic_with_error!(&env, SbtError::RecoveryNotApproved);
+                }
+                approver.require_auth();
+            }
+            if approvers.len() < config.threshold {
+                panic_with_error!(&env, SbtError::RecoveryNotApproved);
+            }
+            true
+        };
+
+        // Lapsed SBTs can be recovered (and renewed afterwards); revoked ones cannot
+        if !Self::is_holder(&env, dao_id, &old_address) {
+            panic_with_error!(&env, SbtError::NotMember);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Member(dao_id, new_address.clone()))
+        {
+            panic_with_error!(&env, SbtError::AlreadyMinted);
+        }
+        let index_key = DataKey::MemberAtIndex(dao_id, member_index);
+        if env
+            .storage()
+            .persistent()
+            .get::<DataKey, Address>(&index_key)
+            != Some(old_address.clone())
+        {
+            panic_with_error!(&env, SbtError::MemberIndexMismatch);
+        }
+        env.storage().persistent().set(&index_key, &new_address);
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Member(dao_id, old_address.clone()));
+        env.storage()
+            .persistent()
+            .set(&DataKey::Member(dao_id, new_address.clone()), &true);
+        // Carries a lapse mark over, so the active count stays right
+        Self::move_entry::<bool>(
+            &env,
+            DataKey::Revoked(dao_id, old_address.clone()),
+            DataKey::Revoked(dao_id, new_address.clone()),
+        );
+        Self::move_entry::<soroban_sdk::String>(
+            &env,
+            DataKey::Alias(dao_id, old_address.clone()),
+            DataKey::Alias(dao_id, new_address.clone()),
+        );
+        Self::move_entry::<u32>(
+            &env,
+            DataKey::AliasKeyVersion(dao_id, old_address.clone()),
+            DataKey::AliasKeyVersion(dao_id, new_address.clone()),
+        );
+        Self::move_entry::<u64>(
+            &env,
+            DataKey::Expiry(dao_id, old_address.clone()),
+            DataKey::Expiry(dao_id, new_address.clone()),
+        );
+        Self::move_entry::<u32>(
+            &env,
+            DataKey::Role(dao_id, old_address.clone()),
+            DataKey::Role(dao_id, new_address.clone()),
+        );
+        Self::move_entry::<Guardians>(
+            &env,
+            DataKey::Guardians(dao_id, old_address.clone()),
+            DataKey::Guardians(dao_id, new_address.clone()),
+        );
+        Self::move_entry::<bool>(
+            &env,
+            DataKey::GateJoined(dao_id, old_address.clone()),
+            DataKey::GateJoined(dao_id, new_address.clone()),
+        );
+        // Keep the active slot, now pointing at the new address
+        let old_position_key = DataKey::ActiveIndex(dao_id, old_address.clone());
+        if let Some(position) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&old_position_key)
+        {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ActiveAt(dao_id, position), &new_address);
+            Self::move_entry::<u64>(
+                &env,
+                old_position_key,
+                DataKey::ActiveIndex(dao_id, new_address.clone()),
+            );
+        }
+
+        if let Some(tree) = Self::tree(env.clone()) {
+            env.invoke_contract::<()>(
+                &tree,
+                &Symbol::new(&env, "recover_from_sbt"),
+                soroban_sdk::vec![
+                    &env,
+                    dao_id.into_val(&env),
+                    old_address.clone().into_val(&env),
+                    new_address.clone().into_val(&env),
+                ],
+            );
+        }
+
+        SbtRecoveredEvent {
+            dao_id,
+            old_address,
+            new_address,
+            by_guardians,
+        }
+        .publish(&env);
+    }
+
+    // Internal: Whether `member` holds an SBT that is not revoked (it may have lapsed)
+    fn is_holder(env: &Env, dao_id: u64, member: &Address) -> bool {
+        let member_key = DataKey::Member(dao_id, member.clone());
         let revoked_key = DataKey::Revoked(dao_id, member.clone());
+        let has_sbt: bool = env.storage().persistent().get(&member_key).unwrap_or(false);
+        let is_revoked: bool = env
+            .storage()
+            .persistent()
+            .get(&revoked_key)
+            .unwrap_or(false);
+        has_sbt && !is_revoked
+    }
+
+    fn move_entry<V>(env: &Env, from: DataKey, to: DataKey)
+    where
+        V: IntoVal<Env, soroban_sdk::Val> + soroban_sdk::TryFromVal<Env, soroban_sdk::Val>,
+    {
+        if let Some(value) = env.storage().persistent().get::<DataKey, V>(&from) {
+            env.storage().persistent().set(&to, &value);
+            env.storage().persistent().remove(&from);
+        }
+    }
+
+    /// Self-join a DAO with open membership
+    /// Allows users to mint their own SBT if the DAO allows open membership, or while it is
+    /// closed if they hold enough of the registry's `token_gate` token
+    pub fn self_join(
+        env: Env,
+        dao_id: u64,
+        member: Address,
+        encrypted_alias: Option<soroban_sdk::String>,
+    ) {
+        // Member must authorize
+        member.require_auth();
+
+        // Check with registry if this DAO has open membership
+        let membership_open = Self::membership_open(&env, dao_id);
+
+        if !membership_open {
+            let Some(gate) = Self::token_gate(&env, dao_id) else {
+                panic_with_error!(&env, SbtError::NotOpenMembership);
+            };
+            if token::Client::new(&env, &gate.token).balance(&member) < gate.min_balance {
+                panic_with_error!(&env, SbtError::BelowTokenGate);
+            }
+        }
+
+        Self::mint_member(&env, dao_id, &member, encrypted_alias);
+        if !membership_open {
+            env.storage()
+                .persistent()
+                .set(&DataKey::GateJoined(dao_id, member.clone()), &true);
+        }
+        Self::charge_joining(&env, dao_id, &member);
+    }
+
+    /// Revoke a token-gated member whose balance fell below the gate
+    /// Permissionless, so one balance handed from address to address cannot keep several
+    /// SBTs alive: anyone can revoke each member `self_join` admitted through the gate once
+    /// they hold less than the current `min_balance`. Like `leave`, the member's tree leaves
+    /// are zeroed; they can `self_join` again after topping up. Only applies while
+    /// membership is closed and the gate is set.
+    pub fn revoke_below_gate(env: Env, dao_id: u64, member: Address) {
+        if !Self::is_holder(&env, dao_id, &member)
+            || !env
+                .storage()
+                .persistent()
+                .has(&DataKey::GateJoined(dao_id, member.clone()))
+            || Self::membership_open(&env, dao_id)
+        {
+            panic_with_error!(&env, SbtError::NotBelowTokenGate);
+        }
+        let Some(gate) = Self::token_gate(&env, dao_id) else {
+            panic_with_error!(&env, SbtError::NotBelowTokenGate);
+        };
+        if token::Client::new(&env, &gate.token).balance(&member) >= gate.min_balance {
+            panic_with_error!(&env, SbtError::NotBelowTokenGate);
+        }
+
+        Self::deactivate(&env, dao_id, &member, true);
+
+        // Zero the member's tree leaves so old roots stop proving membership
+        if let Some(tree) = Self::tree(env.clone()) {
+            env.invoke_contract::<()>(
+                &tree,
+                &Symbol::new(&env, "remove_departed"),
+                soroban_sdk::vec![&env, dao_id.into_val(&env), member.clone().into_val(&env)],
+            );
+        }
+
+        SbtRevokeEvent { dao_id, member }.publish(&env);
+    }
+
+    fn membership_open(env: &Env, dao_id: u64) -> bool {
+        env.invoke_contract(
+            &Self::registry_addr(env),
+            &Symbol::new(env, "is_membership_open"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        )
+    }
+
+    fn token_gate(env: &Env, dao_id: u64) -> Option<TokenGate> {
+        env.invoke_contract(
+            &Self::registry_addr(env),
+            &Symbol::new(env, "token_gate"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        )
+    }
+
+    /// Set how the DAO admits members by invite (admin only)
+    /// Either mechanism may be left `None`; with both unset `join_with_invite` is disabled.
+    /// Replacing the allowlist root does not revive invites that were already used.
+    pub fn set_invites(
+        env: Env,
+        dao_id: u64,
+        allowlist_root: Option<BytesN<32>>,
+        signer: Option<BytesN<32>>,
+        admin: Address,
+    ) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        let config = InviteConfig {
+            allowlist_root,
+            signer,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Invites(dao_id), &config);
+
+        SbtInviteConfigEvent { dao_id, config }.publish(&env);
+    }
+
+    /// Get the DAO's invite configuration
+    pub fn invites(env: Env, dao_id: u64) -> InviteConfig {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Invites(dao_id))
+            .unwrap_or(InviteConfig {
+                allowlist_root: None,
+                signer: None,
+            })
+    }
+
+    /// Check if an allowlist leaf or invite nonce has been used
+    pub fn invite_used(env: Env, dao_id: u64, invite_id: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::InviteUsed(dao_id, invite_id))
+    }
+
+    /// Join a DAO with an invite, even while membership is closed
+    /// Allowlist invites are consumed per address, signed invites per nonce. Signed invites
+    /// name the invitee, so a pending transaction cannot be replayed for another address.
+    pub fn join_with_invite(
+        env: Env,
+        dao_id: u64,
+        member: Address,
+        invite: Invite,
+        encrypted_alias: Option<soroban_sdk::String>,
+    ) {
+        member.require_auth();
+
+        let config = Self::invites(env.clone(), dao_id);
+        let invite_id = match invite {
+            Invite::Allowlist(proof) => {
+                let Some(root) = config.allowlist_root else {
+                    panic_with_error!(&env, SbtError::InvitesDisabled);
+                };
+                if proof.len() > MAX_INVITE_PROOF {
+                    panic_with_error!(&env, SbtError::InvalidInvite);
+                }
+
+                let leaf: BytesN<32> = env.crypto().sha256(&member.clone().to_xdr(&env)).into();
+                let mut node = leaf.clone();
+                for sibling in proof.iter() {
+                    let (left, right) = if node <= sibling {
+                        (node, sibling)
+                    } else {
+                        (sibling, node)
+                    };
+                    let mut pair = Bytes::from_array(&env, &left.to_array());
+                    pair.extend_from_array(&right.to_array());
+                    node = env.crypto().sha256(&pair).into();
+                }
+                if node != root {
+                    panic_with_error!(&env, SbtError::InvalidInvite);
+                }
+                leaf
+            }
+            Invite::Signed(nonce, signature) => {
+                let Some(signer) = config.signer else {
+                    panic_with_error!(&env, SbtError::InvitesDisabled);
+                };
+
+                // Traps if the signature does not match
+                let message = Self::invite_message(&env, dao_id, &member, &nonce);
+                env.crypto().ed25519_verify(&signer, &message, &signature);
+                nonce
+            }
+        };
+
+        let used_key = DataKey::InviteUsed(dao_id, invite_id.clone());
+        if env.storage().persistent().has(&used_key) {
+            panic_with_error!(&env, SbtError::InviteUsed);
+        }
+        env.storage().persistent().set(&used_key, &true);
+
+        Self::mint_member(&env, dao_id, &member, encrypted_alias);
+        Self::charge_joining(&env, dao_id, &member);
+
+        SbtInviteUsedEvent {
+            dao_id,
+            member,
+            invite_id,
+        }
+        .publish(&env);
+    }
+
+    /// Apply to join a DAO; the admin approves through the tree's `approve_application`
+    /// `application_cid` points at the applicant's off-chain request (max 64 bytes).
+    /// `commitment`, if given, is registered in the tree when the application is approved.
+    pub fn apply(
+        env: Env,
+        dao_id: u64,
+        applicant: Address,
+        application_cid: String,
+        commitment: Option<U256>,
+    ) {
+        applicant.require_auth();
+
+        if Self::has(env.clone(), dao_id, applicant.clone()) {
+            panic_with_error!(&env, SbtError::AlreadyMinted);
+        }
+        if application_cid.len() > MAX_APPLICATION_CID_LEN {
+            panic_with_error!(&env, SbtError::InvalidApplication);
+        }
+
+        let key = DataKey::Application(dao_id, applicant.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, SbtError::ApplicationExists);
+        }
+
+        let count = Self::application_count(env.clone(), dao_id);
+        if count >= MAX_PENDING_APPLICATIONS {
+            panic_with_error!(&env, SbtError::ApplicationQueueFull);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ApplicationAt(dao_id, count), &applicant);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ApplicationSlot(dao_id, applicant.clone()), &count);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ApplicationCount(dao_id), &(count + 1));
+
+        let application = Application {
+            applicant: applicant.clone(),
+            application_cid: application_cid.clone(),
+            commitment,
+            applied_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &application);
+
+        SbtApplicationEvent {
+            dao_id,
+            applicant,
+            application_cid,
+        }
+        .publish(&env);
+    }
+
+    /// Reject a pending application (admin only). The applicant may apply again.
+    pub fn reject(env: Env, dao_id: u64, applicant: Address, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        Self::reject_application(&env, dao_id, applicant);
+    }
+
+    /// Reject up to 8 pending applications at once (admin only)
+    /// Clears a queue flooded by throwaway addresses in a few calls; each applicant must
+    /// have a pending application.
+    pub fn reject_batch(env: Env, dao_id: u64, applicants: Vec<Address>, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        if applicants.len() > MAX_REJECT_BATCH {
+            panic_with_error!(&env, SbtError::BatchTooLarge);
+        }
+        for applicant in applicants.iter() {
+            Self::reject_application(&env, dao_id, applicant);
+        }
+    }
+
+    fn reject_application(env: &Env, dao_id: u64, applicant: Address) {
+        Self::take_application(env, dao_id, &applicant);
+
+        SbtApplicationDecidedEvent {
+            dao_id,
+            applicant,
+            approved: false,
+        }
+        .publish(env);
+    }
+
+    /// Approve a pending application and mint its SBT (bound tree only)
+    /// Called by the tree's `approve_application`, which checks the DAO admin and registers
+    /// the returned commitment.
+    pub fn approve_from_tree(env: Env, dao_id: u64, applicant: Address) -> Option<U256> {
+        let tree = Self::tree(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, SbtError::TreeNotBound));
+        tree.require_auth();
+
+        let application = Self::take_application(&env, dao_id, &applicant);
+        Self::mint_member(&env, dao_id, &applicant, None);
+
+        SbtApplicationDecidedEvent {
+            dao_id,
+            applicant,
+            approved: true,
+        }
+        .publish(&env);
+
+        application.commitment
+    }
+
+    /// Get an applicant's pending application
+    pub fn application(env: Env, dao_id: u64, applicant: Address) -> Option<Application> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Application(dao_id, applicant))
+    }
+
+    /// Get the number of pending applications
+    pub fn application_count(env: Env, dao_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ApplicationCount(dao_id))
+            .unwrap_or(0)
+    }
+
+    /// Get pending applications with pagination, by slot
+    /// New applications take the next slot; deciding one moves the last into its place.
+    pub fn pending_applications(
+        env: Env,
+        dao_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Application> {
+        let mut applications = Vec::new(&env);
+        let count = Self::application_count(env.clone(), dao_id);
+
+        let end = core::cmp::min(offset.saturating_add(limit), count);
+        for slot in offset..end {
+            if let Some(applicant) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Address>(&DataKey::ApplicationAt(dao_id, slot))
+            {
+                if let Some(application) = Self::application(env.clone(), dao_id, applicant) {
+                    applications.push_back(application);
+                }
+            }
+        }
+
+        applications
+    }
+
+    // Internal: Remove a pending application, moving the last pending one into its slot
+    // Touches a fixed handful of entries however long the list is.
+    fn take_application(env: &Env, dao_id: u64, applicant: &Address) -> Application {
+        let key = DataKey::Application(dao_id, applicant.clone());
+        let application: Application = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(env, SbtError::NoApplication));
+        env.storage().persistent().remove(&key);
+
+        let slot_key = DataKey::ApplicationSlot(dao_id, applicant.clone());
+        let slot: u32 = env
+            .storage()
+            .persistent()
+            .get(&slot_key)
+            .unwrap_or_else(|| panic_with_error!(env, SbtError::NoApplication));
+        env.storage().persistent().remove(&slot_key);
+
+        let last = Self::application_count(env.clone(), dao_id) - 1;
+        let last_key = DataKey::ApplicationAt(dao_id, last);
+        if slot != last {
+            let moved: Address = env
+                .storage()
+                .persistent()
+                .get(&last_key)
+                .unwrap_or_else(|| panic_with_error!(env, SbtError::NoApplication));
+            env.storage()
+                .persistent()
+                .set(&DataKey::ApplicationAt(dao_id, slot), &moved);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ApplicationSlot(dao_id, moved), &slot);
+        }
+        env.storage().persistent().remove(&last_key);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ApplicationCount(dao_id), &last);
+
+        application
+    }
+
+    /// Message the invite signer signs for `Invite::Signed`
+    pub fn invite_msg(env: Env, dao_id: u64, member: Address, nonce: BytesN<32>) -> Bytes {
+        Self::invite_message(&env, dao_id, &member, &nonce)
+    }
+
+    fn invite_message(env: &Env, dao_id: u64, member: &Address, nonce: &BytesN<32>) -> Bytes {
+        let payload = (
+            env.current_contract_address(),
+            dao_id,
+            member.clone(),
+            nonce.clone(),
+        );
+        let digest: BytesN<32> = env.crypto().sha256(&payload.to_xdr(env)).into();
+        digest.into()
+    }
+
+    // Internal: Mint an SBT, re-activating a revoked one
+    fn mint_member(
+        env: &Env,
+        dao_id: u64,
+        member: &Address,
+        encrypted_alias: Option<soroban_sdk::String>,
+    ) {
+        // Check if already has active SBT (not revoked)
+        if Self::has(env.clone(), dao_id, member.clone()) {
+            panic_with_error!(env, SbtError::AlreadyMinted);
+        }
+
+        let member_key = DataKey::Member(dao_id, member.clone());
 
         // Check if this is a new member (not just re-minting)
         let is_new_member = !env.storage().persistent().has(&member_key);
 
-        // Set member
-        env.storage().persistent().set(&member_key, &true);
-
-        // Clear revoked flag if it exists (allows re-joining)
-        if env.storage().persistent().has(&revoked_key) {
-            env.storage().persistent().remove(&revoked_key);
+        if is_new_member {
+            // A first SBT has no revocation, role or expiry to clear; skipping those
+            // writes keeps `mint_batch` within footprint limits
+            Self::count_active(env, dao_id);
+            Self::add_active_slot(env, dao_id, member);
+            env.storage().persistent().set(&member_key, &true);
+            if let Some(expires_at) = Self::term_end(env, dao_id) {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Expiry(dao_id, member.clone()), &expires_at);
+            }
+        } else {
+            // Count as active; clears the revoked flag of a re-minted SBT
+            Self::activate(env, dao_id, member);
+
+            // Set member; a re-minted SBT starts over as a plain member, admitted by
+            // whichever path minted it this time
+            env.storage().persistent().set(&member_key, &true);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Role(dao_id, member.clone()));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::GateJoined(dao_id, member.clone()));
+            Self::start_term(env, dao_id, member);
         }
 
         // Store encrypted alias if provided
         if let Some(alias) = encrypted_alias {
-            let alias_key = DataKey::Alias(dao_id, member.clone());
-            env.storage().persistent().set(&alias_key, &alias);
+            Self::store_alias(env, dao_id, member, &alias);
         }
 
         // Add to enumeration list if new member
         if is_new_member {
-            Self::add_member_to_list(&env, dao_id, &member);
+            Self::add_member_to_list(env, dao_id, member);
         }
 
-        SbtMintEvent { dao_id, to: member }.publish(&env);
+        SbtMintEvent {
+            dao_id,
+            to: member.clone(),
+        }
+        .publish(env);
     }
 
     /// Update encrypted alias for a member (admin only)
+    /// The alias is recorded as encrypted to the DAO's current alias key.
     pub fn update_alias(
         env: Env,
         dao_id: u64,
@@ -345,13 +1632,11 @@ impl MembershipSbt {
         }
 
         // Update alias
-        let alias_key = DataKey::Alias(dao_id, member);
-        env.storage()
-            .persistent()
-            .set(&alias_key, &new_encrypted_alias);
+        Self::store_alias(&env, dao_id, &member, &new_encrypted_alias);
     }
 
-    /// Get total member count for a DAO
+    /// Get the number of addresses that ever joined a DAO (historical)
+    /// Includes revoked, departed and lapsed members; see `active_member_count`.
     pub fn get_member_count(env: Env, dao_id: u64) -> u64 {
         let count_key = DataKey::MemberCount(dao_id);
         env.storage().persistent().get(&count_key).unwrap_or(0)
@@ -363,8 +1648,8 @@ impl MembershipSbt {
         env.storage().persistent().get(&index_key)
     }
 
-    /// Get a batch of members for a DAO
-    /// Returns addresses from offset to offset+limit (or end of list)
+    /// Get a batch of members for a DAO from the historical list
+    /// Returns addresses from offset to offset+limit (or end of list), in join order
     pub fn get_members(
         env: Env,
         dao_id: u64,
@@ -386,6 +1671,97 @@ impl MembershipSbt {
         members
     }
 
+    /// Get the number of active members of a DAO
+    /// Counts SBTs that are not revoked or marked lapsed (see `mark_lapsed`).
+    pub fn active_member_count(env: Env, dao_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ActiveCount(dao_id))
+            .unwrap_or(0)
+    }
+
+    /// Get the number of slots in a DAO's active list, tombstones included
+    pub fn active_list_len(env: Env, dao_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ActiveLen(dao_id))
+            .unwrap_or(0)
+    }
+
+    /// Get a batch of active members for a DAO
+    /// `offset` and `limit` count slots of the active list (see `active_list_len`);
+    /// tombstones left by revocations, departures and lapses are skipped, so a page can
+    /// hold fewer than `limit` members until `compact_active` runs.
+    pub fn get_active_members(env: Env, dao_id: u64, offset: u64, limit: u64) -> Vec<Address> {
+        let mut members = Vec::new(&env);
+        let len = Self::active_list_len(env.clone(), dao_id);
+        let end = core::cmp::min(offset.saturating_add(limit), len);
+
+        for slot in offset..end {
+            if let Some(member) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Address>(&DataKey::ActiveAt(dao_id, slot))
+            {
+                if Self::is_active(&env, dao_id, &member) {
+                    members.push_back(member);
+                }
+            }
+        }
+        members
+    }
+
+    /// Drop tombstones from a DAO's active list (permissionless)
+    /// Checks up to `limit` (at most 6) slots from `offset`; each tombstone is replaced by
+    /// the list's last slot, so later slots can move forward. Returns how many were dropped.
+    pub fn compact_active(env: Env, dao_id: u64, offset: u64, limit: u32) -> u32 {
+        if limit > MAX_COMPACT_BATCH {
+            panic_with_error!(&env, SbtError::BatchTooLarge);
+        }
+
+        let len_key = DataKey::ActiveLen(dao_id);
+        let mut len = Self::active_list_len(env.clone(), dao_id);
+        let mut slot = offset;
+        let mut dropped = 0;
+        for _ in 0..limit {
+            if slot >= len {
+                break;
+            }
+            let slot_key = DataKey::ActiveAt(dao_id, slot);
+            let member: Address = env
+                .storage()
+                .persistent()
+                .get(&slot_key)
+                .unwrap_or_else(|| panic_with_error!(&env, SbtError::NotMember));
+            if Self::is_active(&env, dao_id, &member) {
+                slot += 1;
+                continue;
+            }
+
+            // Swap-remove; the moved member is checked next
+            len -= 1;
+            let last_key = DataKey::ActiveAt(dao_id, len);
+            if slot != len {
+                let moved: Address = env
+                    .storage()
+                    .persistent()
+                    .get(&last_key)
+                    .unwrap_or_else(|| panic_with_error!(&env, SbtError::NotMember));
+                env.storage().persistent().set(&slot_key, &moved);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::ActiveIndex(dao_id, moved), &slot);
+            }
+            env.storage().persistent().remove(&last_key);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ActiveIndex(dao_id, member));
+            dropped += 1;
+        }
+        env.storage().persistent().set(&len_key, &len);
+        dropped
+    }
+
     /// Contract version for upgrade tracking.
     pub fn version(env: Env) -> u32 {
         env.storage()
@@ -1,7 +1,11 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, testutils::Events as _, Env};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::Address as _, testutils::Events as _, testutils::Ledger as _, testutils::MockAuth,
+    testutils::MockAuthInvoke, Env,
+};
 
 // Mock registry contract for testing
 mod mock_registry {
@@ -31,6 +35,26 @@ mod mock_registry {
             let open_key = (Symbol::new(&env, "is_open"), dao_id);
             env.storage().persistent().get(&open_key).unwrap_or(false)
         }
+
+        pub fn set_dues(env: Env, dao_id: u64, config: crate::DuesConfig) {
+            let dues_key = (symbol_short!("dues"), dao_id);
+            env.storage().persistent().set(&dues_key, &config);
+        }
+
+        pub fn dues(env: Env, dao_id: u64) -> Option<crate::DuesConfig> {
+            let dues_key = (symbol_short!("dues"), dao_id);
+            env.storage().persistent().get(&dues_key)
+        }
+
+        pub fn set_token_gate(env: Env, dao_id: u64, gate: crate::TokenGate) {
+            let gate_key = (symbol_short!("gate"), dao_id);
+            env.storage().persistent().set(&gate_key, &gate);
+        }
+
+        pub fn token_gate(env: Env, dao_id: u64) -> Option<crate::TokenGate> {
+            let gate_key = (symbol_short!("gate"), dao_id);
+            env.storage().persistent().get(&gate_key)
+        }
     }
 }
 
@@ -42,7 +66,10 @@ fn setup_env() -> (Env, Address, Address, Address, Address) {
     let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
 
     // Register SBT with constructor
-    let sbt_id = env.register(MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
     let _sbt_client = MembershipSbtClient::new(&env, &sbt_id);
 
     let admin = Address::generate(&env);
@@ -60,7 +87,10 @@ fn test_constructor() {
     env.mock_all_auths();
 
     let registry_id = env.register(mock_registry::MockRegistry, ());
-    let sbt_id = env.register(MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
     let client = MembershipSbtClient::new(&env, &sbt_id);
 
     assert_eq!(client.registry(), registry_id);
@@ -112,6 +142,60 @@ fn test_mint_multiple_members_same_dao() {
     assert!(client.has(&1u64, &member3));
 }
 
+#[test]
+fn test_mint_batch() {
+    let (env, sbt_id, _, admin, member1) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let alias = soroban_sdk::String::from_str(&env, "encrypted");
+    client.mint(&1u64, &member1, &admin, &None);
+
+    // Existing holders are skipped and reported; the rest are minted
+    let skipped = client.mint_batch(
+        &1u64,
+        &soroban_sdk::vec![
+            &env,
+            (member1.clone(), None),
+            (member2.clone(), Some(alias.clone())),
+            (member3.clone(), None),
+        ],
+        &admin,
+    );
+    assert_eq!(skipped, soroban_sdk::vec![&env, member1.clone()]);
+    assert!(client.has(&1u64, &member2));
+    assert!(client.has(&1u64, &member3));
+    assert_eq!(client.get_alias(&1u64, &member2), Some(alias));
+    assert_eq!(client.get_member_count(&1u64), 3);
+    assert_eq!(client.get_member_at_index(&1u64, &2u64), Some(member3));
+}
+
+#[test]
+fn test_mint_batch_limits() {
+    let (env, sbt_id, _, admin, _) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..MAX_MINT_BATCH {
+        members.push_back((Address::generate(&env), None));
+    }
+    assert!(client.mint_batch(&1u64, &members, &admin).is_empty());
+    assert_eq!(client.get_member_count(&1u64), MAX_MINT_BATCH as u64);
+
+    members.push_back((Address::generate(&env), None));
+    assert_eq!(
+        client.try_mint_batch(&1u64, &members, &admin),
+        Err(Ok(SbtError::BatchTooLarge.into()))
+    );
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_mint_batch(&1u64, &Vec::new(&env), &outsider),
+        Err(Ok(SbtError::NotDaoAdmin.into()))
+    );
+}
+
 #[test]
 fn test_same_member_different_daos() {
     let (env, sbt_id, registry_id, admin, member) = setup_env();
@@ -297,3 +381,745 @@ fn test_self_join_twice_fails() {
     client.self_join(&2u64, &new_member, &None);
     client.self_join(&2u64, &new_member, &None); // Should panic
 }
+
+#[test]
+fn test_term_sets_expiry_and_lapses() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    env.ledger().set_timestamp(1_000);
+
+    client.set_term(&1u64, &3_600, &admin);
+    assert_eq!(client.get_term(&1u64), 3_600);
+    client.mint(&1u64, &member, &admin, &None);
+    assert_eq!(client.expires_at(&1u64, &member), Some(4_600));
+    assert!(client.has(&1u64, &member));
+
+    env.ledger().set_timestamp(4_600);
+    assert!(!client.has(&1u64, &member));
+
+    // A lapsed member can be renewed without re-minting
+    client.renew(&1u64, &member, &10_000, &admin);
+    assert_eq!(client.expires_at(&1u64, &member), Some(10_000));
+    assert!(client.has(&1u64, &member));
+}
+
+#[test]
+fn test_permanent_membership_has_no_expiry() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+
+    client.mint(&1u64, &member, &admin, &None);
+    assert_eq!(client.expires_at(&1u64, &member), None);
+    env.ledger().set_timestamp(u64::MAX);
+    assert!(client.has(&1u64, &member));
+}
+
+#[test]
+fn test_renew_rejects_past_expiry_and_revoked_members() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    env.ledger().set_timestamp(1_000);
+    client.mint(&1u64, &member, &admin, &None);
+
+    assert_eq!(
+        client.try_renew(&1u64, &member, &1_000, &admin),
+        Err(Ok(SbtError::InvalidExpiry.into()))
+    );
+
+    client.revoke(&1u64, &member, &admin);
+    assert_eq!(
+        client.try_renew(&1u64, &member, &2_000, &admin),
+        Err(Ok(SbtError::NotMember.into()))
+    );
+}
+
+#[test]
+fn test_bind_tree_needs_deployer() {
+    let env = Env::default();
+    let registry_id = env.register(mock_registry::MockRegistry, ());
+    let deployer = Address::generate(&env);
+    let sbt_id = env.register(MembershipSbt, (registry_id, deployer.clone()));
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+
+    // Someone racing the real tree deployment cannot bind their own contract
+    let squatter = Address::generate(&env);
+    let squatter_auth = MockAuth {
+        address: &squatter,
+        invoke: &MockAuthInvoke {
+            contract: &sbt_id,
+            fn_name: "bind_tree",
+            args: (squatter.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    };
+    assert!(client
+        .mock_auths(&[squatter_auth])
+        .try_bind_tree(&squatter)
+        .is_err());
+    assert_eq!(client.tree(), None);
+
+    env.mock_all_auths();
+    let tree = Address::generate(&env);
+    client.bind_tree(&tree);
+    assert!(env.auths().iter().any(|(address, _)| *address == deployer));
+    assert_eq!(client.tree(), Some(tree));
+}
+
+#[test]
+fn test_bind_tree_only_once() {
+    let (env, sbt_id, _, _, _) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    assert_eq!(client.tree(), None);
+
+    let tree = Address::generate(&env);
+    client.bind_tree(&tree);
+    assert_eq!(client.tree(), Some(tree.clone()));
+    assert_eq!(
+        client.try_bind_tree(&Address::generate(&env)),
+        Err(Ok(SbtError::AlreadyInitialized.into()))
+    );
+}
+
+#[test]
+fn test_revoke_from_tree_requires_bound_tree() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    client.mint(&1u64, &member, &admin, &None);
+
+    assert_eq!(
+        client.try_revoke_from_tree(&1u64, &member),
+        Err(Ok(SbtError::TreeNotBound.into()))
+    );
+
+    client.bind_tree(&Address::generate(&env));
+    client.revoke_from_tree(&1u64, &member);
+    assert!(!client.has(&1u64, &member));
+    assert_eq!(
+        client.try_revoke_from_tree(&1u64, &Address::generate(&env)),
+        Err(Ok(SbtError::NotMember.into()))
+    );
+}
+
+#[test]
+fn test_reinstate_clears_revocation() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+
+    let alias = soroban_sdk::String::from_str(&env, "enc-alias");
+    client.mint(&1u64, &member, &admin, &Some(alias.clone()));
+    client.revoke(&1u64, &member, &admin);
+    assert_eq!(
+        client.try_reinstate(&1u64, &member),
+        Err(Ok(SbtError::TreeNotBound.into()))
+    );
+
+    client.bind_tree(&Address::generate(&env));
+    client.reinstate(&1u64, &member);
+    assert!(client.has(&1u64, &member));
+    assert_eq!(client.get_alias(&1u64, &member), Some(alias));
+    assert_eq!(client.get_member_count(&1u64), 1);
+
+    assert_eq!(
+        client.try_reinstate(&1u64, &Address::generate(&env)),
+        Err(Ok(SbtError::NotMember.into()))
+    );
+}
+
+#[test]
+fn test_active_members_skip_and_compact_tombstones() {
+    let (env, sbt_id, _, admin, member1) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let member4 = Address::generate(&env);
+    for member in [&member1, &member2, &member3, &member4] {
+        client.mint(&1u64, member, &admin, &None);
+    }
+    assert_eq!(client.active_member_count(&1u64), 4);
+
+    // Revoking and leaving uncount members and leave tombstones behind
+    client.revoke(&1u64, &member2, &admin);
+    client.leave(&1u64, &member1);
+    client.revoke(&1u64, &member2, &admin);
+    assert_eq!(client.active_member_count(&1u64), 2);
+    assert_eq!(client.active_list_len(&1u64), 4);
+    assert_eq!(
+        client.get_active_members(&1u64, &0u64, &2u64),
+        Vec::<Address>::new(&env)
+    );
+    assert_eq!(
+        client.get_active_members(&1u64, &0u64, &10u64),
+        soroban_sdk::vec![&env, member3.clone(), member4.clone()]
+    );
+
+    // Compaction swaps the last slots into the tombstones
+    assert_eq!(client.compact_active(&1u64, &0u64, &6u32), 2);
+    assert_eq!(client.active_list_len(&1u64), 2);
+    assert_eq!(
+        client.get_active_members(&1u64, &0u64, &10u64),
+        soroban_sdk::vec![&env, member4.clone(), member3.clone()]
+    );
+    assert_eq!(client.compact_active(&1u64, &0u64, &6u32), 0);
+
+    // History keeps everyone; re-minting and reinstating count members again
+    client.mint(&1u64, &member1, &admin, &None);
+    client.bind_tree(&Address::generate(&env));
+    client.reinstate(&1u64, &member2);
+    assert_eq!(client.active_member_count(&1u64), 4);
+    assert_eq!(client.get_active_members(&1u64, &0u64, &10u64).len(), 4);
+    assert_eq!(client.get_member_count(&1u64), 4);
+    assert_eq!(
+        client.try_compact_active(&1u64, &0u64, &7u32),
+        Err(Ok(SbtError::BatchTooLarge.into()))
+    );
+}
+
+#[test]
+fn test_active_members_drop_lapsed_until_renewed() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    env.ledger().set_timestamp(1_000);
+
+    client.set_term(&1u64, &3_600, &admin);
+    client.mint(&1u64, &member, &admin, &None);
+    env.ledger().set_timestamp(4_600);
+    assert_eq!(client.active_member_count(&1u64), 1);
+
+    client.mark_lapsed(&1u64, &member);
+    assert_eq!(client.active_member_count(&1u64), 0);
+    assert!(client.get_active_members(&1u64, &0u64, &10u64).is_empty());
+    client.mark_lapsed(&1u64, &member);
+    assert_eq!(client.active_member_count(&1u64), 0);
+    assert!(!client.has(&1u64, &member));
+
+    client.renew(&1u64, &member, &10_000, &admin);
+    assert_eq!(
+        client.get_active_members(&1u64, &0u64, &10u64),
+        soroban_sdk::vec![&env, member.clone()]
+    );
+}
+
+#[test]
+fn test_set_role_tiers_and_reset_on_remint() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+
+    assert_eq!(client.role(&1u64, &member), None);
+    assert_eq!(
+        client.try_set_role(&1u64, &member, &ROLE_MODERATOR, &admin),
+        Err(Ok(SbtError::NotMember.into()))
+    );
+
+    client.mint(&1u64, &member, &admin, &None);
+    assert_eq!(client.role(&1u64, &member), Some(ROLE_MEMBER));
+    assert!(client.has_role(&1u64, &member, &ROLE_MEMBER));
+    assert!(!client.has_role(&1u64, &member, &ROLE_MODERATOR));
+
+    client.set_role(&1u64, &member, &ROLE_COUNCIL, &admin);
+    let events = env.events().all();
+    let mut role_events = 0u32;
+    for event in events.iter() {
+        if event.0 == sbt_id {
+            role_events += 1;
+        }
+    }
+    assert_eq!(role_events, 1);
+    assert!(client.has_role(&1u64, &member, &ROLE_MODERATOR));
+    assert!(client.has_role(&1u64, &member, &ROLE_COUNCIL));
+
+    // Only the DAO admin assigns roles
+    assert!(client
+        .try_set_role(&1u64, &member, &ROLE_MEMBER, &member)
+        .is_err());
+
+    // A revoked SBT carries no role, and a fresh mint starts over as a member
+    client.revoke(&1u64, &member, &admin);
+    assert_eq!(client.role(&1u64, &member), None);
+    assert!(!client.has_role(&1u64, &member, &ROLE_MEMBER));
+    client.mint(&1u64, &member, &admin, &None);
+    assert_eq!(client.role(&1u64, &member), Some(ROLE_MEMBER));
+}
+
+fn invite_leaf(env: &Env, member: &Address) -> BytesN<32> {
+    env.crypto().sha256(&member.clone().to_xdr(env)).into()
+}
+
+fn invite_node(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = Bytes::from_array(env, &left.to_array());
+    pair.extend_from_array(&right.to_array());
+    env.crypto().sha256(&pair).into()
+}
+
+#[test]
+fn test_join_with_allowlist_invite() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let friend = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let member_leaf = invite_leaf(&env, &member);
+    let friend_leaf = invite_leaf(&env, &friend);
+    let root = invite_node(&env, &member_leaf, &friend_leaf);
+    let proof = soroban_sdk::vec![&env, friend_leaf.clone()];
+
+    assert_eq!(
+        client.try_join_with_invite(&1u64, &member, &Invite::Allowlist(proof.clone()), &None),
+        Err(Ok(SbtError::InvitesDisabled.into()))
+    );
+
+    client.set_invites(&1u64, &Some(root.clone()), &None, &admin);
+    assert_eq!(client.invites(&1u64).allowlist_root, Some(root));
+
+    // A proof only works for the address it was built for
+    assert_eq!(
+        client.try_join_with_invite(&1u64, &stranger, &Invite::Allowlist(proof.clone()), &None),
+        Err(Ok(SbtError::InvalidInvite.into()))
+    );
+
+    client.join_with_invite(&1u64, &member, &Invite::Allowlist(proof.clone()), &None);
+    assert!(client.has(&1u64, &member));
+    assert!(client.invite_used(&1u64, &member_leaf));
+    assert!(!client.invite_used(&1u64, &friend_leaf));
+
+    // Invites are one-time: a revoked member cannot rejoin with the same entry
+    client.revoke(&1u64, &member, &admin);
+    assert_eq!(
+        client.try_join_with_invite(&1u64, &member, &Invite::Allowlist(proof), &None),
+        Err(Ok(SbtError::InviteUsed.into()))
+    );
+
+    let friend_proof = soroban_sdk::vec![&env, member_leaf];
+    client.join_with_invite(&1u64, &friend, &Invite::Allowlist(friend_proof), &None);
+    assert_eq!(client.get_member_count(&1u64), 2);
+}
+
+#[test]
+fn test_join_with_signed_invite() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let other = Address::generate(&env);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let signer = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+    client.set_invites(&1u64, &None, &Some(signer), &admin);
+
+    let sign = |who: &Address, nonce: &BytesN<32>| -> BytesN<64> {
+        let mut digest = [0u8; 32];
+        client
+            .invite_msg(&1u64, who, nonce)
+            .copy_into_slice(&mut digest);
+        BytesN::from_array(&env, &key.sign(&digest).to_bytes())
+    };
+
+    let nonce = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign(&member, &nonce);
+
+    // The invite names its invitee, so nobody else can redeem it
+    assert!(client
+        .try_join_with_invite(
+            &1u64,
+            &other,
+            &Invite::Signed(nonce.clone(), signature.clone()),
+            &None
+        )
+        .is_err());
+
+    client.join_with_invite(
+        &1u64,
+        &member,
+        &Invite::Signed(nonce.clone(), signature),
+        &None,
+    );
+    assert!(client.has(&1u64, &member));
+    assert!(client.invite_used(&1u64, &nonce));
+
+    // Nonces are one-time even when re-signed for someone else
+    assert_eq!(
+        client.try_join_with_invite(
+            &1u64,
+            &other,
+            &Invite::Signed(nonce.clone(), sign(&other, &nonce)),
+            &None
+        ),
+        Err(Ok(SbtError::InviteUsed.into()))
+    );
+
+    let fresh = BytesN::from_array(&env, &[2u8; 32]);
+    client.join_with_invite(
+        &1u64,
+        &other,
+        &Invite::Signed(fresh.clone(), sign(&other, &fresh)),
+        &None,
+    );
+    assert!(client.has(&1u64, &other));
+}
+
+#[test]
+fn test_applications_queue_reject_and_approve() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let other = Address::generate(&env);
+    let late = Address::generate(&env);
+    let cid = soroban_sdk::String::from_str(&env, "QmApplication");
+    let commitment = U256::from_u32(&env, 42);
+
+    client.apply(&1u64, &member, &cid, &Some(commitment.clone()));
+    client.apply(&1u64, &other, &cid, &None);
+    client.apply(&1u64, &late, &cid, &None);
+    assert_eq!(
+        client.try_apply(&1u64, &member, &cid, &None),
+        Err(Ok(SbtError::ApplicationExists.into()))
+    );
+    assert_eq!(client.application_count(&1u64), 3);
+
+    let page = client.pending_applications(&1u64, &1u32, &5u32);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get_unchecked(0).applicant, other);
+    assert_eq!(page.get_unchecked(1).applicant, late);
+
+    // The last application moves into the rejected one's slot, and a rejected applicant
+    // may apply again, at the back of the queue
+    client.reject(&1u64, &other, &admin);
+    assert_eq!(client.application(&1u64, &other), None);
+    let page = client.pending_applications(&1u64, &0u32, &10u32);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get_unchecked(1).applicant, late);
+    assert_eq!(
+        client.try_reject(&1u64, &other, &admin),
+        Err(Ok(SbtError::NoApplication.into()))
+    );
+    client.apply(&1u64, &other, &cid, &None);
+    let page = client.pending_applications(&1u64, &0u32, &10u32);
+    assert_eq!(page.get_unchecked(2).applicant, other);
+
+    // Approval goes through the bound tree, which registers the returned commitment
+    assert_eq!(
+        client.try_approve_from_tree(&1u64, &member),
+        Err(Ok(SbtError::TreeNotBound.into()))
+    );
+    client.bind_tree(&Address::generate(&env));
+    assert_eq!(client.approve_from_tree(&1u64, &member), Some(commitment));
+    assert!(client.has(&1u64, &member));
+    assert_eq!(client.application_count(&1u64), 2);
+    assert_eq!(
+        client.try_apply(&1u64, &member, &cid, &None),
+        Err(Ok(SbtError::AlreadyMinted.into()))
+    );
+}
+
+#[test]
+fn test_join_fee_and_dues_extend_membership() {
+    let (env, sbt_id, registry_id, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    registry_client.set_membership_open(&1u64, &true);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_client = soroban_sdk::token::Client::new(&env, &sac.address());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&member, &1_000);
+    let treasury = Address::generate(&env);
+
+    // Joining a free DAO makes dues unpayable
+    assert_eq!(
+        client.try_pay_dues(&1u64, &member, &1u32),
+        Err(Ok(SbtError::DuesNotRequired.into()))
+    );
+
+    registry_client.set_dues(
+        &1u64,
+        &DuesConfig {
+            token: sac.address(),
+            treasury: treasury.clone(),
+            join_fee: 100,
+            dues: 50,
+            period: 1_000,
+        },
+    );
+    env.ledger().set_timestamp(10_000);
+
+    // Joining pays the fee and the first period
+    client.self_join(&1u64, &member, &None);
+    assert_eq!(token_client.balance(&treasury), 150);
+    assert_eq!(client.expires_at(&1u64, &member), Some(11_000));
+    assert_eq!(
+        client.try_mark_lapsed(&1u64, &member),
+        Err(Ok(SbtError::NotLapsed.into()))
+    );
+
+    // Unpaid dues lapse the SBT; paying restores it from the payment time
+    env.ledger().set_timestamp(12_000);
+    assert!(!client.has(&1u64, &member));
+    client.mark_lapsed(&1u64, &member);
+    assert_eq!(client.pay_dues(&1u64, &member, &2u32), 14_000);
+    assert!(client.has(&1u64, &member));
+    assert_eq!(token_client.balance(&treasury), 250);
+
+    // Paying ahead stacks on the current expiry
+    assert_eq!(client.pay_dues(&1u64, &member, &1u32), 15_000);
+    assert_eq!(token_client.balance(&member), 700);
+
+    // Admin mints are never charged
+    let minted = Address::generate(&env);
+    client.mint(&1u64, &minted, &admin, &None);
+    assert_eq!(token_client.balance(&treasury), 300);
+}
+
+#[test]
+fn test_token_gate_admits_holders_to_closed_dao() {
+    let (env, sbt_id, registry_id, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let outsider = Address::generate(&env);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &sac.address());
+    token_admin.mint(&member, &100);
+    token_admin.mint(&outsider, &99);
+
+    assert_eq!(
+        client.try_self_join(&1u64, &member, &None),
+        Err(Ok(SbtError::NotOpenMembership.into()))
+    );
+
+    registry_client.set_token_gate(
+        &1u64,
+        &TokenGate {
+            token: sac.address(),
+            min_balance: 100,
+        },
+    );
+    client.self_join(&1u64, &member, &None);
+    assert!(client.has(&1u64, &member));
+    assert_eq!(
+        client.try_self_join(&1u64, &outsider, &None),
+        Err(Ok(SbtError::BelowTokenGate.into()))
+    );
+}
+
+#[test]
+fn test_revoke_below_gate_only_while_gated() {
+    let (env, sbt_id, registry_id, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let buyer = Address::generate(&env);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&member, &100);
+    registry_client.set_token_gate(
+        &1u64,
+        &TokenGate {
+            token: sac.address(),
+            min_balance: 100,
+        },
+    );
+    client.self_join(&1u64, &member, &None);
+    soroban_sdk::token::Client::new(&env, &sac.address()).transfer(&member, &buyer, &100);
+
+    // Once the DAO opens up, the gate no longer decides who stays
+    registry_client.set_membership_open(&1u64, &true);
+    assert_eq!(
+        client.try_revoke_below_gate(&1u64, &member),
+        Err(Ok(SbtError::NotBelowTokenGate.into()))
+    );
+
+    registry_client.set_membership_open(&1u64, &false);
+    client.revoke_below_gate(&1u64, &member);
+    assert!(!client.has(&1u64, &member));
+    assert_eq!(
+        client.try_revoke_below_gate(&1u64, &member),
+        Err(Ok(SbtError::NotBelowTokenGate.into()))
+    );
+}
+
+#[test]
+fn test_recover_by_old_address_or_guardian_quorum() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let alias = soroban_sdk::String::from_str(&env, "enc-alias");
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.mint(&1u64, &stranger, &admin, &None);
+    client.set_alias_key(&1u64, &BytesN::from_array(&env, &[7u8; 32]), &admin);
+    client.mint(&1u64, &member, &admin, &Some(alias.clone()));
+    client.set_role(&1u64, &member, &ROLE_MODERATOR, &admin);
+
+    let guardians = Guardians {
+        guardians: soroban_sdk::vec![&env, first.clone(), second.clone(), stranger.clone()],
+        threshold: 2,
+    };
+    assert_eq!(
+        client.try_set_guardians(
+            &1u64,
+            &member,
+            &Some(Guardians {
+                guardians: guardians.guardians.clone(),
+                threshold: 4,
+            })
+        ),
+        Err(Ok(SbtError::InvalidGuardians.into()))
+    );
+    client.set_guardians(&1u64, &member, &Some(guardians.clone()));
+
+    // The old address approves a move to a fresh wallet
+    let wallet = Address::generate(&env);
+    assert_eq!(
+        client.try_recover(
+            &1u64,
+            &member,
+            &wallet,
+            &0u64,
+            &soroban_sdk::vec![&env, member.clone()],
+            &admin
+        ),
+        Err(Ok(SbtError::MemberIndexMismatch.into()))
+    );
+    client.recover(
+        &1u64,
+        &member,
+        &wallet,
+        &1u64,
+        &soroban_sdk::vec![&env, member.clone()],
+        &admin,
+    );
+    assert!(!client.has(&1u64, &member));
+    assert!(client.has(&1u64, &wallet));
+    assert_eq!(
+        client.get_alias_record(&1u64, &wallet),
+        Some(AliasRecord {
+            encrypted_alias: alias,
+            key_version: 1,
+        })
+    );
+    assert_eq!(client.role(&1u64, &wallet), Some(ROLE_MODERATOR));
+    assert_eq!(client.guardians(&1u64, &wallet), Some(guardians));
+    assert_eq!(
+        client.get_member_at_index(&1u64, &1u64),
+        Some(wallet.clone())
+    );
+    assert_eq!(client.get_member_count(&1u64), 2);
+
+    // Once that wallet is lost too, one guardian is not enough but two are
+    let replacement = Address::generate(&env);
+    assert_eq!(
+        client.try_recover(
+            &1u64,
+            &wallet,
+            &replacement,
+            &1u64,
+            &soroban_sdk::vec![&env, first.clone()],
+            &admin
+        ),
+        Err(Ok(SbtError::RecoveryNotApproved.into()))
+    );
+    assert_eq!(
+        client.try_recover(
+            &1u64,
+            &wallet,
+            &replacement,
+            &1u64,
+            &soroban_sdk::vec![&env, first.clone(), first.clone()],
+            &admin
+        ),
+        Err(Ok(SbtError::RecoveryNotApproved.into()))
+    );
+    // Existing members cannot absorb another membership
+    assert_eq!(
+        client.try_recover(
+            &1u64,
+            &wallet,
+            &stranger,
+            &1u64,
+            &soroban_sdk::vec![&env, first.clone(), second.clone()],
+            &admin
+        ),
+        Err(Ok(SbtError::AlreadyMinted.into()))
+    );
+    client.recover(
+        &1u64,
+        &wallet,
+        &replacement,
+        &1u64,
+        &soroban_sdk::vec![&env, first, second],
+        &admin,
+    );
+    assert!(client.has(&1u64, &replacement));
+    assert!(!client.has(&1u64, &wallet));
+}
+
+#[test]
+fn test_alias_key_rotation_versions_aliases() {
+    let (env, sbt_id, _, admin, member) = setup_env();
+    let client = MembershipSbtClient::new(&env, &sbt_id);
+    let before_key = soroban_sdk::String::from_str(&env, "plain-ish");
+    let first_cipher = soroban_sdk::String::from_str(&env, "sealed-to-v1");
+    let second_cipher = soroban_sdk::String::from_str(&env, "sealed-to-v2");
+    let other = Address::generate(&env);
+
+    // Aliases stored before the DAO publishes a key carry version 0
+    assert_eq!(client.alias_key(&1u64), None);
+    client.mint(&1u64, &member, &admin, &Some(before_key.clone()));
+    assert_eq!(
+        client.get_alias_record(&1u64, &member),
+        Some(AliasRecord {
+            encrypted_alias: before_key,
+            key_version: 0,
+        })
+    );
+    assert_eq!(client.get_alias_record(&1u64, &other), None);
+
+    let first_key = BytesN::from_array(&env, &[1u8; 32]);
+    assert_eq!(
+        client.try_set_alias_key(&1u64, &first_key, &other),
+        Err(Ok(SbtError::NotDaoAdmin.into()))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.set_alias_key(&1u64, &first_key, &admin), 1);
+    assert_eq!(
+        client.alias_key(&1u64),
+        Some(AliasKey {
+            version: 1,
+            public_key: first_key,
+            set_at: 500,
+        })
+    );
+
+    // New and updated aliases are recorded against the current key
+    client.mint(&1u64, &other, &admin, &Some(first_cipher.clone()));
+    client.update_alias(&1u64, &member, &admin, &first_cipher);
+    assert_eq!(
+        client.get_alias_record(&1u64, &member).unwrap().key_version,
+        1
+    );
+
+    // Rotation tells clients to re-encrypt; old aliases keep their version until then
+    let second_key = BytesN::from_array(&env, &[2u8; 32]);
+    assert_eq!(client.set_alias_key(&1u64, &second_key, &admin), 2);
+    let events = env.events().all();
+    let mut key_events = 0u32;
+    for event in events.iter() {
+        if event.0 == sbt_id {
+            key_events += 1;
+        }
+    }
+    assert_eq!(key_events, 1);
+    assert_eq!(
+        client.get_alias_record(&1u64, &other),
+        Some(AliasRecord {
+            encrypted_alias: first_cipher,
+            key_version: 1,
+        })
+    );
+    client.update_alias(&1u64, &other, &admin, &second_cipher);
+    assert_eq!(
+        client.get_alias_record(&1u64, &other),
+        Some(AliasRecord {
+            encrypted_alias: second_cipher.clone(),
+            key_version: 2,
+        })
+    );
+    assert_eq!(client.get_alias(&1u64, &other), Some(second_cipher));
+}
@@ -0,0 +1,199 @@
+//! # Membership Token View
+//!
+//! Read-only SEP-41 view of one DAO's membership SBTs, so wallets and explorers can show
+//! memberships like any other token. `MembershipSbt` serves every DAO from one contract,
+//! while a SEP-41 token has a single `balance(id)`, so one view is deployed per DAO.
+//!
+//! - `balance` is 1 while the address holds an active SBT for the DAO (see
+//!   `MembershipSbt::has`), 0 otherwise
+//! - `name` is the DAO's name and `symbol` is `ZKV<dao_id>`, read from the registry's
+//!   `DaoInfo` on every call, so renames show up without redeploying
+//! - `decimals` is 0; transfers, approvals and burns fail with `Soulbound`
+//!
+//! The view holds no balances of its own and emits no events; mints and revocations are
+//! published by the SBT contract.
+
+#![no_std]
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    Address, Env, IntoVal, MuxedAddress, String, Symbol,
+};
+use zkvote_admission::{Dues, Gate};
+
+const SBT: Symbol = symbol_short!("sbt");
+const DAO_ID: Symbol = symbol_short!("dao_id");
+const VERSION: u32 = 1;
+const VERSION_KEY: Symbol = symbol_short!("ver");
+
+// Prefix of the per-DAO token symbol
+const SYMBOL_PREFIX: &[u8] = b"ZKV";
+
+#[contracterror]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TokenError {
+    Soulbound = 1,          // Transfer, approval or burn of a membership token
+    AlreadyInitialized = 2, // Constructor called twice
+}
+
+/// DAO record read from the registry (mirrors `dao_registry::DaoInfo`)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaoInfo {
+    pub id: u64,
+    pub name: String,
+    pub admin: Address,
+    pub created_at: u64,
+    pub membership_open: bool,
+    pub members_can_propose: bool,
+    pub metadata_cid: Option<String>,
+    pub dues: Dues,
+    pub token_gate: Gate,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgraded {
+    pub from: u32,
+    pub to: u32,
+}
+
+#[contract]
+pub struct MembershipToken;
+
+#[contractimpl]
+impl MembershipToken {
+    /// Constructor: view `dao_id`'s SBTs in the given `MembershipSbt` contract
+    /// Fails if the registry has no such DAO.
+    pub fn __constructor(env: Env, sbt: Address, dao_id: u64) {
+        if env.storage().instance().has(&VERSION_KEY) {
+            panic_with_error!(&env, TokenError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&VERSION_KEY, &VERSION);
+        ContractUpgraded {
+            from: 0,
+            to: VERSION,
+        }
+        .publish(&env);
+
+        env.storage().instance().set(&SBT, &sbt);
+        env.storage().instance().set(&DAO_ID, &dao_id);
+        Self::dao_info(&env);
+    }
+
+    /// Get the SBT contract this view reads from
+    pub fn sbt(env: Env) -> Address {
+        env.storage().instance().get(&SBT).unwrap()
+    }
+
+    /// Get the DAO this view covers
+    pub fn dao_id(env: Env) -> u64 {
+        env.storage().instance().get(&DAO_ID).unwrap()
+    }
+
+    /// Contract version for upgrade tracking.
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&VERSION_KEY)
+            .unwrap_or(VERSION)
+    }
+
+    fn dao_info(env: &Env) -> DaoInfo {
+        let registry: Address = env.invoke_contract(
+            &Self::sbt(env.clone()),
+            &symbol_short!("registry"),
+            soroban_sdk::vec![env],
+        );
+        env.invoke_contract(
+            &registry,
+            &symbol_short!("get_dao"),
+            soroban_sdk::vec![env, Self::dao_id(env.clone()).into_val(env)],
+        )
+    }
+
+    fn soulbound(env: &Env) -> ! {
+        panic_with_error!(env, TokenError::Soulbound)
+    }
+}
+
+#[contractimpl]
+impl token::TokenInterface for MembershipToken {
+    /// Always 0; membership tokens cannot be approved
+    fn allowance(_env: Env, _from: Address, _spender: Address) -> i128 {
+        0
+    }
+
+    fn approve(
+        env: Env,
+        _from: Address,
+        _spender: Address,
+        _amount: i128,
+        _expiration_ledger: u32,
+    ) {
+        Self::soulbound(&env)
+    }
+
+    /// 1 while `id` holds an active SBT for the DAO, 0 otherwise
+    fn balance(env: Env, id: Address) -> i128 {
+        let has: bool = env.invoke_contract(
+            &Self::sbt(env.clone()),
+            &symbol_short!("has"),
+            soroban_sdk::vec![
+                &env,
+                Self::dao_id(env.clone()).into_val(&env),
+                id.into_val(&env)
+            ],
+        );
+        has.into()
+    }
+
+    fn transfer(env: Env, _from: Address, _to: MuxedAddress, _amount: i128) {
+        Self::soulbound(&env)
+    }
+
+    fn transfer_from(env: Env, _spender: Address, _from: Address, _to: Address, _amount: i128) {
+        Self::soulbound(&env)
+    }
+
+    fn burn(env: Env, _from: Address, _amount: i128) {
+        Self::soulbound(&env)
+    }
+
+    fn burn_from(env: Env, _spender: Address, _from: Address, _amount: i128) {
+        Self::soulbound(&env)
+    }
+
+    fn decimals(_env: Env) -> u32 {
+        0
+    }
+
+    /// The DAO's name
+    fn name(env: Env) -> String {
+        Self::dao_info(&env).name
+    }
+
+    /// `ZKV` followed by the DAO id, e.g. `ZKV7`
+    fn symbol(env: Env) -> String {
+        // Decimal digits of the id, filled from the right (a u64 has at most 20)
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        let mut id = Self::dao_info(&env).id;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (id % 10) as u8;
+            id /= 10;
+            if id == 0 {
+                break;
+            }
+        }
+
+        let len = SYMBOL_PREFIX.len() + digits.len() - start;
+        let mut buf = [0u8; 23];
+        buf[..SYMBOL_PREFIX.len()].copy_from_slice(SYMBOL_PREFIX);
+        buf[SYMBOL_PREFIX.len()..len].copy_from_slice(&digits[start..]);
+        String::from_bytes(&env, &buf[..len])
+    }
+}
+
+#[cfg(test)]
+mod test;
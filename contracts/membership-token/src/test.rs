@@ -0,0 +1,165 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Env};
+
+// Mock Registry contract for testing
+mod mock_registry {
+    use crate::DaoInfo;
+    use soroban_sdk::{contract, contractimpl, contracttype, Env};
+
+    #[contracttype]
+    pub enum DataKey {
+        Dao(u64),
+    }
+
+    #[contract]
+    pub struct MockRegistry;
+
+    #[contractimpl]
+    impl MockRegistry {
+        pub fn set_dao(env: Env, info: DaoInfo) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Dao(info.id), &info);
+        }
+
+        pub fn get_dao(env: Env, dao_id: u64) -> DaoInfo {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Dao(dao_id))
+                .unwrap()
+        }
+    }
+}
+
+// Mock SBT contract for testing
+mod mock_sbt {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+    #[contracttype]
+    pub enum DataKey {
+        Member(u64, Address),
+        Registry,
+    }
+
+    #[contract]
+    pub struct MockSbt;
+
+    #[contractimpl]
+    impl MockSbt {
+        pub fn set_registry(env: Env, registry: Address) {
+            env.storage().instance().set(&DataKey::Registry, &registry);
+        }
+
+        pub fn registry(env: Env) -> Address {
+            env.storage().instance().get(&DataKey::Registry).unwrap()
+        }
+
+        pub fn set_member(env: Env, dao_id: u64, member: Address, active: bool) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Member(dao_id, member), &active);
+        }
+
+        pub fn has(env: Env, dao_id: u64, of: Address) -> bool {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Member(dao_id, of))
+                .unwrap_or(false)
+        }
+    }
+}
+
+fn dao_info(env: &Env, id: u64, name: &str) -> DaoInfo {
+    DaoInfo {
+        id,
+        name: String::from_str(env, name),
+        admin: Address::generate(env),
+        created_at: 0,
+        membership_open: false,
+        members_can_propose: true,
+        metadata_cid: None,
+        dues: Dues::Free,
+        token_gate: Gate::Ungated,
+    }
+}
+
+fn setup_env() -> (Env, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let registry_id = env.register(mock_registry::MockRegistry, ());
+    let registry = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    registry.set_dao(&dao_info(&env, 1, "Test DAO"));
+    registry.set_dao(&dao_info(&env, 42, "Other DAO"));
+
+    let sbt_id = env.register(mock_sbt::MockSbt, ());
+    mock_sbt::MockSbtClient::new(&env, &sbt_id).set_registry(&registry_id);
+
+    let token_id = env.register(MembershipToken, (sbt_id.clone(), 1u64));
+    (env, registry_id, sbt_id, token_id)
+}
+
+#[test]
+fn test_metadata_from_dao_info() {
+    let (env, registry_id, sbt_id, token_id) = setup_env();
+    let token = token::TokenClient::new(&env, &token_id);
+    let view = MembershipTokenClient::new(&env, &token_id);
+
+    assert_eq!(token.name(), String::from_str(&env, "Test DAO"));
+    assert_eq!(token.symbol(), String::from_str(&env, "ZKV1"));
+    assert_eq!(token.decimals(), 0);
+    assert_eq!(view.sbt(), sbt_id);
+    assert_eq!(view.dao_id(), 1);
+    assert_eq!(view.version(), 1);
+
+    // Renames show up without redeploying
+    let registry = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    registry.set_dao(&dao_info(&env, 1, "Renamed DAO"));
+    assert_eq!(token.name(), String::from_str(&env, "Renamed DAO"));
+
+    let other_id = env.register(MembershipToken, (sbt_id, 42u64));
+    let other = token::TokenClient::new(&env, &other_id);
+    assert_eq!(other.symbol(), String::from_str(&env, "ZKV42"));
+}
+
+#[test]
+fn test_balance_follows_sbt() {
+    let (env, _, sbt_id, token_id) = setup_env();
+    let token = token::TokenClient::new(&env, &token_id);
+    let sbt = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+
+    let member = Address::generate(&env);
+    assert_eq!(token.balance(&member), 0);
+
+    sbt.set_member(&1u64, &member, &true);
+    assert_eq!(token.balance(&member), 1);
+
+    // Memberships of other DAOs do not count
+    let outsider = Address::generate(&env);
+    sbt.set_member(&42u64, &outsider, &true);
+    assert_eq!(token.balance(&outsider), 0);
+
+    sbt.set_member(&1u64, &member, &false);
+    assert_eq!(token.balance(&member), 0);
+}
+
+#[test]
+fn test_transfers_are_soulbound() {
+    let (env, _, sbt_id, token_id) = setup_env();
+    let token = token::TokenClient::new(&env, &token_id);
+    let member = Address::generate(&env);
+    let other = Address::generate(&env);
+    mock_sbt::MockSbtClient::new(&env, &sbt_id).set_member(&1u64, &member, &true);
+
+    let soulbound = Err(Ok(TokenError::Soulbound.into()));
+    assert_eq!(token.try_transfer(&member, &other, &1), soulbound);
+    assert_eq!(
+        token.try_transfer_from(&other, &member, &other, &1),
+        soulbound
+    );
+    assert_eq!(token.try_approve(&member, &other, &1, &1000), soulbound);
+    assert_eq!(token.try_burn(&member, &1), soulbound);
+    assert_eq!(token.try_burn_from(&other, &member, &1), soulbound);
+    assert_eq!(token.allowance(&member, &other), 0);
+    assert_eq!(token.balance(&member), 1);
+}
@@ -0,0 +1,108 @@
+//! Verifier for append-only consistency proofs between two tree roots.
+//!
+//! `get_consistency_proof` returns the transitions between two roots in the history
+//! window, each with the sibling path of the changed leaf. This module replays them
+//! without trusting the contract: every step must start from the previous root, and
+//! may only fill an empty leaf (append or slot refill), zero a filled leaf (removal),
+//! or grow the tree by padding the root with empty subtrees.
+//!
+//! The hash is supplied by the caller so auditors can run the check in a native
+//! `Env` (for example with the `testutils` Poseidon helper) or inside a contract.
+
+use soroban_sdk::{Vec, U256};
+
+use crate::ConsistencyStep;
+
+/// Reason a consistency proof was rejected. `u32` fields are the step index.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ConsistencyError {
+    /// Steps do not start at the old root (path does not fold to the running root)
+    PathMismatch(u32),
+    /// Step changed a non-empty leaf to another non-empty value, or changed nothing
+    LeafRewritten(u32),
+    /// Recomputed root after a step differs from the recorded root
+    RootMismatch(u32),
+    /// Depth, path length or leaf index inconsistent with the running tree
+    InvalidStep(u32),
+    /// Replaying every step does not end at the new root
+    EndRootMismatch,
+}
+
+/// Check that `new_root` follows from `old_root` through the given steps.
+pub fn verify_consistency<H>(
+    old_root: &U256,
+    new_root: &U256,
+    steps: &Vec<ConsistencyStep>,
+    hash: H,
+) -> Result<(), ConsistencyError>
+where
+    H: Fn(&U256, &U256) -> U256,
+{
+    let zero = U256::from_u32(old_root.env(), 0);
+    let mut root = old_root.clone();
+    let mut depth: Option<u32> = None;
+
+    for (i, step) in steps.iter().enumerate() {
+        let i = i as u32;
+        let t = &step.transition;
+        if depth.is_some_and(|d| d != t.old_depth) {
+            return Err(ConsistencyError::InvalidStep(i));
+        }
+
+        if t.old_depth != t.new_depth {
+            // Growth: the old root becomes the leftmost node, padded with empty subtrees
+            if t.new_depth < t.old_depth || !step.path.is_empty() {
+                return Err(ConsistencyError::InvalidStep(i));
+            }
+            let mut empty = zero.clone();
+            for _ in 0..t.old_depth {
+                empty = hash(&empty, &empty);
+            }
+            for _ in t.old_depth..t.new_depth {
+                root = hash(&root, &empty);
+                empty = hash(&empty, &empty);
+            }
+        } else {
+            if step.path.len() != t.old_depth || t.leaf_index >= 1u64 << t.old_depth {
+                return Err(ConsistencyError::InvalidStep(i));
+            }
+            if fold(&t.old_value, t.leaf_index, &step.path, &hash) != root {
+                return Err(ConsistencyError::PathMismatch(i));
+            }
+            let filled = t.old_value == zero && t.new_value != zero;
+            let zeroed = t.old_value != zero && t.new_value == zero;
+            if !filled && !zeroed {
+                return Err(ConsistencyError::LeafRewritten(i));
+            }
+            root = fold(&t.new_value, t.leaf_index, &step.path, &hash);
+        }
+
+        if root != t.new_root {
+            return Err(ConsistencyError::RootMismatch(i));
+        }
+        depth = Some(t.new_depth);
+    }
+
+    if root != *new_root {
+        return Err(ConsistencyError::EndRootMismatch);
+    }
+    Ok(())
+}
+
+// Fold a leaf and its sibling path up to the root
+fn fold<H>(leaf: &U256, leaf_index: u64, path: &Vec<U256>, hash: &H) -> U256
+where
+    H: Fn(&U256, &U256) -> U256,
+{
+    let mut current = leaf.clone();
+    let mut index = leaf_index;
+    for sibling in path.iter() {
+        current = if index.is_multiple_of(2) {
+            hash(&current, &sibling)
+        } else {
+            hash(&sibling, &current)
+        };
+        index /= 2;
+    }
+    current
+}
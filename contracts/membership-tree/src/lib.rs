@@ -1,24 +1,53 @@
 #![no_std]
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, Address,
-    Env, IntoVal, Symbol, Vec, U256,
+    Env, IntoVal, Map, String, Symbol, Val, Vec, U256,
 };
 
-mod poseidon_params;
+pub mod consistency;
+mod revocation;
+
+pub use revocation::RevocationNode;
+use revocation::{low_bits, RevocationTree};
+use zkvote_poseidon::Poseidon;
 
 const SBT_CONTRACT: Symbol = symbol_short!("sbt");
 // FIFO history of Merkle roots - older roots are evicted. See THREAT_MODEL.md for impact.
 const MAX_ROOT_HISTORY: u32 = 30;
-// Circuit depth must match vote.circom. Supports ~262K members (2^18 = 262,144)
-const MAX_TREE_DEPTH: u32 = 18;
-const ZEROS_CACHE: Symbol = symbol_short!("zeros");
+// Deepest supported tree; each depth needs a matching vote circuit and VK (see
+// circuits/vote_*.circom). Leaf indices are u64, so a depth-32 tree holds all 2^32 leaves.
+const MAX_TREE_DEPTH: u32 = 32;
+// IC length of the plain vote circuit's VK (5 public signals + 1). Expiring leaves and
+// strict revocation each add a public signal, as the voting contract checks.
+const VOTE_CIRCUIT_IC_LEN: u32 = 6;
+// Extra member groups per DAO (councils, committees); bounds the work in remove_member
+const MAX_GROUPS: u32 = 16;
+/// Group holding the DAO's general membership. The original single-tree entrypoints
+/// (`init_tree`, `register_with_caller`, `current_root`, ...) all operate on it.
+pub const GENERAL_GROUP: u32 = 0;
+/// Expiry baked into the leaves of permanent members on an expiring tree
+pub const NO_EXPIRY: u64 = u64::MAX;
+// Membership changes a frozen DAO can hold back (bounds the queue's storage entry)
+const MAX_PENDING_CHANGES: u32 = 32;
+// Removals a DAO can have scheduled at once per group (bounds the schedule's storage entry)
+const MAX_SCHEDULED_REMOVALS: u32 = 64;
+// Queued changes applied when the last freezing proposal closes, or by a new change ahead
+// of itself once the freeze lifts (bounds either call's footprint); the rest of the queue
+// waits for later changes or `apply_pending`
+const PENDING_BATCH: u32 = 2;
+// Members per import_members call, sized from measured footprints (see the bench). A call
+// writes 8 entries plus 10 per member (4 SBT entries, leaf, both index entries, revocation
+// path claim, root index and transition) plus a node hash per level the new leaves' paths
+// reach, and reads about as many. Two members whose paths split high in the tree write
+// both paths: at leaves 511 and 512 that is 46 writes and 104 footprint entries, past the
+// 100-entry limit, and larger trees split higher still. One member stays under 40 writes
+// and 90 footprint entries at depth 18.
+const IMPORT_BATCH: u32 = 1;
+/// Levels of a DAO's revocation tree (the `SMTVerifier` depth of the strict vote circuit)
+pub const REVOCATION_DEPTH: u32 = 64;
 const VERSION: u32 = 1;
 const VERSION_KEY: Symbol = symbol_short!("ver");
 
-// Poseidon params cache keys (stored in persistent storage)
-const POSEIDON_MDS: Symbol = symbol_short!("pos_mds");
-const POSEIDON_RC: Symbol = symbol_short!("pos_rc");
-
 #[contracterror]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum TreeError {
@@ -36,25 +65,99 @@ pub enum TreeError {
     MemberNotInTree = 12,
     RootNotFound = 13,
     AlreadyInitialized = 14,
-    MemberNotRevoked = 15, // Member hasn't been revoked (for reinstatement)
+    MemberNotRevoked = 15,   // Member hasn't been revoked (for reinstatement)
+    VkNotAvailable = 16,     // No verification key registered for the requested tree depth
+    HistoryUnavailable = 17, // Root transitions needed to rebuild a historical path are missing
+    InvalidRootRange = 18,   // Consistency proof requested from a newer root to an older one
+    InvalidHashInput = 19,   // Poseidon input count not in 1..=5 or input not below the modulus
+    InvalidGroup = 20,       // Group operation on the general group, or group limit reached
+    NotGroupMember = 21,     // Member not added to the group by the DAO admin
+    NotExpiring = 22,        // Leaf renewal on a DAO whose leaves carry no expiry
+    NoPriorCommitment = 23,  // Reinstatement re-enabling a commitment that was never recorded
+    RevocationDepthExceeded = 24, // Revoked commitments share their low 64 bits
+    CommitmentRevoked = 25,  // Commitment is in the revocation tree and could never vote
+    MembershipFrozen = 26, // Freeze change or queue change mid-vote, or recovery with queued changes
+    PendingQueueFull = 27, // Too many membership changes queued or removals scheduled
+    VotingNotBound = 28,   // Voting contract is wired to a different tree
+    RemovalNotScheduled = 29, // Cancel or execute without a scheduled removal
+    RemovalNotDue = 30,    // Scheduled removal executed before its delay has passed
+    RemovalScheduled = 31, // Member already has a removal scheduled
+    BatchTooLarge = 32,    // import_members called with more than IMPORT_BATCH entries
+    RevocationPathTaken = 33, // Another commitment of the DAO shares its low 64 bits
+    VkCircuitMismatch = 34, // The new depth's VK is not for the DAO's expiry/strict circuit variant
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    TreeDepth(u64),                // dao_id -> depth
-    NextLeafIndex(u64),            // dao_id -> next index
-    FilledSubtrees(u64),           // dao_id -> Vec<U256>
-    Roots(u64),                    // dao_id -> Vec<U256> (history)
-    LeafIndex(u64, U256),          // (dao_id, commitment) -> index
-    MemberLeafIndex(u64, Address), // (dao_id, member) -> index
-    LeafValue(u64, u32),           // (dao_id, index) -> commitment (or 0 if removed)
-    NextRootIndex(u64),            // dao_id -> next root index counter
-    RootIndex(u64, U256),          // (dao_id, root) -> root index
-    RevokedAt(u64, U256),          // (dao_id, commitment) -> timestamp when revoked
-    ReinstatedAt(u64, U256),       // (dao_id, commitment) -> timestamp when reinstated
-    NodeHash(u64, u32, u32),       // (dao_id, level, node_index) -> hash value at that position
-    MinValidRootIdx(u64),          // dao_id -> minimum valid root index (after member removals)
+    TreeDepth(u64, u32),                  // (dao_id, group_id) -> depth
+    NextLeafIndex(u64, u32),              // (dao_id, group_id) -> next index
+    FilledSubtrees(u64, u32),             // (dao_id, group_id) -> Vec<U256>
+    Roots(u64, u32),                      // (dao_id, group_id) -> Vec<U256> (history)
+    LeafIndex(u64, u32, U256),            // (dao_id, group_id, commitment) -> index
+    MemberLeafIndex(u64, u32, Address),   // (dao_id, group_id, member) -> index
+    LeafValue(u64, u32, u64), // (dao_id, group_id, index) -> commitment (or 0 if removed)
+    NextRootIndex(u64, u32),  // (dao_id, group_id) -> next root index counter
+    RootIndex(u64, u32, U256), // (dao_id, group_id, root) -> root index
+    RevokedAt(u64, U256),     // (dao_id, commitment) -> timestamp when revoked
+    ReinstatedAt(u64, U256),  // (dao_id, commitment) -> timestamp when reinstated
+    NodeHash(u64, u32, u32, u64), // (dao_id, group_id, level, node_index) -> hash value at that position
+    MinValidRootIdx(u64, u32), // (dao_id, group_id) -> minimum valid root index (after member removals)
+    LeafOwner(u64, u32, u64), // (dao_id, group_id, index) -> member who refilled (or recovered) the leaf
+    SlotReuse(u64, u32),      // (dao_id, group_id) -> bool (refill zeroed slots before appending)
+    FreeSlotHead(u64, u32),   // (dao_id, group_id) -> most recently freed leaf index (stack top)
+    FreeSlotNext(u64, u32, u64), // (dao_id, group_id, index) -> next free index below it in the stack
+    FreeSlotCount(u64, u32),     // (dao_id, group_id) -> number of zeroed slots available for reuse
+    Transition(u64, u32, u32), // (dao_id, group_id, root_index % MAX_ROOT_HISTORY) -> (root_index, RootTransition that produced it)
+    Groups(u64),               // dao_id -> Vec<u32> of extra groups created with create_group
+    GroupMember(u64, u32, Address), // (dao_id, group_id, member) -> bool (admin-curated eligibility)
+    Expiring(u64),                  // dao_id -> bool (leaves are Poseidon(commitment, expires_at))
+    LeafPreimage(u64, u32, u64), // (dao_id, group_id, index) -> (commitment, expiry) behind an expiring leaf
+    RemovedCommitment(u64, u32, Address), // (dao_id, group_id, member) -> commitment of the zeroed leaf
+    StrictRevocation(u64), // dao_id -> bool (removed commitments enter the revocation tree)
+    RevocationPage(u64, u32, u64), // (dao_id, band, page) -> Map<(depth, path), RevocationNode> below the root
+    RevocationRoot(u64), // dao_id -> RevocationNode at the root (absent while nothing is revoked)
+    MembershipFreeze(u64), // dao_id -> voting contract whose active proposals freeze membership
+    PendingChanges(u64), // dao_id -> Vec<PendingChange> held back by the freeze, oldest first
+    RemovalDelay(u64),   // dao_id -> seconds between scheduling and executing a removal
+    RemovalDelayCut(u64), // dao_id -> (lower delay, timestamp it applies from)
+    ScheduledRemovals(u64), // dao_id -> Map<Address, u64> of member -> executable_at
+    ScheduledGroupRemovals(u64, u32), // (dao_id, group_id) -> Map<Address, u64> of member -> executable_at
+    RevocationPath(u64, u64), // (dao_id, low 64 bits) -> commitment whose revocation tree path they are
+}
+
+/// A membership change held back while a DAO's membership is frozen
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PendingChange {
+    Register(u32, U256, Address),     // (group_id, commitment, member)
+    Remove(Address),                  // member
+    Reinstate(Address, Option<U256>), // (member, commitment to insert instead of the removed one)
+    Depart(Address),                  // member who left through the SBT's `leave`
+    RemoveFromGroup(u32, Address),    // (group_id, member) taken out of a group by the admin
+}
+
+/// A single change to a DAO's tree, recorded for every root in the history window.
+/// Leaf insertions and updates have `old_depth == new_depth`; tree growth has
+/// `old_value == new_value` and changes the depth.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RootTransition {
+    pub leaf_index: u64,
+    pub old_value: U256,
+    pub new_value: U256,
+    pub old_depth: u32,
+    pub new_depth: u32,
+    pub new_root: U256,
+}
+
+/// One step of a consistency proof: a recorded transition and the sibling path of its
+/// leaf in the tree just before the change (empty for depth growth).
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsistencyStep {
+    pub transition: RootTransition,
+    pub path: Vec<U256>,
 }
 
 // Typed Events
@@ -63,6 +166,7 @@ pub enum DataKey {
 pub struct TreeInitEvent {
     #[topic]
     pub dao_id: u64,
+    pub group_id: u32, // GENERAL_GROUP for the DAO's main tree
     pub depth: u32,
     pub empty_root: U256,
     pub root_index: u32,
@@ -73,10 +177,12 @@ pub struct TreeInitEvent {
 pub struct CommitEvent {
     #[topic]
     pub dao_id: u64,
+    pub group_id: u32,
     pub commitment: U256,
-    pub index: u32,
+    pub index: u64,
     pub new_root: U256,
     pub root_index: u32,
+    pub expires_at: Option<u64>, // Set on expiring trees; the leaf is Poseidon(commitment, expires_at)
 }
 
 #[soroban_sdk::contractevent]
@@ -86,7 +192,8 @@ pub struct RemovalEvent {
     pub dao_id: u64,
     #[topic]
     pub member: Address,
-    pub index: u32,
+    pub group_id: u32,
+    pub index: u64,
     pub new_root: U256,
     pub root_index: u32,
 }
@@ -99,6 +206,128 @@ pub struct ReinstatementEvent {
     #[topic]
     pub member: Address,
     pub reinstated_at: u64,
+    pub commitment: U256,
+    pub reused: bool, // true when the commitment removed with the member was re-enabled
+    pub index: u64,
+    pub new_root: U256,
+    pub root_index: u32,
+    pub expires_at: Option<u64>, // Set on expiring trees, as in CommitEvent
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeGrowEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub group_id: u32,
+    pub old_depth: u32,
+    pub new_depth: u32, // Merkle paths now have new_depth elements
+    pub new_root: U256,
+    pub root_index: u32,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeafRenewEvent {
+    #[topic]
+    pub dao_id: u64,
+    #[topic]
+    pub member: Address,
+    pub group_id: u32,
+    pub index: u64,
+    pub expires_at: u64,
+    pub new_root: U256,
+    pub root_index: u32,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrictRevocationEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub revocation_root: U256,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevocationEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub commitment: U256,
+    pub revoked: bool, // false when reinstatement took the commitment out again
+    pub new_root: U256,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MembershipFreezeEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub voting_contract: Option<Address>, // None when the freeze was turned off
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangeQueuedEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub change: PendingChange,
+    pub position: u32, // Index in the pending queue
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangeDroppedEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub change: PendingChange,
+    pub error: u32, // TreeError the change would have failed with when applied
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemovalDelayEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub delay: u64,
+    pub effective_at: u64, // Later than now when the delay was lowered
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemovalScheduledEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub group_id: u32,
+    pub executable_at: u64,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemovalCancelledEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub group_id: u32,
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmergencyRemovalEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub member: Address,
+    pub admin: Address,
+    pub scheduled: bool, // true when it overrode a removal still waiting for its delay
+}
+
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlotReuseEvent {
+    #[topic]
+    pub dao_id: u64,
+    pub enabled: bool,
 }
 
 #[soroban_sdk::contractevent]
@@ -108,6 +337,73 @@ pub struct ContractUpgraded {
     pub to: u32,
 }
 
+// A group's append frontier and root history, cached until `save` so a batch of
+// leaf changes stores each once. Per-root index and transition entries are still
+// written as each root is pushed.
+struct Frontier {
+    filled: Vec<U256>,
+    roots: Vec<U256>,
+    next_root_index: u32,
+    filled_dirty: bool,
+    roots_dirty: bool,
+}
+
+impl Frontier {
+    fn load(env: &Env, dao_id: u64, group_id: u32) -> Self {
+        let storage = env.storage().persistent();
+        Frontier {
+            filled: storage
+                .get(&DataKey::FilledSubtrees(dao_id, group_id))
+                .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized)),
+            roots: storage
+                .get(&DataKey::Roots(dao_id, group_id))
+                .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized)),
+            next_root_index: storage
+                .get(&DataKey::NextRootIndex(dao_id, group_id))
+                .unwrap_or(0),
+            filled_dirty: false,
+            roots_dirty: false,
+        }
+    }
+
+    fn set_filled(&mut self, level: u32, hash: U256) {
+        self.filled.set(level, hash);
+        self.filled_dirty = true;
+    }
+
+    // Append a root to the FIFO history and assign it the next root index
+    fn push_root(&mut self, env: &Env, dao_id: u64, group_id: u32, root: &U256) -> u32 {
+        self.roots.push_back(root.clone());
+        if self.roots.len() > MAX_ROOT_HISTORY {
+            self.roots.pop_front();
+        }
+
+        let root_index = self.next_root_index;
+        self.next_root_index += 1;
+        self.roots_dirty = true;
+
+        env.storage().persistent().set(
+            &DataKey::RootIndex(dao_id, group_id, root.clone()),
+            &root_index,
+        );
+        root_index
+    }
+
+    fn save(&self, env: &Env, dao_id: u64, group_id: u32) {
+        let storage = env.storage().persistent();
+        if self.filled_dirty {
+            storage.set(&DataKey::FilledSubtrees(dao_id, group_id), &self.filled);
+        }
+        if self.roots_dirty {
+            storage.set(&DataKey::Roots(dao_id, group_id), &self.roots);
+            storage.set(
+                &DataKey::NextRootIndex(dao_id, group_id),
+                &self.next_root_index,
+            );
+        }
+    }
+}
+
 #[contract]
 pub struct MembershipTree;
 
@@ -127,10 +423,6 @@ impl MembershipTree {
         .publish(&env);
 
         env.storage().instance().set(&SBT_CONTRACT, &sbt_contract);
-
-        // Pre-initialize zeros cache during deployment to spread the cost
-        // This avoids hitting budget limits during first DAO creation
-        Self::ensure_zeros_cache(&env);
     }
 
     fn sbt_contract(env: &Env) -> Address {
@@ -146,125 +438,107 @@ impl MembershipTree {
         admin.require_auth();
 
         // Verify admin owns the DAO via SBT -> Registry chain
-        let sbt_contract: Address = Self::sbt_contract(&env);
-        let registry: Address = env.invoke_contract(
-            &sbt_contract,
-            &symbol_short!("registry"),
-            soroban_sdk::vec![&env],
-        );
-        let dao_admin: Address = env.invoke_contract(
-            &registry,
-            &symbol_short!("get_admin"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
-        );
-        if dao_admin != admin {
-            panic_with_error!(&env, TreeError::NotAdmin);
-        }
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        if depth == 0 || depth > MAX_TREE_DEPTH {
-            panic_with_error!(&env, TreeError::InvalidDepth);
-        }
+        Self::init_group_tree(&env, dao_id, GENERAL_GROUP, depth);
+    }
 
-        let depth_key = DataKey::TreeDepth(dao_id);
-        if env.storage().persistent().has(&depth_key) {
-            panic_with_error!(&env, TreeError::TreeInitialized);
-        }
+    /// Initialize a tree whose leaves commit to each member's SBT expiry
+    /// Leaves are Poseidon(commitment, expires_at), with `NO_EXPIRY` for permanent members,
+    /// and votes need the expiring vote circuit (`vote_expiry.circom`), which proves the
+    /// membership had not lapsed at the proposal's creation time. The mode applies to all of
+    /// the DAO's trees and cannot change later. Admin only.
+    pub fn init_expiring_tree(env: Env, dao_id: u64, depth: u32, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        // Store tree parameters
-        env.storage().persistent().set(&depth_key, &depth);
+        Self::init_group_tree(&env, dao_id, GENERAL_GROUP, depth);
         env.storage()
             .persistent()
-            .set(&DataKey::NextLeafIndex(dao_id), &0u32);
+            .set(&DataKey::Expiring(dao_id), &true);
+    }
 
-        // Initialize root index counter
+    /// Check whether a DAO's leaves commit to membership expiry
+    pub fn expiring(env: Env, dao_id: u64) -> bool {
         env.storage()
             .persistent()
-            .set(&DataKey::NextRootIndex(dao_id), &0u32);
+            .get(&DataKey::Expiring(dao_id))
+            .unwrap_or(false)
+    }
 
-        // Initialize filled subtrees with zeros (use cached zeros for O(1) lookup)
-        let mut filled = Vec::new(&env);
-        for level in 0..depth {
-            filled.push_back(Self::zero_at_level(&env, level));
+    /// Rebake a member's leaves with their current SBT expiry (callable by anyone)
+    /// Run after `renew` on the SBT contract. Updates the general tree and every group
+    /// tree holding an active leaf for the member; leaves already up to date are skipped.
+    pub fn renew_leaf(env: Env, dao_id: u64, member: Address) {
+        if !Self::expiring(env.clone(), dao_id) {
+            panic_with_error!(&env, TreeError::NotExpiring);
         }
-        env.storage()
-            .persistent()
-            .set(&DataKey::FilledSubtrees(dao_id), &filled);
-
-        // Initialize root history with empty tree root (cached zero at depth level)
-        let empty_root = Self::zero_at_level(&env, depth);
-        let mut roots = Vec::new(&env);
-        roots.push_back(empty_root.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::Roots(dao_id), &roots);
+        Self::assert_has_sbt(&env, dao_id, &member);
+        let expires_at = Self::sbt_expiry(&env, dao_id, &member);
+
+        let mut group_ids = Self::get_groups(env.clone(), dao_id);
+        group_ids.push_front(GENERAL_GROUP);
+        let mut renewed = false;
+        for group_id in group_ids.iter() {
+            let Some(index) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, u64>(&DataKey::MemberLeafIndex(dao_id, group_id, member.clone()))
+            else {
+                continue;
+            };
+            let value: U256 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::LeafValue(dao_id, group_id, index))
+                .unwrap_or_else(|| Self::zero_value(&env));
+            if value == Self::zero_value(&env)
+                || !Self::leaf_owned_by(&env, dao_id, group_id, index, &member)
+            {
+                continue;
+            }
+            renewed = true;
 
-        // Store root index for empty root
-        env.storage()
-            .persistent()
-            .set(&DataKey::RootIndex(dao_id, empty_root.clone()), &0u32);
+            let preimage_key = DataKey::LeafPreimage(dao_id, group_id, index);
+            let (commitment, expiry): (U256, u64) = env
+                .storage()
+                .persistent()
+                .get(&preimage_key)
+                .unwrap_or_else(|| panic_with_error!(&env, TreeError::MemberNotInTree));
+            if expiry == expires_at {
+                continue;
+            }
+            let leaf = Self::expiring_leaf(&env, &commitment, expires_at);
+            let mut frontier = Frontier::load(&env, dao_id, group_id);
+            let (new_root, root_index) =
+                Self::update_leaf(&env, dao_id, group_id, &mut frontier, index, leaf);
+            frontier.save(&env, dao_id, group_id);
+            env.storage()
+                .persistent()
+                .set(&preimage_key, &(commitment, expires_at));
+
+            LeafRenewEvent {
+                dao_id,
+                member: member.clone(),
+                group_id,
+                index,
+                expires_at,
+                new_root,
+                root_index,
+            }
+            .publish(&env);
+        }
 
-        TreeInitEvent {
-            dao_id,
-            depth,
-            empty_root,
-            root_index: 0,
+        if !renewed {
+            panic_with_error!(&env, TreeError::MemberNotInTree);
         }
-        .publish(&env);
     }
 
     /// Initialize tree from registry during DAO initialization
     /// This function is called by the registry contract during create_and_init_dao
     /// to avoid re-entrancy issues. The registry is a trusted system contract.
     pub fn init_tree_from_registry(env: Env, dao_id: u64, depth: u32) {
-        if depth == 0 || depth > MAX_TREE_DEPTH {
-            panic_with_error!(&env, TreeError::InvalidDepth);
-        }
-
-        let depth_key = DataKey::TreeDepth(dao_id);
-        if env.storage().persistent().has(&depth_key) {
-            panic_with_error!(&env, TreeError::TreeInitialized);
-        }
-
-        // Store tree parameters
-        env.storage().persistent().set(&depth_key, &depth);
-        env.storage()
-            .persistent()
-            .set(&DataKey::NextLeafIndex(dao_id), &0u32);
-
-        // Initialize root index counter
-        env.storage()
-            .persistent()
-            .set(&DataKey::NextRootIndex(dao_id), &0u32);
-
-        // Initialize filled subtrees with zeros (use cached zeros for O(1) lookup)
-        let mut filled = Vec::new(&env);
-        for level in 0..depth {
-            filled.push_back(Self::zero_at_level(&env, level));
-        }
-        env.storage()
-            .persistent()
-            .set(&DataKey::FilledSubtrees(dao_id), &filled);
-
-        // Initialize root history with empty tree root (cached zero at depth level)
-        let empty_root = Self::zero_at_level(&env, depth);
-        let mut roots = Vec::new(&env);
-        roots.push_back(empty_root.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::Roots(dao_id), &roots);
-
-        // Store root index for empty root
-        env.storage()
-            .persistent()
-            .set(&DataKey::RootIndex(dao_id, empty_root.clone()), &0u32);
-
-        TreeInitEvent {
-            dao_id,
-            depth,
-            empty_root,
-            root_index: 0,
-        }
-        .publish(&env);
+        Self::init_group_tree(&env, dao_id, GENERAL_GROUP, depth);
     }
 
     /// Register a commitment from registry during DAO initialization
@@ -272,249 +546,268 @@ impl MembershipTree {
     /// to automatically register the creator's commitment.
     /// The registry is trusted to have already verified SBT ownership.
     pub fn register_from_registry(env: Env, dao_id: u64, commitment: U256, member: Address) {
-        // Check tree is initialized
-        let depth_key = DataKey::TreeDepth(dao_id);
-        if !env.storage().persistent().has(&depth_key) {
-            panic_with_error!(&env, TreeError::TreeNotInitialized);
-        }
-
-        // Check commitment not already registered
-        let leaf_key = DataKey::LeafIndex(dao_id, commitment.clone());
-        if env.storage().persistent().has(&leaf_key) {
-            panic_with_error!(&env, TreeError::CommitmentExists);
-        }
+        Self::add_commitment(&env, dao_id, GENERAL_GROUP, commitment, member);
+    }
 
-        // Check member hasn't already registered
-        let member_key = DataKey::MemberLeafIndex(dao_id, member.clone());
-        if env.storage().persistent().has(&member_key) {
-            panic_with_error!(&env, TreeError::MemberExists);
-        }
+    /// Register a commitment with explicit caller (requires SBT membership)
+    pub fn register_with_caller(env: Env, dao_id: u64, commitment: U256, caller: Address) {
+        caller.require_auth();
 
-        // Get tree parameters
-        let depth: u32 = env
-            .storage()
-            .persistent()
-            .get(&depth_key)
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
-        let next_index: u32 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextLeafIndex(dao_id))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
+        // Verify caller has SBT for this DAO
+        Self::assert_has_sbt(&env, dao_id, &caller);
 
-        if next_index >= (1u32 << depth) {
-            panic_with_error!(&env, TreeError::TreeFull);
+        let change = PendingChange::Register(GENERAL_GROUP, commitment.clone(), caller.clone());
+        if Self::defer(&env, dao_id, change) {
+            return;
         }
+        Self::add_commitment(&env, dao_id, GENERAL_GROUP, commitment, caller);
+    }
 
-        // Insert leaf into tree
-        let (new_root, root_index) =
-            Self::insert_leaf(&env, dao_id, commitment.clone(), next_index, depth);
-
-        // Update next index
-        env.storage()
-            .persistent()
-            .set(&DataKey::NextLeafIndex(dao_id), &(next_index + 1));
-
-        // Store leaf index for this commitment
-        env.storage().persistent().set(&leaf_key, &next_index);
+    /// Self-register a commitment in a public DAO (requires SBT membership)
+    /// For public and token-gated DAOs, anyone with an SBT can register their commitment
+    pub fn self_register(env: Env, dao_id: u64, commitment: U256, member: Address) {
+        member.require_auth();
 
-        // Store member -> index mapping
-        env.storage().persistent().set(&member_key, &next_index);
+        // Get SBT contract and verify membership
+        let sbt_contract: Address = Self::sbt_contract(&env);
+        Self::assert_has_sbt(&env, dao_id, &member);
 
-        // Store leaf value
-        let leaf_value_key = DataKey::LeafValue(dao_id, next_index);
-        env.storage().persistent().set(&leaf_value_key, &commitment);
+        // Get registry from SBT contract
+        let registry: Address = env.invoke_contract(
+            &sbt_contract,
+            &symbol_short!("registry"),
+            soroban_sdk::vec![&env],
+        );
 
-        CommitEvent {
-            dao_id,
-            commitment,
-            index: next_index,
-            new_root,
-            root_index,
-        }
-        .publish(&env);
-    }
-
-    /// Register a commitment with explicit caller (requires SBT membership)
-    pub fn register_with_caller(env: Env, dao_id: u64, commitment: U256, caller: Address) {
-        caller.require_auth();
-
-        // Verify caller has SBT for this DAO
-        let sbt_contract: Address = Self::sbt_contract(&env);
-        let has_sbt: bool = env.invoke_contract(
-            &sbt_contract,
-            &symbol_short!("has"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env), caller.clone().into_val(&env)],
+        // Check if DAO has open membership
+        let membership_open: bool = env.invoke_contract(
+            &registry,
+            &Symbol::new(&env, "is_membership_open"),
+            soroban_sdk::vec![&env, dao_id.into_val(&env)],
         );
 
-        if !has_sbt {
-            panic_with_error!(&env, TreeError::NoSbt);
-        }
-
-        // Check tree is initialized
-        let depth_key = DataKey::TreeDepth(dao_id);
-        if !env.storage().persistent().has(&depth_key) {
-            panic_with_error!(&env, TreeError::TreeNotInitialized);
+        if !membership_open {
+            // Token-gated DAOs let their holders in without the admin; the SBT's
+            // `self_join` already checked the balance. Only presence matters here.
+            let gate: Val = env.invoke_contract(
+                &registry,
+                &Symbol::new(&env, "token_gate"),
+                soroban_sdk::vec![&env, dao_id.into_val(&env)],
+            );
+            if gate.is_void() {
+                panic_with_error!(&env, TreeError::NotOpenMembership);
+            }
         }
 
-        // Check commitment not already registered
-        let leaf_key = DataKey::LeafIndex(dao_id, commitment.clone());
-        if env.storage().persistent().has(&leaf_key) {
-            panic_with_error!(&env, TreeError::CommitmentExists);
+        let change = PendingChange::Register(GENERAL_GROUP, commitment.clone(), member.clone());
+        if Self::defer(&env, dao_id, change) {
+            return;
         }
+        Self::add_commitment(&env, dao_id, GENERAL_GROUP, commitment, member);
+    }
 
-        // Check member hasn't already registered
-        let member_key = DataKey::MemberLeafIndex(dao_id, caller.clone());
-        if env.storage().persistent().has(&member_key) {
-            panic_with_error!(&env, TreeError::MemberExists);
-        }
+    /// Get current root for a DAO
+    pub fn current_root(env: Env, dao_id: u64) -> U256 {
+        Self::group_root(env, dao_id, GENERAL_GROUP)
+    }
 
-        // Get tree parameters
-        let depth: u32 = env
-            .storage()
-            .persistent()
-            .get(&depth_key)
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
-        let next_index: u32 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextLeafIndex(dao_id))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
+    /// Get current root (short alias for cross-contract calls)
+    pub fn get_root(env: Env, dao_id: u64) -> U256 {
+        Self::current_root(env, dao_id)
+    }
 
-        if next_index >= (1u32 << depth) {
-            panic_with_error!(&env, TreeError::TreeFull);
-        }
+    /// Check if a root is valid (in history)
+    pub fn root_ok(env: Env, dao_id: u64, root: U256) -> bool {
+        Self::group_root_ok(env, dao_id, GENERAL_GROUP, root)
+    }
 
-        // Insert leaf into tree
-        let (new_root, root_index) =
-            Self::insert_leaf(&env, dao_id, commitment.clone(), next_index, depth);
+    /// Get root index for a specific root (for vote mode validation)
+    pub fn root_idx(env: Env, dao_id: u64, root: U256) -> u32 {
+        Self::group_root_idx(env, dao_id, GENERAL_GROUP, root)
+    }
 
-        // Update next index
-        env.storage()
-            .persistent()
-            .set(&DataKey::NextLeafIndex(dao_id), &(next_index + 1));
+    /// Get current root index (for proposal creation)
+    pub fn curr_idx(env: Env, dao_id: u64) -> u32 {
+        Self::group_curr_idx(env, dao_id, GENERAL_GROUP)
+    }
 
-        // Store leaf index for this commitment
-        env.storage().persistent().set(&leaf_key, &next_index);
+    /// Get leaf index for a commitment
+    pub fn get_leaf_index(env: Env, dao_id: u64, commitment: U256) -> u64 {
+        Self::group_leaf_idx(env, dao_id, GENERAL_GROUP, commitment)
+    }
 
-        // Store member -> index mapping
-        env.storage().persistent().set(&member_key, &next_index);
+    /// Get tree info for a DAO
+    /// Returns (depth, next_index, root); next_index saturates at u32::MAX once a depth-32
+    /// tree is full (see `get_tree_usage` for the exact count).
+    pub fn get_tree_info(env: Env, dao_id: u64) -> (u32, u32, U256) {
+        Self::group_info(env, dao_id, GENERAL_GROUP)
+    }
 
-        // Store leaf value
-        let leaf_value_key = DataKey::LeafValue(dao_id, next_index);
-        env.storage().persistent().set(&leaf_value_key, &commitment);
+    /// Get slot usage of a DAO's tree
+    /// Returns (next_index, active_leaves, capacity) where:
+    /// - next_index is the number of slots ever appended (zeroed slots included)
+    /// - active_leaves excludes zeroed slots waiting in the free list
+    /// - capacity is the total number of leaves the tree can hold (2^depth)
+    pub fn get_tree_usage(env: Env, dao_id: u64) -> (u64, u64, u64) {
+        Self::group_usage(env, dao_id, GENERAL_GROUP)
+    }
 
-        CommitEvent {
-            dao_id,
-            commitment,
-            index: next_index,
-            new_root,
-            root_index,
-        }
-        .publish(&env);
+    /// Get the current depth of a DAO's tree (short form for cross-contract calls)
+    pub fn get_depth(env: Env, dao_id: u64) -> u32 {
+        Self::group_depth(env, dao_id, GENERAL_GROUP)
     }
 
-    /// Self-register a commitment in a public DAO (requires SBT membership)
-    /// For public DAOs, anyone with an SBT can register their commitment
-    pub fn self_register(env: Env, dao_id: u64, commitment: U256, member: Address) {
-        member.require_auth();
+    /// Get Merkle path for a specific leaf index
+    /// Returns (pathElements, pathIndices) where:
+    /// - pathElements[i] is the sibling hash at level i
+    /// - pathIndices[i] is 0 if leaf is left child, 1 if right child
+    pub fn get_merkle_path(env: Env, dao_id: u64, leaf_index: u64) -> (Vec<U256>, Vec<u32>) {
+        Self::group_path(env, dao_id, GENERAL_GROUP, leaf_index)
+    }
 
-        // Get SBT contract and verify membership
-        let sbt_contract: Address = Self::sbt_contract(&env);
-        let has_sbt: bool = env.invoke_contract(
-            &sbt_contract,
-            &symbol_short!("has"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env), member.clone().into_val(&env)],
-        );
+    /// Create an additional member group (e.g. a council) with its own tree and root
+    /// history. Only the DAO admin can create groups; `group_id` must be non-zero since
+    /// group 0 is the general membership created by `init_tree`.
+    pub fn create_group(env: Env, dao_id: u64, group_id: u32, depth: u32, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        if !has_sbt {
-            panic_with_error!(&env, TreeError::NoSbt);
+        if group_id == GENERAL_GROUP {
+            panic_with_error!(&env, TreeError::InvalidGroup);
+        }
+        let mut groups = Self::get_groups(env.clone(), dao_id);
+        if groups.len() >= MAX_GROUPS {
+            panic_with_error!(&env, TreeError::InvalidGroup);
         }
 
-        // Get registry from SBT contract
-        let registry: Address = env.invoke_contract(
-            &sbt_contract,
-            &symbol_short!("registry"),
-            soroban_sdk::vec![&env],
-        );
+        Self::init_group_tree(&env, dao_id, group_id, depth);
 
-        // Check if DAO has open membership
-        let membership_open: bool = env.invoke_contract(
-            &registry,
-            &Symbol::new(&env, "is_membership_open"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
-        );
+        groups.push_back(group_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Groups(dao_id), &groups);
+    }
 
-        if !membership_open {
-            panic_with_error!(&env, TreeError::NotOpenMembership);
-        }
+    /// List the extra groups created for a DAO (the general group 0 is not included)
+    pub fn get_groups(env: Env, dao_id: u64) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Groups(dao_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
 
-        // Check tree is initialized
-        let depth_key = DataKey::TreeDepth(dao_id);
-        if !env.storage().persistent().has(&depth_key) {
-            panic_with_error!(&env, TreeError::TreeNotInitialized);
-        }
+    /// Allow a DAO member to register a commitment in a group (admin only)
+    /// Groups other than the general membership are curated: SBT holders can only
+    /// join after the admin adds them here.
+    pub fn add_group_member(env: Env, dao_id: u64, group_id: u32, member: Address, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+        Self::assert_group(&env, dao_id, group_id);
 
-        // Check commitment not already registered
-        let leaf_key = DataKey::LeafIndex(dao_id, commitment.clone());
-        if env.storage().persistent().has(&leaf_key) {
-            panic_with_error!(&env, TreeError::CommitmentExists);
-        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::GroupMember(dao_id, group_id, member), &true);
+    }
 
-        // Check member hasn't already registered
-        let member_key = DataKey::MemberLeafIndex(dao_id, member.clone());
-        if env.storage().persistent().has(&member_key) {
-            panic_with_error!(&env, TreeError::MemberExists);
-        }
+    /// Check if a member has been added to a group (always true for the general group)
+    pub fn is_group_member(env: Env, dao_id: u64, group_id: u32, member: Address) -> bool {
+        group_id == GENERAL_GROUP
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::GroupMember(dao_id, group_id, member))
+    }
 
-        // Get tree parameters
-        let depth: u32 = env
-            .storage()
-            .persistent()
-            .get(&depth_key)
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
-        let next_index: u32 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextLeafIndex(dao_id))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
+    /// Register a commitment in a group (requires SBT membership)
+    /// Non-general groups also require the admin to have added the caller with
+    /// `add_group_member`. The same identity may be registered in several groups.
+    pub fn register_in_group(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        commitment: U256,
+        caller: Address,
+    ) {
+        caller.require_auth();
+        Self::assert_has_sbt(&env, dao_id, &caller);
 
-        if next_index >= (1u32 << depth) {
-            panic_with_error!(&env, TreeError::TreeFull);
+        if !Self::is_group_member(env.clone(), dao_id, group_id, caller.clone()) {
+            panic_with_error!(&env, TreeError::NotGroupMember);
         }
 
-        // Insert leaf into tree
-        let (new_root, root_index) =
-            Self::insert_leaf(&env, dao_id, commitment.clone(), next_index, depth);
+        let change = PendingChange::Register(group_id, commitment.clone(), caller.clone());
+        if Self::defer(&env, dao_id, change) {
+            return;
+        }
+        Self::add_commitment(&env, dao_id, group_id, commitment, caller);
+    }
 
-        // Update next index
-        env.storage()
-            .persistent()
-            .set(&DataKey::NextLeafIndex(dao_id), &(next_index + 1));
+    /// Remove a member from a group by zeroing their leaf (admin only)
+    /// Also withdraws their group eligibility; their SBT and general membership are
+    /// untouched. Use `remove_member` to remove someone from the DAO entirely.
+    /// While membership is frozen the removal is queued (see `set_membership_freeze`).
+    /// With a removal delay set, a member with a group leaf is only scheduled for removal,
+    /// like `remove_member`; see `execute_group_removal`.
+    pub fn remove_from_group(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        member: Address,
+        admin: Address,
+    ) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+        Self::assert_group(&env, dao_id, group_id);
 
-        // Store leaf index for this commitment
-        env.storage().persistent().set(&leaf_key, &next_index);
+        // Withdrawing the eligibility of a member who never registered moves no root
+        let delay = Self::removal_delay(env.clone(), dao_id);
+        if delay > 0 && Self::leaf_error(&env, dao_id, group_id, &member).is_none() {
+            Self::schedule_removal(&env, dao_id, group_id, member, delay);
+            return;
+        }
+        Self::remove_now(&env, dao_id, group_id, member);
+    }
 
-        // Store member -> index mapping
-        env.storage().persistent().set(&member_key, &next_index);
+    /// Apply a scheduled group removal once its delay has passed (anyone can call)
+    pub fn execute_group_removal(env: Env, dao_id: u64, group_id: u32, member: Address) {
+        let executable_at = Self::unschedule_removal(&env, dao_id, group_id, &member)
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::RemovalNotScheduled));
+        if env.ledger().timestamp() < executable_at {
+            panic_with_error!(&env, TreeError::RemovalNotDue);
+        }
+        Self::remove_now(&env, dao_id, group_id, member);
+    }
 
-        // Store leaf value
-        let leaf_value_key = DataKey::LeafValue(dao_id, next_index);
-        env.storage().persistent().set(&leaf_value_key, &commitment);
+    /// Cancel a scheduled group removal before it is executed (admin only)
+    pub fn cancel_group_removal(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        member: Address,
+        admin: Address,
+    ) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        CommitEvent {
+        if Self::unschedule_removal(&env, dao_id, group_id, &member).is_none() {
+            panic_with_error!(&env, TreeError::RemovalNotScheduled);
+        }
+        RemovalCancelledEvent {
             dao_id,
-            commitment,
-            index: next_index,
-            new_root,
-            root_index,
+            member,
+            group_id,
         }
         .publish(&env);
     }
 
-    /// Get current root for a DAO
-    pub fn current_root(env: Env, dao_id: u64) -> U256 {
-        let roots_key = DataKey::Roots(dao_id);
+    /// Group removals waiting for their delay: member -> earliest execution timestamp
+    pub fn scheduled_group_removals(env: Env, dao_id: u64, group_id: u32) -> Map<Address, u64> {
+        Self::removal_schedule(&env, dao_id, group_id)
+    }
+
+    /// Get current root of a group
+    pub fn group_root(env: Env, dao_id: u64, group_id: u32) -> U256 {
+        let roots_key = DataKey::Roots(dao_id, group_id);
         let roots: Vec<U256> = env
             .storage()
             .persistent()
@@ -525,14 +818,9 @@ impl MembershipTree {
             .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized))
     }
 
-    /// Get current root (short alias for cross-contract calls)
-    pub fn get_root(env: Env, dao_id: u64) -> U256 {
-        Self::current_root(env, dao_id)
-    }
-
-    /// Check if a root is valid (in history)
-    pub fn root_ok(env: Env, dao_id: u64, root: U256) -> bool {
-        let roots_key = DataKey::Roots(dao_id);
+    /// Check if a root is in a group's history
+    pub fn group_root_ok(env: Env, dao_id: u64, group_id: u32, root: U256) -> bool {
+        let roots_key = DataKey::Roots(dao_id, group_id);
         if !env.storage().persistent().has(&roots_key) {
             return false;
         }
@@ -554,63 +842,85 @@ impl MembershipTree {
         false
     }
 
-    /// Get root index for a specific root (for vote mode validation)
-    pub fn root_idx(env: Env, dao_id: u64, root: U256) -> u32 {
+    /// Get a group's root index for a specific root
+    pub fn group_root_idx(env: Env, dao_id: u64, group_id: u32, root: U256) -> u32 {
         env.storage()
             .persistent()
-            .get(&DataKey::RootIndex(dao_id, root))
+            .get(&DataKey::RootIndex(dao_id, group_id, root))
             .unwrap_or_else(|| panic_with_error!(&env, TreeError::RootNotFound))
     }
 
-    /// Get current root index (for proposal creation)
-    pub fn curr_idx(env: Env, dao_id: u64) -> u32 {
-        let current_root = Self::current_root(env.clone(), dao_id);
-        Self::root_idx(env, dao_id, current_root)
+    /// Get a group's current root index
+    pub fn group_curr_idx(env: Env, dao_id: u64, group_id: u32) -> u32 {
+        let current_root = Self::group_root(env.clone(), dao_id, group_id);
+        Self::group_root_idx(env, dao_id, group_id, current_root)
     }
 
-    /// Get leaf index for a commitment
-    pub fn get_leaf_index(env: Env, dao_id: u64, commitment: U256) -> u32 {
-        let key = DataKey::LeafIndex(dao_id, commitment);
+    /// Get leaf index for a commitment in a group
+    pub fn group_leaf_idx(env: Env, dao_id: u64, group_id: u32, commitment: U256) -> u64 {
+        let key = DataKey::LeafIndex(dao_id, group_id, commitment);
         env.storage()
             .persistent()
             .get(&key)
             .unwrap_or_else(|| panic_with_error!(&env, TreeError::MemberNotInTree))
     }
 
-    /// Get tree info for a DAO
-    pub fn get_tree_info(env: Env, dao_id: u64) -> (u32, u32, U256) {
-        let depth: u32 = env
+    /// Get tree info for a group, as `get_tree_info`
+    pub fn group_info(env: Env, dao_id: u64, group_id: u32) -> (u32, u32, U256) {
+        let depth: u32 = Self::group_depth(env.clone(), dao_id, group_id);
+        let next_index = Self::next_leaf_index(&env, dao_id, group_id);
+        let root = Self::group_root(env, dao_id, group_id);
+        (depth, u32::try_from(next_index).unwrap_or(u32::MAX), root)
+    }
+
+    /// Get slot usage of a group's tree, as `get_tree_usage`
+    pub fn group_usage(env: Env, dao_id: u64, group_id: u32) -> (u64, u64, u64) {
+        let depth: u32 = Self::group_depth(env.clone(), dao_id, group_id);
+        let next_index = Self::next_leaf_index(&env, dao_id, group_id);
+        let free_slots: u64 = env
             .storage()
             .persistent()
-            .get(&DataKey::TreeDepth(dao_id))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
-        let next_index: u32 = env
-            .storage()
+            .get(&DataKey::FreeSlotCount(dao_id, group_id))
+            .unwrap_or(0);
+        (
+            next_index,
+            next_index.saturating_sub(free_slots),
+            Self::capacity(depth),
+        )
+    }
+
+    /// Get the depth of a group's tree
+    pub fn group_depth(env: Env, dao_id: u64, group_id: u32) -> u32 {
+        env.storage()
             .persistent()
-            .get(&DataKey::NextLeafIndex(dao_id))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
-        let root = Self::current_root(env, dao_id);
-        (depth, next_index, root)
+            .get(&DataKey::TreeDepth(dao_id, group_id))
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized))
     }
 
-    /// Get Merkle path for a specific leaf index
-    /// Returns (pathElements, pathIndices) where:
-    /// - pathElements[i] is the sibling hash at level i
-    /// - pathIndices[i] is 0 if leaf is left child, 1 if right child
+    /// Get the minimum valid root index of a group (see `min_root`)
+    pub fn group_min_root(env: Env, dao_id: u64, group_id: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MinValidRootIdx(dao_id, group_id))
+            .unwrap_or(0)
+    }
+
+    /// Get Merkle path for a leaf in a group, as `get_merkle_path`
     ///
     /// This optimized version reads stored node hashes directly (O(depth) reads)
     /// instead of reconstructing subtrees (which was O(n * log n) hashes).
-    pub fn get_merkle_path(env: Env, dao_id: u64, leaf_index: u32) -> (Vec<U256>, Vec<u32>) {
-        let depth: u32 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TreeDepth(dao_id))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
-
-        let next_index: u32 = env
+    pub fn group_path(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        leaf_index: u64,
+    ) -> (Vec<U256>, Vec<u32>) {
+        let depth: u32 = Self::group_depth(env.clone(), dao_id, group_id);
+
+        let next_index: u64 = env
             .storage()
             .persistent()
-            .get(&DataKey::NextLeafIndex(dao_id))
+            .get(&DataKey::NextLeafIndex(dao_id, group_id))
             .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
 
         if leaf_index >= next_index {
@@ -637,7 +947,7 @@ impl MembershipTree {
             let sibling = if level == 0 {
                 // Level 0: sibling is a raw leaf (commitment value)
                 if sibling_index < next_index {
-                    let leaf_key = DataKey::LeafValue(dao_id, sibling_index);
+                    let leaf_key = DataKey::LeafValue(dao_id, group_id, sibling_index);
                     env.storage()
                         .persistent()
                         .get(&leaf_key)
@@ -648,7 +958,7 @@ impl MembershipTree {
                 }
             } else {
                 // Level > 0: look up stored node hash (written during insert_leaf)
-                let node_key = DataKey::NodeHash(dao_id, level, sibling_index);
+                let node_key = DataKey::NodeHash(dao_id, group_id, level, sibling_index);
                 env.storage()
                     .persistent()
                     .get(&node_key)
@@ -662,6 +972,142 @@ impl MembershipTree {
         (path_elements, path_indices)
     }
 
+    /// Get Merkle path for a leaf in a group against a historical root, as
+    /// `get_merkle_path_at`
+    ///
+    /// Rebuilds the tree state at `root` by reverting newer root transitions in memory,
+    /// so a voter can produce a proof for a Fixed-mode proposal's `eligible_root` after
+    /// later membership changes. The path length is the tree depth at that root.
+    pub fn group_path_at(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        leaf_index: u64,
+        root: U256,
+    ) -> (Vec<U256>, Vec<u32>) {
+        let next_index: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextLeafIndex(dao_id, group_id))
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
+        if leaf_index >= next_index {
+            panic_with_error!(&env, TreeError::LeafOutOfBounds);
+        }
+
+        let (overrides, depth) = Self::state_at(&env, dao_id, group_id, &root, next_index);
+        if leaf_index >= Self::capacity(depth) {
+            panic_with_error!(&env, TreeError::LeafOutOfBounds);
+        }
+
+        Self::path_from(
+            &env, dao_id, group_id, &overrides, leaf_index, depth, next_index,
+        )
+    }
+
+    /// Get a consistency proof between two roots of a group, as `get_consistency_proof`
+    pub fn group_consistency_proof(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        old_root: U256,
+        new_root: U256,
+    ) -> Vec<ConsistencyStep> {
+        let next_index: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextLeafIndex(dao_id, group_id))
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
+
+        let (mut overrides, mut depth) =
+            Self::state_at(&env, dao_id, group_id, &old_root, next_index);
+        let first = Self::first_transition_after(&env, dao_id, group_id, &old_root);
+        let end = Self::first_transition_after(&env, dao_id, group_id, &new_root);
+        if end < first {
+            panic_with_error!(&env, TreeError::InvalidRootRange);
+        }
+        let current_depth = Self::group_depth(env.clone(), dao_id, group_id);
+        let poseidon = Poseidon::pair(&env);
+
+        let mut steps = Vec::new(&env);
+        for root_index in first..end {
+            let transition = Self::transition(&env, dao_id, group_id, root_index)
+                .unwrap_or_else(|| panic_with_error!(&env, TreeError::HistoryUnavailable));
+
+            let path = if transition.old_depth == transition.new_depth {
+                let (path, _) = Self::path_from(
+                    &env,
+                    dao_id,
+                    group_id,
+                    &overrides,
+                    transition.leaf_index,
+                    depth,
+                    next_index,
+                );
+                Self::override_leaf(
+                    &env,
+                    &poseidon,
+                    dao_id,
+                    group_id,
+                    &mut overrides,
+                    transition.leaf_index,
+                    transition.new_value.clone(),
+                    current_depth,
+                    next_index,
+                );
+                path
+            } else {
+                depth = transition.new_depth;
+                Vec::new(&env)
+            };
+
+            steps.push_back(ConsistencyStep { transition, path });
+        }
+
+        steps
+    }
+
+    /// Get the transition that produced a group's root index, as `get_transition`
+    pub fn group_transition(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        root_index: u32,
+    ) -> Option<RootTransition> {
+        Self::transition(&env, dao_id, group_id, root_index)
+    }
+
+    /// Get Merkle path for a leaf against a historical root still in `Roots(dao_id)`
+    /// Rebuilds the tree state at `root` by reverting newer root transitions in memory,
+    /// so a voter can produce a proof for a Fixed-mode proposal's `eligible_root` after
+    /// later membership changes. The path length is the tree depth at that root.
+    pub fn get_merkle_path_at(
+        env: Env,
+        dao_id: u64,
+        leaf_index: u64,
+        root: U256,
+    ) -> (Vec<U256>, Vec<u32>) {
+        Self::group_path_at(env, dao_id, GENERAL_GROUP, leaf_index, root)
+    }
+
+    /// Get a consistency proof that `new_root` was reached from `old_root` only by
+    /// appending, refilling or zeroing leaves (plus depth growth)
+    /// Each step carries the recorded transition and the sibling path of the changed leaf
+    /// just before the change. Check it with `consistency::verify_consistency`.
+    pub fn get_consistency_proof(
+        env: Env,
+        dao_id: u64,
+        old_root: U256,
+        new_root: U256,
+    ) -> Vec<ConsistencyStep> {
+        Self::group_consistency_proof(env, dao_id, GENERAL_GROUP, old_root, new_root)
+    }
+
+    /// Get the transition that produced a root index (None once evicted from history)
+    /// Clients can replay transitions as a compact diff instead of the full event stream.
+    pub fn get_transition(env: Env, dao_id: u64, root_index: u32) -> Option<RootTransition> {
+        Self::group_transition(env, dao_id, GENERAL_GROUP, root_index)
+    }
+
     /// Get SBT contract address
     pub fn sbt_contr(env: Env) -> Address {
         env.storage()
@@ -671,234 +1117,1572 @@ impl MembershipTree {
     }
 
     /// Pre-initialize the zeros cache to avoid budget issues during first tree operations.
-    /// This should be called once during deployment to precompute zero values for all levels.
-    pub fn init_zeros_cache(env: Env) {
-        Self::ensure_zeros_cache(&env);
-    }
+    /// Zero values are now compile-time constants, so this is a no-op kept for deploy scripts.
+    pub fn init_zeros_cache(_env: Env) {}
 
     /// Remove a member by zeroing their leaf and recomputing the root
     /// Only callable by DAO admin
     /// This zeros the leaf in the Merkle tree, preventing proofs against new roots
+    /// While membership is frozen the removal is queued (see `set_membership_freeze`).
+    /// With a removal delay set, the removal is only scheduled; anyone can apply it with
+    /// `execute_removal` once the delay has passed (see `set_removal_delay`).
     pub fn remove_member(env: Env, dao_id: u64, member: Address, admin: Address) {
         admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
 
-        // Verify admin owns the DAO via SBT -> Registry chain
-        let sbt_contract: Address = Self::sbt_contr(env.clone());
-        let registry: Address = env.invoke_contract(
-            &sbt_contract,
-            &symbol_short!("registry"),
-            soroban_sdk::vec![&env],
-        );
-        let dao_admin: Address = env.invoke_contract(
-            &registry,
-            &symbol_short!("get_admin"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
-        );
-        if dao_admin != admin {
-            panic_with_error!(&env, TreeError::NotAdmin);
+        let delay = Self::removal_delay(env.clone(), dao_id);
+        if delay > 0 {
+            Self::schedule_removal(&env, dao_id, GENERAL_GROUP, member, delay);
+            return;
         }
+        Self::remove_now(&env, dao_id, GENERAL_GROUP, member);
+    }
 
-        // Get member's leaf index
-        let member_key = DataKey::MemberLeafIndex(dao_id, member.clone());
-        let leaf_index: u32 = env
-            .storage()
-            .persistent()
-            .get(&member_key)
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::MemberNotInTree));
+    /// Remove a member without waiting for the removal delay (admin only)
+    /// The override for emergencies, such as a compromised member key. Emits an
+    /// `EmergencyRemovalEvent` naming the admin, replaces any scheduled removal of the
+    /// member, and is still queued while membership is frozen, so it cannot change the
+    /// electorate of an open proposal.
+    pub fn emergency_remove(env: Env, dao_id: u64, member: Address, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        let scheduled = Self::unschedule_removal(&env, dao_id, GENERAL_GROUP, &member).is_some();
+        EmergencyRemovalEvent {
+            dao_id,
+            member: member.clone(),
+            admin,
+            scheduled,
+        }
+        .publish(&env);
+        // Skips the delay, not the freeze
+        Self::remove_now(&env, dao_id, GENERAL_GROUP, member);
+    }
+
+    /// Apply a scheduled removal once its delay has passed (anyone can call)
+    pub fn execute_removal(env: Env, dao_id: u64, member: Address) {
+        let executable_at = Self::unschedule_removal(&env, dao_id, GENERAL_GROUP, &member)
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::RemovalNotScheduled));
+        if env.ledger().timestamp() < executable_at {
+            panic_with_error!(&env, TreeError::RemovalNotDue);
+        }
+        Self::remove_now(&env, dao_id, GENERAL_GROUP, member);
+    }
+
+    /// Cancel a scheduled removal before it is executed (admin only)
+    pub fn cancel_removal(env: Env, dao_id: u64, member: Address, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        if Self::unschedule_removal(&env, dao_id, GENERAL_GROUP, &member).is_none() {
+            panic_with_error!(&env, TreeError::RemovalNotScheduled);
+        }
+        RemovalCancelledEvent {
+            dao_id,
+            member,
+            group_id: GENERAL_GROUP,
+        }
+        .publish(&env);
+    }
+
+    /// Removals waiting for their delay: member -> earliest execution timestamp
+    pub fn scheduled_removals(env: Env, dao_id: u64) -> Map<Address, u64> {
+        Self::removal_schedule(&env, dao_id, GENERAL_GROUP)
+    }
+
+    /// Set the delay between `remove_member` (or `remove_from_group`) and the removal
+    /// taking effect (admin only)
+    /// Raising the delay applies at once. Lowering it only applies once the current
+    /// delay has passed, so a removal cannot be rushed by cutting the delay first.
+    /// Removals already scheduled keep their execution time. 0 removes immediately.
+    pub fn set_removal_delay(env: Env, dao_id: u64, delay: u64, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        let current = Self::removal_delay(env.clone(), dao_id);
+        let storage = env.storage().persistent();
+        let effective_at = if delay >= current {
+            storage.set(&DataKey::RemovalDelay(dao_id), &delay);
+            storage.remove(&DataKey::RemovalDelayCut(dao_id));
+            env.ledger().timestamp()
+        } else {
+            // The delay in force (possibly an earlier cut) holds until the new cut applies
+            let effective_at = env.ledger().timestamp().saturating_add(current);
+            storage.set(&DataKey::RemovalDelay(dao_id), &current);
+            storage.set(&DataKey::RemovalDelayCut(dao_id), &(delay, effective_at));
+            effective_at
+        };
+
+        RemovalDelayEvent {
+            dao_id,
+            delay,
+            effective_at,
+        }
+        .publish(&env);
+    }
+
+    /// Removal delay currently in force for a DAO, in seconds (0 = immediate removals)
+    pub fn removal_delay(env: Env, dao_id: u64) -> u64 {
+        if let Some((delay, effective_at)) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, (u64, u64)>(&DataKey::RemovalDelayCut(dao_id))
+        {
+            if env.ledger().timestamp() >= effective_at {
+                return delay;
+            }
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::RemovalDelay(dao_id))
+            .unwrap_or(0)
+    }
+
+    /// Zero the leaves of a member who left through the SBT contract's `leave`, or whom its
+    /// `revoke_below_gate` removed. Only callable by the SBT contract. Mirrors `remove_member` (leaf zeroed,
+    /// `MinValidRootIdx` bumped, `RemovalEvent` emitted) so a voluntary exit is as final
+    /// as a removal; members who never registered a commitment are a no-op. While
+    /// membership is frozen, or changes queued by the freeze are still waiting, the tree
+    /// side is queued behind them; the SBT is revoked at once either way.
+    pub fn remove_departed(env: Env, dao_id: u64, member: Address) {
+        Self::sbt_contract(&env).require_auth();
+
+        // Not `defer`: applying queued changes calls back into the SBT, which is mid-`leave`,
+        // so the departure waits behind the queue instead of draining it
+        let pending = Self::pending_changes(env.clone(), dao_id);
+        if !pending.is_empty() || Self::membership_frozen(env.clone(), dao_id) {
+            Self::enqueue(&env, dao_id, pending, PendingChange::Depart(member));
+            return;
+        }
+        Self::apply_departure(&env, dao_id, member);
+    }
+
+    /// Re-key a recovered member's leaves to their new address
+    /// Only callable by the SBT contract (via `recover`), which checks the approvals.
+    /// Leaves, commitments and roots stay as they are; only address-keyed lookups move.
+    /// Refused while a scheduled removal or a queued membership change names `old_address`.
+    pub fn recover_from_sbt(env: Env, dao_id: u64, old_address: Address, new_address: Address) {
+        Self::sbt_contract(&env).require_auth();
+
+        // Queued changes name the old address, so they must be applied before it moves
+        if Self::pending_changes(env.clone(), dao_id)
+            .iter()
+            .any(|change| Self::change_member(&change) == &old_address)
+        {
+            panic_with_error!(&env, TreeError::MembershipFrozen);
+        }
+
+        let mut group_ids = Self::get_groups(env.clone(), dao_id);
+        group_ids.push_front(GENERAL_GROUP);
+        for group_id in group_ids.iter() {
+            // A pending removal must be executed or cancelled first, not silently retargeted
+            if Self::removal_schedule(&env, dao_id, group_id).contains_key(old_address.clone()) {
+                panic_with_error!(&env, TreeError::RemovalScheduled);
+            }
+            let old_leaf_key = DataKey::MemberLeafIndex(dao_id, group_id, old_address.clone());
+            let new_leaf_key = DataKey::MemberLeafIndex(dao_id, group_id, new_address.clone());
+            if env.storage().persistent().has(&new_leaf_key) {
+                panic_with_error!(&env, TreeError::MemberExists);
+            }
+            if let Some(index) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, u64>(&old_leaf_key)
+            {
+                env.storage().persistent().set(&new_leaf_key, &index);
+                env.storage().persistent().remove(&old_leaf_key);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::LeafOwner(dao_id, group_id, index), &new_address);
+            }
+
+            let old_removed_key = DataKey::RemovedCommitment(dao_id, group_id, old_address.clone());
+            if let Some(commitment) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, U256>(&old_removed_key)
+            {
+                env.storage().persistent().set(
+                    &DataKey::RemovedCommitment(dao_id, group_id, new_address.clone()),
+                    &commitment,
+                );
+                env.storage().persistent().remove(&old_removed_key);
+            }
+
+            let old_group_key = DataKey::GroupMember(dao_id, group_id, old_address.clone());
+            if env.storage().persistent().has(&old_group_key) {
+                env.storage().persistent().set(
+                    &DataKey::GroupMember(dao_id, group_id, new_address.clone()),
+                    &true,
+                );
+                env.storage().persistent().remove(&old_group_key);
+            }
+        }
+    }
+
+    /// Reinstate a previously removed member in one step (admin only)
+    /// Clears the SBT revocation and inserts the member's leaf at a fresh index: `commitment`
+    /// when given, otherwise the commitment that was zeroed on removal is re-enabled.
+    /// Emits a single `ReinstatementEvent` describing the new leaf.
+    pub fn reinstate_member(
+        env: Env,
+        dao_id: u64,
+        member: Address,
+        commitment: Option<U256>,
+        admin: Address,
+    ) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        let change = PendingChange::Reinstate(member.clone(), commitment.clone());
+        if Self::defer(&env, dao_id, change) {
+            return;
+        }
+        if let Some(error) = Self::reinstate_error(&env, dao_id, &member, &commitment) {
+            panic_with_error!(&env, error);
+        }
+        Self::apply_reinstatement(&env, dao_id, member, commitment);
+    }
+
+    /// Approve a membership application made through the SBT's `apply` (admin only)
+    /// Mints the applicant's SBT and registers the commitment they applied with, if any.
+    /// A commitment that cannot be registered (e.g. already in the tree) fails the approval;
+    /// reject the application instead so the applicant can apply again.
+    pub fn approve_application(env: Env, dao_id: u64, applicant: Address, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        // The SBT only accepts this from its bound tree
+        let commitment: Option<U256> = env.invoke_contract(
+            &Self::sbt_contract(&env),
+            &Symbol::new(&env, "approve_from_tree"),
+            soroban_sdk::vec![
+                &env,
+                dao_id.into_val(&env),
+                applicant.clone().into_val(&env)
+            ],
+        );
+        let Some(commitment) = commitment else {
+            return;
+        };
+
+        let change = PendingChange::Register(GENERAL_GROUP, commitment.clone(), applicant.clone());
+        if Self::defer(&env, dao_id, change) {
+            return;
+        }
+        Self::add_commitment(&env, dao_id, GENERAL_GROUP, commitment, applicant);
+    }
+
+    /// Mint an SBT and register its holder's commitment in one call (admin only)
+    /// Entries are `(member, encrypted_alias, commitment)`; a `None` commitment only mints.
+    /// Existing SBT holders are skipped and returned untouched. Registrations are deferred
+    /// like any other while membership is frozen. At most `IMPORT_BATCH` (1) entry per
+    /// call, as one leaf can write 18 node hashes at depth 18; for bulk onboarding, mint with
+    /// the SBT's `mint_batch` (10 per call) and let members register their own commitments.
+    pub fn import_members(
+        env: Env,
+        dao_id: u64,
+        members: Vec<(Address, Option<String>, Option<U256>)>,
+        admin: Address,
+    ) -> Vec<Address> {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        if members.len() > IMPORT_BATCH {
+            panic_with_error!(&env, TreeError::BatchTooLarge);
+        }
+
+        let mut mints = Vec::new(&env);
+        for (member, alias, _) in members.iter() {
+            mints.push_back((member, alias));
+        }
+        let skipped: Vec<Address> = env.invoke_contract(
+            &Self::sbt_contract(&env),
+            &Symbol::new(&env, "mint_batch"),
+            soroban_sdk::vec![
+                &env,
+                dao_id.into_val(&env),
+                mints.into_val(&env),
+                admin.into_val(&env)
+            ],
+        );
+
+        // Once one registration applies directly the queue is empty and stays so
+        let mut deferring = true;
+        let mut entries = Vec::new(&env);
+        for (member, _, commitment) in members.iter() {
+            let Some(commitment) = commitment else {
+                continue;
+            };
+            if skipped.contains(&member) {
+                continue;
+            }
+            let change = PendingChange::Register(GENERAL_GROUP, commitment.clone(), member.clone());
+            if deferring && Self::defer(&env, dao_id, change) {
+                continue;
+            }
+            deferring = false;
+            entries.push_back((member, commitment));
+        }
+        Self::add_commitments(&env, dao_id, GENERAL_GROUP, entries);
+        skipped
+    }
+
+    // Internal: Re-insert a removed member's leaf and clear their SBT revocation
+    // Callers check `reinstate_error` first.
+    fn apply_reinstatement(env: &Env, dao_id: u64, member: Address, commitment: Option<U256>) {
+        let leaf_index_key = DataKey::MemberLeafIndex(dao_id, GENERAL_GROUP, member.clone());
+        let removed_key = DataKey::RemovedCommitment(dao_id, GENERAL_GROUP, member.clone());
+        let (commitment, reused) = match commitment {
+            Some(commitment) => (commitment, false),
+            None => {
+                let removed: U256 = env
+                    .storage()
+                    .persistent()
+                    .get(&removed_key)
+                    .unwrap_or_else(|| panic_with_error!(env, TreeError::NoPriorCommitment));
+                // Release the old commitment so it can be inserted again
+                env.storage().persistent().remove(&DataKey::LeafIndex(
+                    dao_id,
+                    GENERAL_GROUP,
+                    removed.clone(),
+                ));
+                // A re-enabled commitment leaves the revocation tree again
+                Self::set_revocation_leaf(env, dao_id, &removed, false);
+                (removed, true)
+            }
+        };
+        env.storage().persistent().remove(&removed_key);
+        env.storage().persistent().remove(&leaf_index_key);
+
+        // Clear the SBT revocation; the SBT only accepts this from its bound tree
+        env.invoke_contract::<()>(
+            &Self::sbt_contract(env),
+            &symbol_short!("reinstate"),
+            soroban_sdk::vec![env, dao_id.into_val(env), member.clone().into_val(env)],
+        );
+
+        let mut frontier = Frontier::load(env, dao_id, GENERAL_GROUP);
+        let (index, new_root, root_index, expires_at) = Self::insert_commitment(
+            env,
+            dao_id,
+            GENERAL_GROUP,
+            &mut frontier,
+            commitment.clone(),
+            &member,
+        );
+        frontier.save(env, dao_id, GENERAL_GROUP);
+
+        let reinstated_at = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &DataKey::ReinstatedAt(dao_id, commitment.clone()),
+            &reinstated_at,
+        );
+
+        ReinstatementEvent {
+            dao_id,
+            member,
+            reinstated_at,
+            commitment,
+            reused,
+            index,
+            new_root,
+            root_index,
+            expires_at,
+        }
+        .publish(env);
+    }
+
+    /// Get revocation timestamp for a commitment (returns None if never revoked)
+    /// Used by voting contract to check if member was revoked
+    pub fn revok_at(env: Env, dao_id: u64, commitment: U256) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RevokedAt(dao_id, commitment))
+    }
+
+    /// Get reinstatement timestamp for a commitment (returns None if never reinstated)
+    /// Used by voting contract to check if member was reinstated after revocation
+    pub fn reinst_at(env: Env, dao_id: u64, commitment: U256) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReinstatedAt(dao_id, commitment))
+    }
+
+    /// Get the minimum valid root index for a DAO
+    /// Roots with index < min_valid_root_index are invalid for Trailing mode proposals
+    /// Returns 0 if no members have been removed
+    pub fn min_root(env: Env, dao_id: u64) -> u32 {
+        Self::group_min_root(env, dao_id, GENERAL_GROUP)
+    }
+
+    /// Turn on strict revocation for a DAO (admin only, cannot be turned off)
+    /// From then on every commitment zeroed by `remove_member` or a voluntary exit is also
+    /// added to the DAO's sparse Merkle revocation tree, keyed by commitment. Proposals
+    /// created afterwards need the strict vote circuit, which proves at vote time that the
+    /// voter's commitment is not in that tree, so revocation takes effect immediately even
+    /// for Fixed-mode snapshots.
+    pub fn enable_strict_revocation(env: Env, dao_id: u64, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::TreeDepth(dao_id, GENERAL_GROUP))
+        {
+            panic_with_error!(&env, TreeError::TreeNotInitialized);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::StrictRevocation(dao_id), &true);
+
+        StrictRevocationEvent {
+            dao_id,
+            revocation_root: Self::revocation_root(env.clone(), dao_id),
+        }
+        .publish(&env);
+    }
+
+    /// Check whether removals feed the DAO's revocation tree
+    pub fn strict_revocation(env: Env, dao_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StrictRevocation(dao_id))
+            .unwrap_or(false)
+    }
+
+    /// Current root of the DAO's revocation tree (0 while nothing is revoked)
+    /// The voting contract passes it as the last public signal of strict-mode votes.
+    pub fn revocation_root(env: Env, dao_id: u64) -> U256 {
+        RevocationTree::load(&env, dao_id).root()
+    }
+
+    /// `SMTVerifier` witness for a commitment: the `REVOCATION_DEPTH` siblings from the
+    /// root down (zero-padded) and the commitment of the leaf its path ends at, if any.
+    /// The commitment is revoked iff that leaf is the commitment itself; otherwise this is
+    /// the exclusion proof (`isOld0` when no leaf is returned).
+    pub fn revocation_proof(env: Env, dao_id: u64, commitment: U256) -> (Vec<U256>, Option<U256>) {
+        RevocationTree::load(&env, dao_id).witness(&commitment)
+    }
+
+    /// Freeze a DAO's membership while proposals are open (admin only)
+    /// With a voting contract set, `register_*`, `remove_member`, `remove_from_group`,
+    /// `reinstate_member` and the tree side of SBT `leave` are queued instead of applied
+    /// while that contract reports an active proposal for the DAO. Once voting ends the queue applies in order, `PENDING_BATCH` changes at a
+    /// time: when the last freezing proposal is closed (see `release_pending`), ahead of
+    /// every later membership change, and through the permissionless `apply_pending`.
+    /// Neither turning the freeze off (`None`) nor pointing it at another voting contract
+    /// is accepted while membership is frozen.
+    pub fn set_membership_freeze(
+        env: Env,
+        dao_id: u64,
+        voting_contract: Option<Address>,
+        admin: Address,
+    ) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        if Self::membership_frozen(env.clone(), dao_id) {
+            panic_with_error!(&env, TreeError::MembershipFrozen);
+        }
+
+        let key = DataKey::MembershipFreeze(dao_id);
+        match &voting_contract {
+            Some(voting) => {
+                // Only a voting contract reading this tree can vouch for its proposals
+                let voting_tree: Address = env.invoke_contract(
+                    voting,
+                    &Symbol::new(&env, "tree_contract"),
+                    soroban_sdk::vec![&env],
+                );
+                if voting_tree != env.current_contract_address() {
+                    panic_with_error!(&env, TreeError::VotingNotBound);
+                }
+                env.storage().persistent().set(&key, voting);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        MembershipFreezeEvent {
+            dao_id,
+            voting_contract,
+        }
+        .publish(&env);
+    }
+
+    /// Voting contract whose active proposals freeze the DAO's membership, if any
+    pub fn membership_freeze(env: Env, dao_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MembershipFreeze(dao_id))
+    }
+
+    /// Check whether membership changes are currently queued instead of applied
+    pub fn membership_frozen(env: Env, dao_id: u64) -> bool {
+        match Self::membership_freeze(env.clone(), dao_id) {
+            Some(voting) => env.invoke_contract(
+                &voting,
+                &Symbol::new(&env, "has_active_proposal"),
+                soroban_sdk::vec![&env, dao_id.into_val(&env)],
+            ),
+            None => false,
+        }
+    }
+
+    /// Membership changes held back by the freeze, oldest first
+    pub fn pending_changes(env: Env, dao_id: u64) -> Vec<PendingChange> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingChanges(dao_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Apply up to `max_changes` queued membership changes once voting has ended
+    /// Callable by anyone. Closing a proposal and each membership change only apply
+    /// `PENDING_BATCH` changes, so this empties a longer queue or one left by proposals
+    /// that ran to their end time. Each change costs about as much as making it directly,
+    /// so keep `max_changes` to a few per transaction and repeat until this returns 0.
+    /// Changes that are no longer valid (e.g. a commitment registered twice) are dropped
+    /// with a `ChangeDroppedEvent`. Returns the number still queued.
+    pub fn apply_pending(env: Env, dao_id: u64, max_changes: u32) -> u32 {
+        if Self::membership_frozen(env.clone(), dao_id) {
+            panic_with_error!(&env, TreeError::MembershipFrozen);
+        }
+        Self::drain_pending(&env, dao_id, max_changes).len()
+    }
+
+    /// Apply up to `PENDING_BATCH` queued changes as voting ends (freezing contract only)
+    /// Called by the voting contract set with `set_membership_freeze` from `close_proposal`
+    /// once the DAO has no active proposal left, so the queue starts applying without
+    /// anyone calling `apply_pending`. Returns the number still queued.
+    pub fn release_pending(env: Env, dao_id: u64) -> u32 {
+        let voting = Self::membership_freeze(env.clone(), dao_id)
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::VotingNotBound));
+        // The caller has just checked it has no active proposal; asking it again here
+        // would re-enter it
+        voting.require_auth();
+        Self::drain_pending(&env, dao_id, PENDING_BATCH).len()
+    }
+
+    /// Enable or disable leaf slot reuse for a DAO (admin only)
+    /// When enabled, new commitments refill slots zeroed by `remove_member` before
+    /// appending at `next_index`, so churn does not exhaust the tree.
+    /// Zeroed slots are tracked regardless of this setting.
+    pub fn set_slot_reuse(env: Env, dao_id: u64, enabled: bool, admin: Address) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::TreeDepth(dao_id, GENERAL_GROUP))
+        {
+            panic_with_error!(&env, TreeError::TreeNotInitialized);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::SlotReuse(dao_id, GENERAL_GROUP), &enabled);
+
+        SlotReuseEvent { dao_id, enabled }.publish(&env);
+    }
+
+    /// Check if leaf slot reuse is enabled for a DAO
+    pub fn slot_reuse(env: Env, dao_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SlotReuse(dao_id, GENERAL_GROUP))
+            .unwrap_or(false)
+    }
+
+    /// Grow a DAO's tree to a larger depth (admin only)
+    /// The current root becomes the leftmost node at the old depth and is hashed with
+    /// zero subtrees up to `new_depth`, so every leaf index and Merkle path prefix is
+    /// kept; paths simply gain `new_depth - depth` zero siblings.
+    /// `voting_contract` must be bound to this tree and hold a VK for `new_depth` built
+    /// for the DAO's circuit variant (expiring leaves, strict revocation), otherwise
+    /// members could no longer produce verifiable proofs.
+    /// Roots recorded before growth stay in history, so proposals pinned to the old
+    /// depth's VK keep working with pre-growth roots.
+    pub fn grow_tree(
+        env: Env,
+        dao_id: u64,
+        new_depth: u32,
+        voting_contract: Address,
+        admin: Address,
+    ) {
+        Self::grow_group(
+            env,
+            dao_id,
+            GENERAL_GROUP,
+            new_depth,
+            voting_contract,
+            admin,
+        )
+    }
+
+    /// Grow a group's tree to a larger depth (admin only), as `grow_tree`
+    pub fn grow_group(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        new_depth: u32,
+        voting_contract: Address,
+        admin: Address,
+    ) {
+        admin.require_auth();
+        Self::assert_dao_admin(&env, dao_id, &admin);
+
+        let depth_key = DataKey::TreeDepth(dao_id, group_id);
+        let depth: u32 = env
+            .storage()
+            .persistent()
+            .get(&depth_key)
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::TreeNotInitialized));
+
+        if new_depth <= depth || new_depth > MAX_TREE_DEPTH {
+            panic_with_error!(&env, TreeError::InvalidDepth);
+        }
+
+        // Check a VK for the new depth is available in a voting contract wired to this tree
+        let voting_tree: Address = env.invoke_contract(
+            &voting_contract,
+            &Symbol::new(&env, "tree_contract"),
+            soroban_sdk::vec![&env],
+        );
+        if voting_tree != env.current_contract_address() {
+            panic_with_error!(&env, TreeError::VkNotAvailable);
+        }
+        let ic_len: u32 = env
+            .invoke_contract::<Option<u32>>(
+                &voting_contract,
+                &Symbol::new(&env, "depth_vk_ic_len"),
+                soroban_sdk::vec![&env, dao_id.into_val(&env), new_depth.into_val(&env)],
+            )
+            .unwrap_or_else(|| panic_with_error!(&env, TreeError::VkNotAvailable));
+        // A VK for another variant cannot verify the DAO's public signals, and proposals
+        // only accept the matching one, so voting would stop for good
+        let expected_ic_len = VOTE_CIRCUIT_IC_LEN
+            + Self::expiring(env.clone(), dao_id) as u32
+            + Self::strict_revocation(env.clone(), dao_id) as u32;
+        if ic_len != expected_ic_len {
+            panic_with_error!(&env, TreeError::VkCircuitMismatch);
+        }
+
+        let mut frontier = Frontier::load(&env, dao_id, group_id);
+
+        // Extend upwards: the old root is node 0 at level `depth`, its right siblings are empty.
+        // The new spine nodes are not stored until a leaf lands past them (see node_needed).
+        let poseidon = Poseidon::pair(&env);
+        let mut current_hash = Self::group_root(env.clone(), dao_id, group_id);
+        for level in depth..new_depth {
+            frontier.filled.push_back(current_hash.clone());
+            let zero = Self::zero_at_level(&env, level);
+            current_hash = poseidon.hash_pair(&env, &current_hash, &zero);
+        }
+        frontier.filled_dirty = true;
+        env.storage().persistent().set(&depth_key, &new_depth);
+
+        let root_index = frontier.push_root(&env, dao_id, group_id, &current_hash);
+        frontier.save(&env, dao_id, group_id);
+        Self::record_transition(
+            &env,
+            dao_id,
+            group_id,
+            root_index,
+            &RootTransition {
+                leaf_index: 0,
+                old_value: Self::zero_value(&env),
+                new_value: Self::zero_value(&env),
+                old_depth: depth,
+                new_depth,
+                new_root: current_hash.clone(),
+            },
+        );
+
+        TreeGrowEvent {
+            dao_id,
+            group_id,
+            old_depth: depth,
+            new_depth,
+            new_root: current_hash,
+            root_index,
+        }
+        .publish(&env);
+    }
+
+    // Internal: Verify admin owns the DAO via SBT -> Registry chain
+    fn assert_dao_admin(env: &Env, dao_id: u64, admin: &Address) {
+        let sbt_contract: Address = Self::sbt_contract(env);
+        let registry: Address = env.invoke_contract(
+            &sbt_contract,
+            &symbol_short!("registry"),
+            soroban_sdk::vec![env],
+        );
+        let dao_admin: Address = env.invoke_contract(
+            &registry,
+            &symbol_short!("get_admin"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        );
+        if &dao_admin != admin {
+            panic_with_error!(env, TreeError::NotAdmin);
+        }
+    }
+
+    // Internal: Remove a member from the DAO (general group) or from one group now, or
+    // queue the removal while membership is frozen
+    fn remove_now(env: &Env, dao_id: u64, group_id: u32, member: Address) {
+        let change = if group_id == GENERAL_GROUP {
+            PendingChange::Remove(member.clone())
+        } else {
+            PendingChange::RemoveFromGroup(group_id, member.clone())
+        };
+        if Self::defer(env, dao_id, change) {
+            return;
+        }
+        if group_id == GENERAL_GROUP {
+            Self::apply_removal(env, dao_id, member);
+        } else {
+            Self::apply_group_removal(env, dao_id, group_id, member);
+        }
+    }
+
+    // Internal: Storage key of the removals scheduled in a group
+    // The general group keeps the DAO-wide key `scheduled_removals` has always read.
+    fn schedule_key(dao_id: u64, group_id: u32) -> DataKey {
+        if group_id == GENERAL_GROUP {
+            DataKey::ScheduledRemovals(dao_id)
+        } else {
+            DataKey::ScheduledGroupRemovals(dao_id, group_id)
+        }
+    }
+
+    // Internal: Removals scheduled in a group, member -> executable_at
+    fn removal_schedule(env: &Env, dao_id: u64, group_id: u32) -> Map<Address, u64> {
+        env.storage()
+            .persistent()
+            .get(&Self::schedule_key(dao_id, group_id))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    // Internal: Record a removal from a group to be executed once `delay` has passed
+    fn schedule_removal(env: &Env, dao_id: u64, group_id: u32, member: Address, delay: u64) {
+        // Fail now rather than at execution for members without a live leaf
+        if let Some(error) = Self::leaf_error(env, dao_id, group_id, &member) {
+            panic_with_error!(env, error);
+        }
+
+        let mut scheduled = Self::removal_schedule(env, dao_id, group_id);
+        if scheduled.contains_key(member.clone()) {
+            panic_with_error!(env, TreeError::RemovalScheduled);
+        }
+        if scheduled.len() >= MAX_SCHEDULED_REMOVALS {
+            panic_with_error!(env, TreeError::PendingQueueFull);
+        }
+
+        let executable_at = env.ledger().timestamp().saturating_add(delay);
+        scheduled.set(member.clone(), executable_at);
+        env.storage()
+            .persistent()
+            .set(&Self::schedule_key(dao_id, group_id), &scheduled);
+        RemovalScheduledEvent {
+            dao_id,
+            member,
+            group_id,
+            executable_at,
+        }
+        .publish(env);
+    }
+
+    // Internal: Drop a member's scheduled removal from a group, returning when it was due
+    fn unschedule_removal(env: &Env, dao_id: u64, group_id: u32, member: &Address) -> Option<u64> {
+        let key = Self::schedule_key(dao_id, group_id);
+        let mut scheduled: Map<Address, u64> = env.storage().persistent().get(&key)?;
+        let executable_at = scheduled.get(member.clone())?;
+        scheduled.remove(member.clone());
+        if scheduled.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &scheduled);
+        }
+        Some(executable_at)
+    }
+
+    // Internal: Zero a member's general leaf and group leaves and revoke their SBT
+    fn apply_removal(env: &Env, dao_id: u64, member: Address) {
+        let (leaf_index, new_root, root_index) =
+            Self::zero_member_leaf(env, dao_id, GENERAL_GROUP, &member);
+        Self::revoke_removed(env, dao_id, GENERAL_GROUP, &member);
+
+        // Leaving the DAO also drops the member from every group they joined
+        Self::remove_from_all_groups(env, dao_id, &member);
+
+        // Also revoke the member's SBT in the same transaction
+        // The SBT only accepts this from its bound tree; the admin was checked on entry
+        env.invoke_contract::<()>(
+            &Self::sbt_contract(env),
+            &Symbol::new(env, "revoke_from_tree"),
+            soroban_sdk::vec![env, dao_id.into_val(env), member.clone().into_val(env)],
+        );
+
+        RemovalEvent {
+            dao_id,
+            member,
+            group_id: GENERAL_GROUP,
+            index: leaf_index,
+            new_root,
+            root_index,
+        }
+        .publish(env);
+    }
+
+    // Internal: Withdraw a member's group eligibility and zero their leaf in the group
+    fn apply_group_removal(env: &Env, dao_id: u64, group_id: u32, member: Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GroupMember(dao_id, group_id, member.clone()));
+
+        let member_key = DataKey::MemberLeafIndex(dao_id, group_id, member.clone());
+        if !env.storage().persistent().has(&member_key) {
+            // Added but never registered: nothing to zero
+            return;
+        }
+        let (leaf_index, new_root, root_index) =
+            Self::zero_member_leaf(env, dao_id, group_id, &member);
+
+        // Forget the slot so the member can register again if re-added
+        env.storage().persistent().remove(&member_key);
+
+        RemovalEvent {
+            dao_id,
+            member,
+            group_id,
+            index: leaf_index,
+            new_root,
+            root_index,
+        }
+        .publish(env);
+    }
+
+    // Internal: Zero a departed member's leaves; their SBT is already revoked
+    fn apply_departure(env: &Env, dao_id: u64, member: Address) {
+        // Nothing is left for a scheduled removal to do
+        if Self::unschedule_removal(env, dao_id, GENERAL_GROUP, &member).is_some() {
+            RemovalCancelledEvent {
+                dao_id,
+                member: member.clone(),
+                group_id: GENERAL_GROUP,
+            }
+            .publish(env);
+        }
+
+        Self::remove_from_all_groups(env, dao_id, &member);
+
+        if Self::leaf_error(env, dao_id, GENERAL_GROUP, &member).is_some() {
+            return;
+        }
+
+        let (leaf_index, new_root, root_index) =
+            Self::zero_member_leaf(env, dao_id, GENERAL_GROUP, &member);
+        Self::revoke_removed(env, dao_id, GENERAL_GROUP, &member);
+        RemovalEvent {
+            dao_id,
+            member,
+            group_id: GENERAL_GROUP,
+            index: leaf_index,
+            new_root,
+            root_index,
+        }
+        .publish(env);
+    }
+
+    // Internal: Queue a membership change while the DAO's membership is frozen
+    // Returns true if the change was queued rather than left to the caller. Once voting
+    // has ended, up to PENDING_BATCH queued changes are applied first; if some remain,
+    // the new change waits behind them so changes always land in order.
+    fn defer(env: &Env, dao_id: u64, change: PendingChange) -> bool {
+        let pending = if Self::membership_frozen(env.clone(), dao_id) {
+            Self::pending_changes(env.clone(), dao_id)
+        } else {
+            let pending = Self::drain_pending(env, dao_id, PENDING_BATCH);
+            if pending.is_empty() {
+                return false;
+            }
+            pending
+        };
+        Self::enqueue(env, dao_id, pending, change);
+        true
+    }
+
+    // Internal: Append a change to the queue `pending` read from storage
+    fn enqueue(env: &Env, dao_id: u64, mut pending: Vec<PendingChange>, change: PendingChange) {
+        if pending.len() >= MAX_PENDING_CHANGES {
+            panic_with_error!(env, TreeError::PendingQueueFull);
+        }
+
+        pending.push_back(change.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingChanges(dao_id), &pending);
+        ChangeQueuedEvent {
+            dao_id,
+            change,
+            position: pending.len() - 1,
+        }
+        .publish(env);
+    }
+
+    // Internal: Apply up to `max_changes` queued changes, oldest first
+    // Returns the changes still queued.
+    fn drain_pending(env: &Env, dao_id: u64, max_changes: u32) -> Vec<PendingChange> {
+        let key = DataKey::PendingChanges(dao_id);
+        let Some(mut pending) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<PendingChange>>(&key)
+        else {
+            return Vec::new(env);
+        };
+
+        let mut applied = 0;
+        while applied < max_changes {
+            let Some(change) = pending.pop_front() else {
+                break;
+            };
+            applied += 1;
+            match Self::change_error(env, dao_id, &change) {
+                Some(error) => ChangeDroppedEvent {
+                    dao_id,
+                    change,
+                    error: error as u32,
+                }
+                .publish(env),
+                None => match change {
+                    PendingChange::Register(group_id, commitment, member) => {
+                        Self::add_commitment(env, dao_id, group_id, commitment, member)
+                    }
+                    PendingChange::Remove(member) => Self::apply_removal(env, dao_id, member),
+                    PendingChange::Reinstate(member, commitment) => {
+                        Self::apply_reinstatement(env, dao_id, member, commitment)
+                    }
+                    PendingChange::Depart(member) => Self::apply_departure(env, dao_id, member),
+                    PendingChange::RemoveFromGroup(group_id, member) => {
+                        Self::apply_group_removal(env, dao_id, group_id, member)
+                    }
+                },
+            }
+        }
+
+        if pending.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &pending);
+        }
+        pending
+    }
+
+    // Internal: Member a queued change applies to
+    fn change_member(change: &PendingChange) -> &Address {
+        match change {
+            PendingChange::Register(_, _, member)
+            | PendingChange::Remove(member)
+            | PendingChange::Reinstate(member, _)
+            | PendingChange::Depart(member)
+            | PendingChange::RemoveFromGroup(_, member) => member,
+        }
+    }
+
+    // Internal: Error a queued change would fail with if applied now, if any
+    // Authorization was checked when the change was queued; membership is re-checked.
+    fn change_error(env: &Env, dao_id: u64, change: &PendingChange) -> Option<TreeError> {
+        match change {
+            PendingChange::Register(group_id, commitment, member) => {
+                let has_sbt: bool = env.invoke_contract(
+                    &Self::sbt_contract(env),
+                    &symbol_short!("has"),
+                    soroban_sdk::vec![env, dao_id.into_val(env), member.clone().into_val(env)],
+                );
+                if !has_sbt {
+                    Some(TreeError::NoSbt)
+                } else if !Self::is_group_member(env.clone(), dao_id, *group_id, member.clone()) {
+                    Some(TreeError::NotGroupMember)
+                } else {
+                    Self::insert_error(env, dao_id, *group_id, commitment, Some(member))
+                }
+            }
+            PendingChange::Remove(member) => Self::leaf_error(env, dao_id, GENERAL_GROUP, member),
+            PendingChange::Reinstate(member, commitment) => {
+                Self::reinstate_error(env, dao_id, member, commitment)
+            }
+            // Members without a live leaf only leave their groups
+            PendingChange::Depart(_) | PendingChange::RemoveFromGroup(..) => None,
+        }
+    }
+
+    // Internal: Why a member has no live leaf in a group, if that is the case
+    fn leaf_error(env: &Env, dao_id: u64, group_id: u32, member: &Address) -> Option<TreeError> {
+        let Some(index) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::MemberLeafIndex(dao_id, group_id, member.clone()))
+        else {
+            return Some(TreeError::MemberNotInTree);
+        };
+        let value: U256 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LeafValue(dao_id, group_id, index))
+            .unwrap_or_else(|| Self::zero_value(env));
+        // A zeroed slot, or one refilled by another member, means the member was removed
+        if value == Self::zero_value(env)
+            || !Self::leaf_owned_by(env, dao_id, group_id, index, member)
+        {
+            return Some(TreeError::MemberRemoved);
+        }
+        None
+    }
+
+    // Internal: Why a member cannot be reinstated now, if that is the case
+    fn reinstate_error(
+        env: &Env,
+        dao_id: u64,
+        member: &Address,
+        commitment: &Option<U256>,
+    ) -> Option<TreeError> {
+        // The member must have been registered before, and removed since
+        match Self::leaf_error(env, dao_id, GENERAL_GROUP, member) {
+            None => return Some(TreeError::MemberNotRevoked),
+            Some(TreeError::MemberNotInTree) => return Some(TreeError::MemberNotInTree),
+            Some(_) => {}
+        }
+        match commitment {
+            // The member's old slot is freed first, so only the commitment is checked
+            Some(commitment) => Self::insert_error(env, dao_id, GENERAL_GROUP, commitment, None),
+            // The removed commitment is released and leaves the revocation tree on reuse
+            None if !env.storage().persistent().has(&DataKey::RemovedCommitment(
+                dao_id,
+                GENERAL_GROUP,
+                member.clone(),
+            )) =>
+            {
+                Some(TreeError::NoPriorCommitment)
+            }
+            None => Self::tree_full(env, dao_id, GENERAL_GROUP).then_some(TreeError::TreeFull),
+        }
+    }
+
+    // Internal: Why a commitment cannot be inserted into a group now, if that is the case
+    // `member` is checked for an existing leaf unless None.
+    fn insert_error(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        commitment: &U256,
+        member: Option<&Address>,
+    ) -> Option<TreeError> {
+        let storage = env.storage().persistent();
+        // Check tree is initialized
+        if !storage.has(&DataKey::TreeDepth(dao_id, group_id)) {
+            return Some(TreeError::TreeNotInitialized);
+        }
+
+        // Check commitment not already registered
+        if storage.has(&DataKey::LeafIndex(dao_id, group_id, commitment.clone())) {
+            return Some(TreeError::CommitmentExists);
+        }
+
+        // Check member hasn't already registered
+        if member.is_some_and(|member| {
+            storage.has(&DataKey::MemberLeafIndex(dao_id, group_id, member.clone()))
+        }) {
+            return Some(TreeError::MemberExists);
+        }
+
+        // Two commitments on the same revocation tree path could not both be revoked.
+        // Paths are claimed in every DAO, since strict revocation can be turned on later.
+        if storage
+            .get::<DataKey, U256>(&DataKey::RevocationPath(dao_id, low_bits(commitment)))
+            .is_some_and(|claimed| claimed != *commitment)
+        {
+            return Some(TreeError::RevocationPathTaken);
+        }
+
+        // A revoked commitment fails the strict non-revocation proof on every vote
+        if Self::strict_revocation(env.clone(), dao_id) && Self::is_revoked(env, dao_id, commitment)
+        {
+            return Some(TreeError::CommitmentRevoked);
+        }
+
+        Self::tree_full(env, dao_id, group_id).then_some(TreeError::TreeFull)
+    }
+
+    // Internal: Check whether a group's tree has no slot left for another leaf
+    fn tree_full(env: &Env, dao_id: u64, group_id: u32) -> bool {
+        let slot_reuse: bool = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SlotReuse(dao_id, group_id))
+            .unwrap_or(false);
+        if slot_reuse
+            && env
+                .storage()
+                .persistent()
+                .has(&DataKey::FreeSlotHead(dao_id, group_id))
+        {
+            return false;
+        }
+        let depth: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TreeDepth(dao_id, group_id))
+            .unwrap_or(0);
+        let next_index: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextLeafIndex(dao_id, group_id))
+            .unwrap_or(0);
+        next_index >= Self::capacity(depth)
+    }
+
+    // Internal: Zero a member's leaf in a group and invalidate older roots for Trailing mode
+    // Returns (leaf_index, new_root, root_index). The slot goes on the free-slot stack.
+    fn zero_member_leaf(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        member: &Address,
+    ) -> (u64, U256, u32) {
+        // Get member's leaf index
+        let leaf_index: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MemberLeafIndex(dao_id, group_id, member.clone()))
+            .unwrap_or_else(|| panic_with_error!(env, TreeError::MemberNotInTree));
+
+        // Get their commitment from the tree
+        let commitment: U256 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LeafValue(dao_id, group_id, leaf_index))
+            .unwrap_or_else(|| panic_with_error!(env, TreeError::MemberNotInTree));
+
+        // A zeroed slot, or one that has since been refilled by another member, means
+        // this member was already removed
+        if commitment == Self::zero_value(env)
+            || !Self::leaf_owned_by(env, dao_id, group_id, leaf_index, member)
+        {
+            panic_with_error!(env, TreeError::MemberRemoved);
+        }
+
+        // Remember the commitment behind the leaf so reinstatement can re-enable it
+        let removed: U256 = env
+            .storage()
+            .persistent()
+            .get::<DataKey, (U256, u64)>(&DataKey::LeafPreimage(dao_id, group_id, leaf_index))
+            .map_or_else(|| commitment.clone(), |(removed, _)| removed);
+        env.storage().persistent().set(
+            &DataKey::RemovedCommitment(dao_id, group_id, member.clone()),
+            &removed,
+        );
+
+        // Zero the leaf and recompute root
+        let zero = Self::zero_value(env);
+        let mut frontier = Frontier::load(env, dao_id, group_id);
+        let (new_root, root_index) =
+            Self::update_leaf(env, dao_id, group_id, &mut frontier, leaf_index, zero);
+        frontier.save(env, dao_id, group_id);
+
+        // Track the zeroed slot so it can be refilled when slot reuse is enabled
+        Self::push_free_slot(env, dao_id, group_id, leaf_index);
+
+        // Update min_valid_root_index - all roots before this are now invalid for Trailing mode
+        // This prevents removed members from using old proofs
+        env.storage()
+            .persistent()
+            .set(&DataKey::MinValidRootIdx(dao_id, group_id), &root_index);
+
+        (leaf_index, new_root, root_index)
+    }
+
+    // Internal: Drop a departing member's eligibility and leaf in every group
+    fn remove_from_all_groups(env: &Env, dao_id: u64, member: &Address) {
+        for group_id in Self::get_groups(env.clone(), dao_id).iter() {
+            env.storage().persistent().remove(&DataKey::GroupMember(
+                dao_id,
+                group_id,
+                member.clone(),
+            ));
+            // Group slots are forgotten on removal, so a stored index is an active leaf
+            let member_key = DataKey::MemberLeafIndex(dao_id, group_id, member.clone());
+            if !env.storage().persistent().has(&member_key) {
+                continue;
+            }
+            // Only members with a group leaf can have a group removal scheduled
+            if Self::unschedule_removal(env, dao_id, group_id, member).is_some() {
+                RemovalCancelledEvent {
+                    dao_id,
+                    member: member.clone(),
+                    group_id,
+                }
+                .publish(env);
+            }
+            let (index, new_root, root_index) =
+                Self::zero_member_leaf(env, dao_id, group_id, member);
+            Self::revoke_removed(env, dao_id, group_id, member);
+            env.storage().persistent().remove(&member_key);
+            RemovalEvent {
+                dao_id,
+                member: member.clone(),
+                group_id,
+                index,
+                new_root,
+                root_index,
+            }
+            .publish(env);
+        }
+    }
+
+    // Internal: Create an empty tree for a group
+    fn init_group_tree(env: &Env, dao_id: u64, group_id: u32, depth: u32) {
+        if depth == 0 || depth > MAX_TREE_DEPTH {
+            panic_with_error!(env, TreeError::InvalidDepth);
+        }
+
+        let depth_key = DataKey::TreeDepth(dao_id, group_id);
+        if env.storage().persistent().has(&depth_key) {
+            panic_with_error!(env, TreeError::TreeInitialized);
+        }
+
+        // Store tree parameters
+        env.storage().persistent().set(&depth_key, &depth);
+        env.storage()
+            .persistent()
+            .set(&DataKey::NextLeafIndex(dao_id, group_id), &0u64);
+
+        // Initialize root index counter
+        env.storage()
+            .persistent()
+            .set(&DataKey::NextRootIndex(dao_id, group_id), &0u32);
+
+        // Initialize filled subtrees with zeros (use cached zeros for O(1) lookup)
+        let mut filled = Vec::new(env);
+        for level in 0..depth {
+            filled.push_back(Self::zero_at_level(env, level));
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::FilledSubtrees(dao_id, group_id), &filled);
+
+        // Initialize root history with empty tree root (cached zero at depth level)
+        let empty_root = Self::zero_at_level(env, depth);
+        let mut roots = Vec::new(env);
+        roots.push_back(empty_root.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Roots(dao_id, group_id), &roots);
+
+        // Store root index for empty root
+        env.storage().persistent().set(
+            &DataKey::RootIndex(dao_id, group_id, empty_root.clone()),
+            &0u32,
+        );
+
+        TreeInitEvent {
+            dao_id,
+            group_id,
+            depth,
+            empty_root,
+            root_index: 0,
+        }
+        .publish(env);
+    }
+
+    // Internal: Require the member to hold the DAO's SBT
+    fn assert_has_sbt(env: &Env, dao_id: u64, member: &Address) {
+        let sbt_contract: Address = Self::sbt_contract(env);
+        let has_sbt: bool = env.invoke_contract(
+            &sbt_contract,
+            &symbol_short!("has"),
+            soroban_sdk::vec![env, dao_id.into_val(env), member.clone().into_val(env)],
+        );
+
+        if !has_sbt {
+            panic_with_error!(env, TreeError::NoSbt);
+        }
+    }
 
-        // Get their commitment from the tree
-        let commitment: U256 = env
+    // Internal: Require an existing non-general group
+    fn assert_group(env: &Env, dao_id: u64, group_id: u32) {
+        if group_id == GENERAL_GROUP {
+            panic_with_error!(env, TreeError::InvalidGroup);
+        }
+        if !env
             .storage()
             .persistent()
-            .get(&DataKey::LeafValue(dao_id, leaf_index))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::MemberNotInTree));
-
-        if commitment == Self::zero_value(&env) {
-            panic_with_error!(&env, TreeError::MemberRemoved);
+            .has(&DataKey::TreeDepth(dao_id, group_id))
+        {
+            panic_with_error!(env, TreeError::TreeNotInitialized);
         }
+    }
 
-        // Zero the leaf and recompute root
-        let zero = Self::zero_value(&env);
-        let (new_root, root_index) = Self::update_leaf(&env, dao_id, leaf_index, zero);
-
-        // Update min_valid_root_index - all roots before this are now invalid for Trailing mode
-        // This prevents removed members from using old proofs
-        env.storage()
-            .persistent()
-            .set(&DataKey::MinValidRootIdx(dao_id), &root_index);
+    // Internal: Register a commitment for a member and emit its CommitEvent
+    // Callers are responsible for authorization and SBT checks.
+    fn add_commitment(env: &Env, dao_id: u64, group_id: u32, commitment: U256, member: Address) {
+        let mut frontier = Frontier::load(env, dao_id, group_id);
+        Self::commit(env, dao_id, group_id, &mut frontier, commitment, member);
+        frontier.save(env, dao_id, group_id);
+    }
 
-        // Also revoke the member's SBT in the same transaction
-        // The admin has already called require_auth(), so the SBT contract will accept this
-        env.invoke_contract::<()>(
-            &sbt_contract,
-            &symbol_short!("revoke"),
-            soroban_sdk::vec![
-                &env,
-                dao_id.into_val(&env),
-                member.clone().into_val(&env),
-                admin.into_val(&env),
-            ],
-        );
+    // Internal: Insert a batch of `(member, commitment)` and emit their events
+    // The frontier and root history are loaded and stored once for the whole batch.
+    fn add_commitments(env: &Env, dao_id: u64, group_id: u32, entries: Vec<(Address, U256)>) {
+        let mut frontier = Frontier::load(env, dao_id, group_id);
+        for (member, commitment) in entries.iter() {
+            Self::commit(env, dao_id, group_id, &mut frontier, commitment, member);
+        }
+        frontier.save(env, dao_id, group_id);
+    }
 
-        RemovalEvent {
+    // Internal: Insert a member's commitment into a loaded frontier and emit its event
+    fn commit(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        frontier: &mut Frontier,
+        commitment: U256,
+        member: Address,
+    ) {
+        let (index, new_root, root_index, expires_at) =
+            Self::insert_commitment(env, dao_id, group_id, frontier, commitment.clone(), &member);
+        CommitEvent {
             dao_id,
-            member,
-            index: leaf_index,
+            group_id,
+            commitment,
+            index,
             new_root,
             root_index,
+            expires_at,
         }
-        .publish(&env);
+        .publish(env);
     }
 
-    /// Reinstate a previously removed member
-    /// Clears their leaf index mapping so they can re-register with a new commitment
-    /// The admin should also re-mint their SBT via the membership-sbt contract
-    pub fn reinstate_member(env: Env, dao_id: u64, member: Address, admin: Address) {
-        admin.require_auth();
-
-        // Verify admin is the DAO admin via cross-contract call
-        let sbt_contract: Address = Self::sbt_contr(env.clone());
-        let registry: Address = env.invoke_contract(
-            &sbt_contract,
-            &symbol_short!("registry"),
-            soroban_sdk::vec![&env],
-        );
-        let dao_admin: Address = env.invoke_contract(
-            &registry,
-            &symbol_short!("get_admin"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
-        );
-
-        if admin != dao_admin {
-            panic_with_error!(&env, TreeError::NotAdmin);
+    // Internal: Insert a member's commitment without emitting an event
+    // Refills a zeroed slot when slot reuse is enabled, otherwise appends at next_index.
+    // Returns (index, new_root, root_index, expires_at).
+    fn insert_commitment(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        frontier: &mut Frontier,
+        commitment: U256,
+        member: &Address,
+    ) -> (u64, U256, u32, Option<u64>) {
+        if let Some(error) = Self::insert_error(env, dao_id, group_id, &commitment, Some(member)) {
+            panic_with_error!(env, error);
         }
-
-        // Get member's leaf index (they must have been registered before)
-        let leaf_index_key = DataKey::MemberLeafIndex(dao_id, member.clone());
-        let leaf_index: u32 = env
+        let depth: u32 = env
             .storage()
             .persistent()
-            .get(&leaf_index_key)
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::MemberNotInTree));
+            .get(&DataKey::TreeDepth(dao_id, group_id))
+            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
+        let leaf_key = DataKey::LeafIndex(dao_id, group_id, commitment.clone());
+        let member_key = DataKey::MemberLeafIndex(dao_id, group_id, member.clone());
 
-        // Verify the member was actually removed (leaf should be zero)
-        let current_value: U256 = env
+        let slot_reuse: bool = env
             .storage()
             .persistent()
-            .get(&DataKey::LeafValue(dao_id, leaf_index))
-            .unwrap_or_else(|| panic_with_error!(&env, TreeError::MemberNotInTree));
+            .get(&DataKey::SlotReuse(dao_id, group_id))
+            .unwrap_or(false);
+        let free_slot = if slot_reuse {
+            Self::pop_free_slot(env, dao_id, group_id)
+        } else {
+            None
+        };
+
+        // Expiring trees bake the member's SBT expiry into the leaf
+        let expires_at = if Self::expiring(env.clone(), dao_id) {
+            Some(Self::sbt_expiry(env, dao_id, member))
+        } else {
+            None
+        };
+        let leaf = match expires_at {
+            Some(expiry) => Self::expiring_leaf(env, &commitment, expiry),
+            None => commitment.clone(),
+        };
+
+        let (index, new_root, root_index) = match free_slot {
+            Some(index) => {
+                // Refill the zeroed slot in place (next_index is unchanged)
+                let (new_root, root_index) =
+                    Self::update_leaf(env, dao_id, group_id, frontier, index, leaf.clone());
+                (index, new_root, root_index)
+            }
+            None => {
+                let next_index: u64 = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::NextLeafIndex(dao_id, group_id))
+                    .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
+
+                // Insert leaf into tree
+                let (new_root, root_index) = Self::insert_leaf(
+                    env,
+                    dao_id,
+                    group_id,
+                    frontier,
+                    leaf.clone(),
+                    next_index,
+                    depth,
+                );
 
-        if current_value != Self::zero_value(&env) {
-            panic_with_error!(&env, TreeError::MemberNotRevoked);
-        }
+                // Update next index
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::NextLeafIndex(dao_id, group_id), &(next_index + 1));
 
-        // Clear the leaf index mapping so they can re-register
-        env.storage().persistent().remove(&leaf_index_key);
+                // Store leaf value
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::LeafValue(dao_id, group_id, next_index), &leaf);
 
-        // Record reinstatement timestamp
-        let reinstated_at = env.ledger().timestamp();
+                (next_index, new_root, root_index)
+            }
+        };
+
+        // Store leaf index for this commitment, and claim its revocation tree path for
+        // good (the same commitment may sit in several groups)
+        env.storage().persistent().set(&leaf_key, &index);
+        let path_key = DataKey::RevocationPath(dao_id, low_bits(&commitment));
+        if !env.storage().persistent().has(&path_key) {
+            env.storage().persistent().set(&path_key, &commitment);
+        }
 
-        // Emit event
-        ReinstatementEvent {
-            dao_id,
-            member,
-            reinstated_at,
+        // Store member -> index mapping; only a refilled slot needs its owner recorded,
+        // a freshly appended one can only be the member's (see leaf_owned_by)
+        env.storage().persistent().set(&member_key, &index);
+        if free_slot.is_some() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::LeafOwner(dao_id, group_id, index), member);
         }
-        .publish(&env);
+
+        if let Some(expiry) = expires_at {
+            env.storage().persistent().set(
+                &DataKey::LeafPreimage(dao_id, group_id, index),
+                &(commitment, expiry),
+            );
+        }
+
+        (index, new_root, root_index, expires_at)
     }
 
-    /// Get revocation timestamp for a commitment (returns None if never revoked)
-    /// Used by voting contract to check if member was revoked
-    pub fn revok_at(env: Env, dao_id: u64, commitment: U256) -> Option<u64> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::RevokedAt(dao_id, commitment))
+    // Internal: Leaf of an expiring tree, Poseidon(commitment, expires_at)
+    fn expiring_leaf(env: &Env, commitment: &U256, expires_at: u64) -> U256 {
+        let expiry = U256::from_u128(env, expires_at as u128);
+        Poseidon::pair(env).hash_pair(env, commitment, &expiry)
     }
 
-    /// Get reinstatement timestamp for a commitment (returns None if never reinstated)
-    /// Used by voting contract to check if member was reinstated after revocation
-    pub fn reinst_at(env: Env, dao_id: u64, commitment: U256) -> Option<u64> {
+    // Internal: A member's SBT expiry, or NO_EXPIRY for permanent memberships
+    fn sbt_expiry(env: &Env, dao_id: u64, member: &Address) -> u64 {
+        let expires_at: Option<u64> = env.invoke_contract(
+            &Self::sbt_contract(env),
+            &Symbol::new(env, "expires_at"),
+            soroban_sdk::vec![env, dao_id.into_val(env), member.clone().into_val(env)],
+        );
+        expires_at.unwrap_or(NO_EXPIRY)
+    }
+
+    // Internal: Check whether a leaf slot still belongs to a member
+    // Slots that were never refilled have no owner and are assumed owned: only the
+    // member who appended the leaf can point at them.
+    fn leaf_owned_by(env: &Env, dao_id: u64, group_id: u32, index: u64, member: &Address) -> bool {
         env.storage()
             .persistent()
-            .get(&DataKey::ReinstatedAt(dao_id, commitment))
+            .get::<DataKey, Address>(&DataKey::LeafOwner(dao_id, group_id, index))
+            .is_none_or(|owner| &owner == member)
     }
 
-    /// Get the minimum valid root index for a DAO
-    /// Roots with index < min_valid_root_index are invalid for Trailing mode proposals
-    /// Returns 0 if no members have been removed
-    pub fn min_root(env: Env, dao_id: u64) -> u32 {
+    // Internal: Push a zeroed slot onto the DAO's free-slot stack
+    fn push_free_slot(env: &Env, dao_id: u64, group_id: u32, index: u64) {
+        let head_key = DataKey::FreeSlotHead(dao_id, group_id);
+        if let Some(head) = env.storage().persistent().get::<DataKey, u64>(&head_key) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::FreeSlotNext(dao_id, group_id, index), &head);
+        }
+        env.storage().persistent().set(&head_key, &index);
+
+        let count_key = DataKey::FreeSlotCount(dao_id, group_id);
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(&count_key, &(count + 1));
+    }
+
+    // Internal: Pop the most recently zeroed slot, if any
+    fn pop_free_slot(env: &Env, dao_id: u64, group_id: u32) -> Option<u64> {
+        let head_key = DataKey::FreeSlotHead(dao_id, group_id);
+        let index: u64 = env.storage().persistent().get(&head_key)?;
+
+        let next_key = DataKey::FreeSlotNext(dao_id, group_id, index);
+        match env.storage().persistent().get::<DataKey, u64>(&next_key) {
+            Some(next) => {
+                env.storage().persistent().set(&head_key, &next);
+                env.storage().persistent().remove(&next_key);
+            }
+            None => env.storage().persistent().remove(&head_key),
+        }
+
+        let count_key = DataKey::FreeSlotCount(dao_id, group_id);
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
         env.storage()
             .persistent()
-            .get(&DataKey::MinValidRootIdx(dao_id))
-            .unwrap_or(0)
+            .set(&count_key, &count.saturating_sub(1));
+
+        Some(index)
     }
 
     // Internal: Insert leaf and update tree
     // Also stores intermediate node hashes at each level for O(depth) merkle path lookups
-    fn insert_leaf(env: &Env, dao_id: u64, leaf: U256, index: u32, depth: u32) -> (U256, u32) {
-        let mut filled: Vec<U256> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::FilledSubtrees(dao_id))
-            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
+    fn insert_leaf(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        frontier: &mut Frontier,
+        leaf: U256,
+        index: u64,
+        depth: u32,
+    ) -> (U256, u32) {
+        let leaf_value = leaf.clone();
+        let poseidon = Poseidon::pair(env);
 
         // Fast path for first leaf (index 0): pre-compute root directly
         // Since all siblings are zeros, we can compute the root in a tight loop
-        // without repeatedly calling zero_at_level
+        // without repeatedly calling zero_at_level. Every ancestor is on the left
+        // spine, so no node hash needs storing yet (see node_needed).
         if index == 0 {
-            filled.set(0, leaf.clone());
+            frontier.set_filled(0, leaf.clone());
             let mut current_hash = leaf;
-            let mut current_index = index;
             for level in 0..depth {
                 let zero = Self::zero_at_level(env, level);
-                current_hash = Self::hash_pair(env, &current_hash, &zero);
-                // Store intermediate node hash at level+1 (since level 0 is leaves)
-                let parent_index = current_index / 2;
-                env.storage().persistent().set(
-                    &DataKey::NodeHash(dao_id, level + 1, parent_index),
-                    &current_hash,
-                );
-                current_index = parent_index;
+                current_hash = poseidon.hash_pair(env, &current_hash, &zero);
             }
 
-            env.storage()
-                .persistent()
-                .set(&DataKey::FilledSubtrees(dao_id), &filled);
-
             // Update root history
-            let mut roots: Vec<U256> = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Roots(dao_id))
-                .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
-            roots.push_back(current_hash.clone());
-            if roots.len() > MAX_ROOT_HISTORY {
-                let mut new_roots = Vec::new(env);
-                for i in 1..roots.len() {
-                    if let Some(r) = roots.get(i) {
-                        new_roots.push_back(r);
-                    }
-                }
-                roots = new_roots;
-            }
-            env.storage()
-                .persistent()
-                .set(&DataKey::Roots(dao_id), &roots);
-
-            // Get and increment root index
-            let root_index: u32 = env
-                .storage()
-                .persistent()
-                .get(&DataKey::NextRootIndex(dao_id))
-                .unwrap_or(0);
-            env.storage()
-                .persistent()
-                .set(&DataKey::NextRootIndex(dao_id), &(root_index + 1));
-
-            // Store root index mapping
-            env.storage().persistent().set(
-                &DataKey::RootIndex(dao_id, current_hash.clone()),
-                &root_index,
+            let root_index = frontier.push_root(env, dao_id, group_id, &current_hash);
+            Self::record_transition(
+                env,
+                dao_id,
+                group_id,
+                root_index,
+                &RootTransition {
+                    leaf_index: index,
+                    old_value: Self::zero_value(env),
+                    new_value: leaf_value,
+                    old_depth: depth,
+                    new_depth: depth,
+                    new_root: current_hash.clone(),
+                },
             );
 
             return (current_hash, root_index);
@@ -912,68 +2696,49 @@ impl MembershipTree {
             let level = i as u32;
             if current_index % 2 == 0 {
                 // Left child - update filled subtree at this level
-                filled.set(level, current_hash.clone());
+                frontier.set_filled(level, current_hash.clone());
                 let zero_at_level = Self::zero_at_level(env, level);
-                current_hash = Self::hash_pair(env, &current_hash, &zero_at_level);
+                current_hash = poseidon.hash_pair(env, &current_hash, &zero_at_level);
             } else {
                 // Right child - use filled subtree from left
-                let left = filled
+                let left = frontier
+                    .filled
                     .get(level)
                     .unwrap_or_else(|| Self::zero_at_level(env, level));
-                current_hash = Self::hash_pair(env, &left, &current_hash);
+                // First leaf past 2^level: the left spine node becomes a sibling
+                if level > 0 && index == 1u64 << level {
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::NodeHash(dao_id, group_id, level, 0), &left);
+                }
+                current_hash = poseidon.hash_pair(env, &left, &current_hash);
             }
             // Store intermediate node hash at level+1 (since level 0 is leaves)
             let parent_index = current_index / 2;
-            env.storage().persistent().set(
-                &DataKey::NodeHash(dao_id, level + 1, parent_index),
-                &current_hash,
-            );
-            current_index = parent_index;
-        }
-
-        // Save updated filled subtrees
-        env.storage()
-            .persistent()
-            .set(&DataKey::FilledSubtrees(dao_id), &filled);
-
-        // Update root history with FIFO cap
-        let mut roots: Vec<U256> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Roots(dao_id))
-            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
-
-        roots.push_back(current_hash.clone());
-
-        // Maintain max roots cap (FIFO)
-        if roots.len() > MAX_ROOT_HISTORY {
-            let mut new_roots = Vec::new(env);
-            for i in 1..roots.len() {
-                if let Some(r) = roots.get(i) {
-                    new_roots.push_back(r);
-                }
+            if Self::node_needed(level + 1, parent_index, index + 1) {
+                env.storage().persistent().set(
+                    &DataKey::NodeHash(dao_id, group_id, level + 1, parent_index),
+                    &current_hash,
+                );
             }
-            roots = new_roots;
+            current_index = parent_index;
         }
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Roots(dao_id), &roots);
-
-        // Get and increment root index
-        let root_index: u32 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextRootIndex(dao_id))
-            .unwrap_or(0);
-        env.storage()
-            .persistent()
-            .set(&DataKey::NextRootIndex(dao_id), &(root_index + 1));
-
-        // Store root index mapping
-        env.storage().persistent().set(
-            &DataKey::RootIndex(dao_id, current_hash.clone()),
-            &root_index,
+        // Update root history
+        let root_index = frontier.push_root(env, dao_id, group_id, &current_hash);
+        Self::record_transition(
+            env,
+            dao_id,
+            group_id,
+            root_index,
+            &RootTransition {
+                leaf_index: index,
+                old_value: Self::zero_value(env),
+                new_value: leaf_value,
+                old_depth: depth,
+                new_depth: depth,
+                new_root: current_hash.clone(),
+            },
         );
 
         (current_hash, root_index)
@@ -982,21 +2747,42 @@ impl MembershipTree {
     /// Internal: Update an existing leaf value and recompute the path to root
     /// Used for revocation (zeroing) and reinstatement (restoring commitment)
     /// Returns (new_root, root_index)
-    fn update_leaf(env: &Env, dao_id: u64, leaf_index: u32, new_value: U256) -> (U256, u32) {
+    fn update_leaf(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        frontier: &mut Frontier,
+        leaf_index: u64,
+        new_value: U256,
+    ) -> (U256, u32) {
         let depth: u32 = env
             .storage()
             .persistent()
-            .get(&DataKey::TreeDepth(dao_id))
+            .get(&DataKey::TreeDepth(dao_id, group_id))
             .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
 
-        // Update the leaf value
-        env.storage()
+        let next_index: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextLeafIndex(dao_id, group_id))
+            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
+
+        let old_value: U256 = env
+            .storage()
             .persistent()
-            .set(&DataKey::LeafValue(dao_id, leaf_index), &new_value);
+            .get(&DataKey::LeafValue(dao_id, group_id, leaf_index))
+            .unwrap_or_else(|| Self::zero_value(env));
+
+        // Update the leaf value
+        env.storage().persistent().set(
+            &DataKey::LeafValue(dao_id, group_id, leaf_index),
+            &new_value,
+        );
 
         // Recompute path from leaf to root
+        let poseidon = Poseidon::pair(env);
         let mut current_index = leaf_index;
-        let mut current_hash = new_value;
+        let mut current_hash = new_value.clone();
 
         for level in 0..depth {
             let is_left = current_index % 2 == 0;
@@ -1006,18 +2792,28 @@ impl MembershipTree {
                 current_index - 1
             };
 
+            // Keep the append frontier in sync: the next insert_leaf hashes against
+            // filled[level] when its right sibling at this level is still empty
+            if is_left && current_index + 1 == (next_index >> level) {
+                frontier.set_filled(level, current_hash.clone());
+            }
+
             // Get sibling hash from stored NodeHash or use zero if doesn't exist
-            let sibling: U256 = if level == 0 {
+            // Subtrees past the append frontier hold no leaves, so their entries are
+            // never written; skipping them keeps them out of the footprint.
+            let sibling: U256 = if sibling_index << level >= next_index {
+                Self::zero_at_level(env, level)
+            } else if level == 0 {
                 // At leaf level, sibling is another leaf value
                 env.storage()
                     .persistent()
-                    .get(&DataKey::LeafValue(dao_id, sibling_index))
+                    .get(&DataKey::LeafValue(dao_id, group_id, sibling_index))
                     .unwrap_or_else(|| Self::zero_at_level(env, level))
             } else {
                 // At higher levels, sibling is stored in NodeHash
                 env.storage()
                     .persistent()
-                    .get(&DataKey::NodeHash(dao_id, level, sibling_index))
+                    .get(&DataKey::NodeHash(dao_id, group_id, level, sibling_index))
                     .unwrap_or_else(|| Self::zero_at_level(env, level))
             };
 
@@ -1027,154 +2823,310 @@ impl MembershipTree {
             } else {
                 (sibling, current_hash.clone())
             };
-            current_hash = Self::hash_pair(env, &left, &right);
+            current_hash = poseidon.hash_pair(env, &left, &right);
 
             // Store updated node hash at level+1
             let parent_index = current_index / 2;
-            env.storage().persistent().set(
-                &DataKey::NodeHash(dao_id, level + 1, parent_index),
-                &current_hash,
-            );
+            if Self::node_needed(level + 1, parent_index, next_index) {
+                env.storage().persistent().set(
+                    &DataKey::NodeHash(dao_id, group_id, level + 1, parent_index),
+                    &current_hash,
+                );
+            }
 
             current_index = parent_index;
         }
 
-        // Update root history with FIFO cap
-        let mut roots: Vec<U256> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Roots(dao_id))
-            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
+        // Update root history
+        let root_index = frontier.push_root(env, dao_id, group_id, &current_hash);
+        Self::record_transition(
+            env,
+            dao_id,
+            group_id,
+            root_index,
+            &RootTransition {
+                leaf_index,
+                old_value,
+                new_value,
+                old_depth: depth,
+                new_depth: depth,
+                new_root: current_hash.clone(),
+            },
+        );
 
-        roots.push_back(current_hash.clone());
+        (current_hash, root_index)
+    }
 
-        // Maintain max roots cap (FIFO)
-        if roots.len() > MAX_ROOT_HISTORY {
-            let mut new_roots = Vec::new(env);
-            for i in 1..roots.len() {
-                if let Some(r) = roots.get(i) {
-                    new_roots.push_back(r);
-                }
+    // Internal: Root index of the first transition applied after `root`
+    // The initial empty root shares index 0 with the first transition, so compare roots.
+    fn first_transition_after(env: &Env, dao_id: u64, group_id: u32, root: &U256) -> u32 {
+        if !Self::group_root_ok(env.clone(), dao_id, group_id, root.clone()) {
+            panic_with_error!(env, TreeError::RootNotFound);
+        }
+        let root_index = Self::group_root_idx(env.clone(), dao_id, group_id, root.clone());
+        match Self::transition(env, dao_id, group_id, root_index) {
+            Some(t) if t.new_root != *root => root_index,
+            _ => root_index + 1,
+        }
+    }
+
+    // Internal: Rebuild the tree at a historical root as overrides on top of current storage
+    // Returns the overridden nodes and the tree depth at that root.
+    fn state_at(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        root: &U256,
+        next_index: u64,
+    ) -> (Map<(u32, u64), U256>, u32) {
+        let first_reverted = Self::first_transition_after(env, dao_id, group_id, root);
+        let current_index = Self::group_curr_idx(env.clone(), dao_id, group_id);
+        let current_depth = Self::group_depth(env.clone(), dao_id, group_id);
+
+        let poseidon = Poseidon::pair(env);
+        let mut overrides: Map<(u32, u64), U256> = Map::new(env);
+        let mut depth = current_depth;
+        for root_index in (first_reverted..=current_index).rev() {
+            let transition = Self::transition(env, dao_id, group_id, root_index)
+                .unwrap_or_else(|| panic_with_error!(env, TreeError::HistoryUnavailable));
+            if transition.old_value != transition.new_value {
+                Self::override_leaf(
+                    env,
+                    &poseidon,
+                    dao_id,
+                    group_id,
+                    &mut overrides,
+                    transition.leaf_index,
+                    transition.old_value,
+                    current_depth,
+                    next_index,
+                );
             }
-            roots = new_roots;
+            depth = transition.old_depth;
+        }
+
+        (overrides, depth)
+    }
+
+    // Internal: Set a leaf in the overrides and recompute its ancestors up to `depth`
+    #[allow(clippy::too_many_arguments)]
+    fn override_leaf(
+        env: &Env,
+        poseidon: &Poseidon,
+        dao_id: u64,
+        group_id: u32,
+        overrides: &mut Map<(u32, u64), U256>,
+        leaf_index: u64,
+        value: U256,
+        depth: u32,
+        next_index: u64,
+    ) {
+        let mut index = leaf_index;
+        let mut hash = value;
+        for level in 0..depth {
+            overrides.set((level, index), hash.clone());
+            let sibling = Self::node_at(
+                env,
+                dao_id,
+                group_id,
+                overrides,
+                level,
+                index ^ 1,
+                next_index,
+            );
+            hash = if index.is_multiple_of(2) {
+                poseidon.hash_pair(env, &hash, &sibling)
+            } else {
+                poseidon.hash_pair(env, &sibling, &hash)
+            };
+            index /= 2;
+        }
+    }
+
+    // Internal: Sibling path for a leaf, reading through the overrides
+    fn path_from(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        overrides: &Map<(u32, u64), U256>,
+        leaf_index: u64,
+        depth: u32,
+        next_index: u64,
+    ) -> (Vec<U256>, Vec<u32>) {
+        let mut path_elements = Vec::new(env);
+        let mut path_indices = Vec::new(env);
+        let mut current = leaf_index;
+        for level in 0..depth {
+            path_indices.push_back((current % 2) as u32);
+            path_elements.push_back(Self::node_at(
+                env,
+                dao_id,
+                group_id,
+                overrides,
+                level,
+                current ^ 1,
+                next_index,
+            ));
+            current /= 2;
         }
+        (path_elements, path_indices)
+    }
+
+    // Internal: Number of leaves a tree of this depth can address
+    fn capacity(depth: u32) -> u64 {
+        1u64 << depth
+    }
 
+    // Internal: Number of slots ever appended to a group's tree
+    fn next_leaf_index(env: &Env, dao_id: u64, group_id: u32) -> u64 {
         env.storage()
             .persistent()
-            .set(&DataKey::Roots(dao_id), &roots);
+            .get(&DataKey::NextLeafIndex(dao_id, group_id))
+            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized))
+    }
 
-        // Get and increment root index
-        let root_index: u32 = env
-            .storage()
+    // Internal: Whether NodeHash(level, index) must be kept current
+    // Left-spine nodes (index 0) are only read as siblings once a leaf exists past
+    // 2^level, so storage grows with the number of members rather than the depth.
+    // insert_leaf writes the spine node from the frontier when the first such leaf lands.
+    fn node_needed(level: u32, index: u64, next_index: u64) -> bool {
+        index > 0 || next_index > 1u64 << level
+    }
+
+    // Internal: Node value at (level, index), preferring in-memory overrides
+    fn node_at(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        overrides: &Map<(u32, u64), U256>,
+        level: u32,
+        index: u64,
+        next_index: u64,
+    ) -> U256 {
+        if let Some(value) = overrides.get((level, index)) {
+            return value;
+        }
+        if level == 0 {
+            if index >= next_index {
+                return Self::zero_value(env);
+            }
+            return env
+                .storage()
+                .persistent()
+                .get(&DataKey::LeafValue(dao_id, group_id, index))
+                .unwrap_or_else(|| Self::zero_value(env));
+        }
+        env.storage()
             .persistent()
-            .get(&DataKey::NextRootIndex(dao_id))
-            .unwrap_or(0);
+            .get(&DataKey::NodeHash(dao_id, group_id, level, index))
+            .unwrap_or_else(|| Self::zero_at_level(env, level))
+    }
+
+    // Internal: Transition that produced a group's root index, if still recorded
+    fn transition(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        root_index: u32,
+    ) -> Option<RootTransition> {
         env.storage()
             .persistent()
-            .set(&DataKey::NextRootIndex(dao_id), &(root_index + 1));
+            .get::<DataKey, (u32, RootTransition)>(&DataKey::Transition(
+                dao_id,
+                group_id,
+                root_index % MAX_ROOT_HISTORY,
+            ))
+            .and_then(|(recorded, transition)| (recorded == root_index).then_some(transition))
+    }
 
-        // Store root index mapping
+    // Internal: Record the transition that produced a root
+    // Transitions live in a ring of MAX_ROOT_HISTORY slots, so the one falling out of
+    // the history window is overwritten rather than removed by a second write.
+    fn record_transition(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        root_index: u32,
+        transition: &RootTransition,
+    ) {
         env.storage().persistent().set(
-            &DataKey::RootIndex(dao_id, current_hash.clone()),
-            &root_index,
+            &DataKey::Transition(dao_id, group_id, root_index % MAX_ROOT_HISTORY),
+            &(root_index, transition.clone()),
         );
-
-        (current_hash, root_index)
     }
 
-    // Internal: Ensure Poseidon params are cached in persistent storage
-    // This is the key optimization - we only load params once and reuse
-    fn ensure_poseidon_params_cached(env: &Env) {
-        if env.storage().persistent().has(&POSEIDON_MDS) {
+    // Internal: Add the commitment zeroed with a member's leaf to the revocation tree
+    fn revoke_removed(env: &Env, dao_id: u64, group_id: u32, member: &Address) {
+        if !Self::strict_revocation(env.clone(), dao_id) {
             return;
         }
-        // Load params from our local module (expensive - only do once)
-        let mds = poseidon_params::get_mds3(env);
-        let rc = poseidon_params::get_rc3(env);
-        env.storage().persistent().set(&POSEIDON_MDS, &mds);
-        env.storage().persistent().set(&POSEIDON_RC, &rc);
-    }
-
-    // Internal: Poseidon hash of two U256 values using cached params
-    // Uses poseidon_permutation directly with pre-cached MDS and round constants
-    fn hash_pair(env: &Env, left: &U256, right: &U256) -> U256 {
-        Self::ensure_poseidon_params_cached(env);
-
-        // Load cached params from persistent storage (safe: ensure_poseidon_params_cached guarantees these exist)
-        let mds: Vec<Vec<U256>> = env
-            .storage()
-            .persistent()
-            .get(&POSEIDON_MDS)
-            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
-        let rc: Vec<Vec<U256>> = env
+        let commitment: U256 = env
             .storage()
             .persistent()
-            .get(&POSEIDON_RC)
-            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
-
-        // Sponge construction: state = [0, left, right]
-        let zero = U256::from_u32(env, 0);
-        let state = soroban_sdk::vec![env, zero, left.clone(), right.clone()];
-        let field = Symbol::new(env, "BN254");
-
-        // Single permutation call with cached params via hazmat API
-        let result = env.crypto_hazmat().poseidon_permutation(
-            &state,
-            field,
-            poseidon_params::T,
-            poseidon_params::SBOX_D,
-            poseidon_params::ROUNDS_F,
-            poseidon_params::ROUNDS_P,
-            &mds,
-            &rc,
+            .get(&DataKey::RemovedCommitment(
+                dao_id,
+                group_id,
+                member.clone(),
+            ))
+            .unwrap_or_else(|| panic_with_error!(env, TreeError::MemberNotInTree));
+        Self::set_revocation_leaf(env, dao_id, &commitment, true);
+        env.storage().persistent().set(
+            &DataKey::RevokedAt(dao_id, commitment),
+            &env.ledger().timestamp(),
         );
-
-        // Output is first element of state (always exists after Poseidon permutation)
-        result
-            .get(0)
-            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized))
-    }
-
-    // Internal: Zero value (empty leaf)
-    fn zero_value(_env: &Env) -> U256 {
-        // Standard Semaphore zero value
-        U256::from_u32(_env, 0)
     }
 
-    // Internal: Ensure zeros cache is initialized (lazy init, shared across all DAOs)
-    fn ensure_zeros_cache(env: &Env) {
-        if env.storage().instance().has(&ZEROS_CACHE) {
+    // Internal: Add a commitment to (or take it out of) the revocation tree
+    // No-op when the tree already has the requested state.
+    fn set_revocation_leaf(env: &Env, dao_id: u64, commitment: &U256, revoked: bool) {
+        let mut tree = RevocationTree::load(env, dao_id);
+        let changed = if revoked {
+            tree.insert(commitment)
+                .unwrap_or_else(|_| panic_with_error!(env, TreeError::RevocationDepthExceeded))
+        } else {
+            tree.remove(commitment)
+        };
+        if !changed {
             return;
         }
+        let new_root = tree.root();
+        tree.save();
 
-        // Precompute zeros[0..MAX_TREE_DEPTH+1]
-        // zeros[0] = 0
-        // zeros[i+1] = Poseidon(zeros[i], zeros[i])
-        let mut zeros = Vec::new(env);
-        let mut current = Self::zero_value(env);
-        zeros.push_back(current.clone());
-
-        for _ in 0..MAX_TREE_DEPTH {
-            current = Self::hash_pair(env, &current, &current);
-            zeros.push_back(current.clone());
+        RevocationEvent {
+            dao_id,
+            commitment: commitment.clone(),
+            revoked,
+            new_root,
         }
+        .publish(env);
+    }
+
+    // Internal: Check whether a commitment is in the revocation tree
+    fn is_revoked(env: &Env, dao_id: u64, commitment: &U256) -> bool {
+        let (_, leaf) = RevocationTree::load(env, dao_id).witness(commitment);
+        leaf.as_ref() == Some(commitment)
+    }
 
-        env.storage().instance().set(&ZEROS_CACHE, &zeros);
+    // Internal: Zero value (empty leaf)
+    fn zero_value(_env: &Env) -> U256 {
+        // Standard Semaphore zero value
+        U256::from_u32(_env, 0)
     }
 
-    // Internal: O(1) lookup for precomputed zero at each level
+    // Internal: Precomputed zero at each level (compile-time constants)
     fn zero_at_level(env: &Env, level: u32) -> U256 {
-        Self::ensure_zeros_cache(env);
-        let zeros: Vec<U256> = env
-            .storage()
-            .instance()
-            .get(&ZEROS_CACHE)
-            .unwrap_or_else(|| panic_with_error!(env, TreeError::TreeNotInitialized));
-        zeros
-            .get(level)
+        zkvote_poseidon::zeros::zero_at(env, level)
             .unwrap_or_else(|| panic_with_error!(env, TreeError::InvalidDepth))
     }
 
+    /// Poseidon hash of 1 to 5 BN254 field elements (circomlib `Poseidon(n)`).
+    /// Lets clients and other contracts cross-check commitments (n = 2), vote
+    /// nullifiers (n = 3) and comment nullifiers (n = 4) against the circuits.
+    pub fn poseidon_hash(env: Env, inputs: Vec<U256>) -> U256 {
+        zkvote_poseidon::hash(&env, &inputs)
+            .unwrap_or_else(|_| panic_with_error!(&env, TreeError::InvalidHashInput))
+    }
+
     /// Contract version for upgrade tracking.
     pub fn version(env: Env) -> u32 {
         env.storage()
@@ -1193,7 +3145,7 @@ impl MembershipTree {
     /// This function is used to verify that Stellar P25's Poseidon implementation
     /// matches circomlib's parameters. Compare results with circuits/utils/poseidon_kat.js
     pub fn test_poseidon_hash(env: Env, a: U256, b: U256) -> U256 {
-        Self::hash_pair(&env, &a, &b)
+        Poseidon::pair(&env).hash_pair(&env, &a, &b)
     }
 
     /// Test helper: Get zero value at specific tree level
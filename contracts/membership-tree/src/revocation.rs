@@ -0,0 +1,296 @@
+//! Sparse Merkle revocation tree for strict-revocation DAOs.
+//!
+//! The layout is circomlib's `smt`, so `SMTVerifier` can prove exclusion in-circuit:
+//! a leaf is `Poseidon(commitment, 1, 1)`, a branch is `Poseidon(left, right)` and an
+//! empty subtree is 0. The path of a commitment follows its bits, least significant
+//! first, and a leaf sits at the shallowest depth where no other leaf shares its path,
+//! so an update costs about log2(revoked) hashes rather than the full depth.
+//!
+//! Nodes below the root are stored in pages of `PAGE_LEVELS` levels: page `p` of band
+//! `b` holds the nodes at depths `8b + 1 ..= 8b + 8` under the node with path prefix
+//! `p` at depth `8b`. A node and its sibling always share a page, and an update writes
+//! one page per band it crosses.
+
+use soroban_sdk::{contracttype, Env, Map, Vec, U256};
+use zkvote_poseidon::Poseidon;
+
+use crate::{DataKey, REVOCATION_DEPTH};
+
+const PAGE_LEVELS: u32 = 8;
+
+/// A non-empty node of the revocation tree
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RevocationNode {
+    Leaf(U256, U256), // (commitment, leaf hash)
+    Branch(U256),     // hash of the two children
+}
+
+impl RevocationNode {
+    fn hash(&self) -> U256 {
+        match self {
+            RevocationNode::Leaf(_, hash) | RevocationNode::Branch(hash) => hash.clone(),
+        }
+    }
+}
+
+/// Reason a revocation tree update was refused
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RevocationError {
+    /// Two commitments share their low `REVOCATION_DEPTH` bits and cannot be separated
+    DepthExceeded,
+}
+
+/// A DAO's revocation tree with its touched pages cached until `save`
+pub struct RevocationTree<'a> {
+    env: &'a Env,
+    dao_id: u64,
+    root: Option<RevocationNode>,
+    pages: Map<(u32, u64), Map<(u32, u64), RevocationNode>>,
+    dirty: Vec<(u32, u64)>,
+    root_dirty: bool,
+}
+
+impl<'a> RevocationTree<'a> {
+    pub fn load(env: &'a Env, dao_id: u64) -> Self {
+        RevocationTree {
+            env,
+            dao_id,
+            root: env
+                .storage()
+                .persistent()
+                .get(&DataKey::RevocationRoot(dao_id)),
+            pages: Map::new(env),
+            dirty: Vec::new(env),
+            root_dirty: false,
+        }
+    }
+
+    /// Root hash (0 while nothing is revoked)
+    pub fn root(&self) -> U256 {
+        self.hash_of(&self.root)
+    }
+
+    /// Exclusion/inclusion witness for `commitment`: the siblings from the root down,
+    /// padded with zeros to `REVOCATION_DEPTH`, and the leaf the path ends at (None for
+    /// an empty subtree). The commitment is revoked iff that leaf holds it.
+    pub fn witness(&mut self, commitment: &U256) -> (Vec<U256>, Option<U256>) {
+        let bits = low_bits(commitment);
+        let (mut siblings, _, end) = self.descend(bits);
+        while siblings.len() < REVOCATION_DEPTH {
+            siblings.push_back(U256::from_u32(self.env, 0));
+        }
+        let leaf = match end {
+            Some(RevocationNode::Leaf(key, _)) => Some(key),
+            _ => None,
+        };
+        (siblings, leaf)
+    }
+
+    /// Add a commitment. Returns false if it is already revoked.
+    pub fn insert(&mut self, commitment: &U256) -> Result<bool, RevocationError> {
+        let bits = low_bits(commitment);
+        let (_, depth, end) = self.descend(bits);
+        let leaf_depth = match end {
+            Some(RevocationNode::Leaf(key, _)) if key == *commitment => return Ok(false),
+            Some(RevocationNode::Leaf(other, hash)) => {
+                // Push the existing leaf down until the two paths diverge
+                let other_bits = low_bits(&other);
+                let mut split = depth;
+                while split < REVOCATION_DEPTH && bit(bits, split) == bit(other_bits, split) {
+                    split += 1;
+                }
+                if split == REVOCATION_DEPTH {
+                    return Err(RevocationError::DepthExceeded);
+                }
+                self.set(
+                    split + 1,
+                    prefix(other_bits, split + 1),
+                    Some(RevocationNode::Leaf(other, hash)),
+                );
+                split + 1
+            }
+            _ => depth,
+        };
+        let leaf = self.leaf(commitment);
+        self.set(leaf_depth, prefix(bits, leaf_depth), Some(leaf));
+        self.rehash(bits, leaf_depth);
+        Ok(true)
+    }
+
+    /// Remove a commitment. Returns false if it was not revoked.
+    pub fn remove(&mut self, commitment: &U256) -> bool {
+        let bits = low_bits(commitment);
+        let (_, depth, end) = self.descend(bits);
+        match end {
+            Some(RevocationNode::Leaf(key, _)) if key == *commitment => {}
+            _ => return false,
+        }
+        self.set(depth, prefix(bits, depth), None);
+        if depth == 0 {
+            return true;
+        }
+
+        let sibling_prefix = prefix(bits, depth) ^ (1u64 << (depth - 1));
+        match self.node(depth, sibling_prefix) {
+            Some(leaf @ RevocationNode::Leaf(..)) => {
+                // A lone leaf moves up to the shallowest depth where it is still unique
+                self.set(depth, sibling_prefix, None);
+                let mut top = depth - 1;
+                while top > 0
+                    && self
+                        .node(top, prefix(bits, top) ^ (1u64 << (top - 1)))
+                        .is_none()
+                {
+                    self.set(top, prefix(bits, top), None);
+                    top -= 1;
+                }
+                self.set(top, prefix(bits, top), Some(leaf));
+                self.rehash(bits, top);
+            }
+            _ => self.rehash(bits, depth),
+        }
+        true
+    }
+
+    /// Write back the root and every modified page
+    pub fn save(self) {
+        let storage = self.env.storage().persistent();
+        if self.root_dirty {
+            match &self.root {
+                Some(root) => storage.set(&DataKey::RevocationRoot(self.dao_id), root),
+                None => storage.remove(&DataKey::RevocationRoot(self.dao_id)),
+            }
+        }
+        for (band, page) in self.dirty.iter() {
+            let key = DataKey::RevocationPage(self.dao_id, band, page);
+            match self.pages.get((band, page)) {
+                Some(nodes) if !nodes.is_empty() => storage.set(&key, &nodes),
+                _ => storage.remove(&key),
+            }
+        }
+    }
+
+    // Walk the commitment's path through branches. Returns the siblings passed, the
+    // depth reached and the node there (a leaf or None).
+    fn descend(&mut self, bits: u64) -> (Vec<U256>, u32, Option<RevocationNode>) {
+        let mut siblings = Vec::new(self.env);
+        let mut depth = 0;
+        let mut node = self.root.clone();
+        while let Some(RevocationNode::Branch(_)) = node {
+            let child = prefix(bits, depth + 1);
+            let sibling = self.node(depth + 1, child ^ (1u64 << depth));
+            siblings.push_back(self.hash_of(&sibling));
+            depth += 1;
+            node = self.node(depth, child);
+        }
+        (siblings, depth, node)
+    }
+
+    // Recompute the branches on the commitment's path above `depth`
+    fn rehash(&mut self, bits: u64, depth: u32) {
+        let poseidon = Poseidon::pair(self.env);
+        let start = self.node(depth, prefix(bits, depth));
+        let mut hash = self.hash_of(&start);
+        for level in (0..depth).rev() {
+            let sibling = self.node(level + 1, prefix(bits, level + 1) ^ (1u64 << level));
+            let sibling = self.hash_of(&sibling);
+            hash = if bit(bits, level) == 0 {
+                poseidon.hash_pair(self.env, &hash, &sibling)
+            } else {
+                poseidon.hash_pair(self.env, &sibling, &hash)
+            };
+            self.set(
+                level,
+                prefix(bits, level),
+                Some(RevocationNode::Branch(hash.clone())),
+            );
+        }
+    }
+
+    fn leaf(&self, commitment: &U256) -> RevocationNode {
+        let one = U256::from_u32(self.env, 1);
+        let inputs = soroban_sdk::vec![self.env, commitment.clone(), one.clone(), one];
+        let hash = Poseidon::new(self.env, 3)
+            .and_then(|poseidon| poseidon.hash(self.env, &inputs))
+            .expect("3-input Poseidon over field elements");
+        RevocationNode::Leaf(commitment.clone(), hash)
+    }
+
+    fn hash_of(&self, node: &Option<RevocationNode>) -> U256 {
+        node.as_ref()
+            .map(RevocationNode::hash)
+            .unwrap_or_else(|| U256::from_u32(self.env, 0))
+    }
+
+    fn node(&mut self, depth: u32, path: u64) -> Option<RevocationNode> {
+        if depth == 0 {
+            return self.root.clone();
+        }
+        self.page(depth, path).get((depth, path))
+    }
+
+    fn set(&mut self, depth: u32, path: u64, node: Option<RevocationNode>) {
+        if depth == 0 {
+            self.root = node;
+            self.root_dirty = true;
+            return;
+        }
+        let mut nodes = self.page(depth, path);
+        match node {
+            Some(node) => nodes.set((depth, path), node),
+            None => {
+                nodes.remove((depth, path));
+            }
+        }
+        let key = page_of(depth, path);
+        self.pages.set(key, nodes);
+        if !self.dirty.contains(key) {
+            self.dirty.push_back(key);
+        }
+    }
+
+    fn page(&mut self, depth: u32, path: u64) -> Map<(u32, u64), RevocationNode> {
+        let key = page_of(depth, path);
+        if let Some(nodes) = self.pages.get(key) {
+            return nodes;
+        }
+        let nodes = self
+            .env
+            .storage()
+            .persistent()
+            .get(&DataKey::RevocationPage(self.dao_id, key.0, key.1))
+            .unwrap_or_else(|| Map::new(self.env));
+        self.pages.set(key, nodes.clone());
+        nodes
+    }
+}
+
+// Low 64 bits of a commitment: its path through the tree
+pub fn low_bits(commitment: &U256) -> u64 {
+    let mut low = [0u8; 8];
+    commitment
+        .to_be_bytes()
+        .slice(24..32)
+        .copy_into_slice(&mut low);
+    u64::from_be_bytes(low)
+}
+
+fn bit(bits: u64, level: u32) -> u64 {
+    (bits >> level) & 1
+}
+
+// Path prefix of a node at `depth`: the first `depth` bits of the commitment's path
+fn prefix(bits: u64, depth: u32) -> u64 {
+    if depth >= 64 {
+        bits
+    } else {
+        bits & ((1u64 << depth) - 1)
+    }
+}
+
+// (band, page) holding the node at `depth` > 0 with path prefix `path`
+fn page_of(depth: u32, path: u64) -> (u32, u64) {
+    let band = (depth - 1) / PAGE_LEVELS;
+    (band, prefix(path, band * PAGE_LEVELS))
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Env};
 
 // Mock Registry contract for testing
 mod mock_registry {
@@ -40,6 +40,7 @@ mod mock_sbt {
     pub enum DataKey {
         Member(u64, Address),
         Registry,
+        Expiry(u64, Address),
     }
 
     #[contract]
@@ -64,6 +65,83 @@ mod mock_sbt {
             let key = DataKey::Member(dao_id, of);
             env.storage().persistent().get(&key).unwrap_or(false)
         }
+
+        pub fn set_expiry(env: Env, dao_id: u64, member: Address, expires_at: u64) {
+            let key = DataKey::Expiry(dao_id, member);
+            env.storage().persistent().set(&key, &expires_at);
+        }
+
+        pub fn expires_at(env: Env, dao_id: u64, member: Address) -> Option<u64> {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Expiry(dao_id, member))
+        }
+
+        pub fn revoke(env: Env, dao_id: u64, member: Address, _admin: Address) {
+            let key = DataKey::Member(dao_id, member);
+            env.storage().persistent().set(&key, &false);
+        }
+
+        pub fn revoke_from_tree(env: Env, dao_id: u64, member: Address) {
+            let key = DataKey::Member(dao_id, member);
+            env.storage().persistent().set(&key, &false);
+        }
+
+        pub fn reinstate(env: Env, dao_id: u64, member: Address) {
+            let key = DataKey::Member(dao_id, member);
+            env.storage().persistent().set(&key, &true);
+        }
+    }
+}
+
+// Mock Voting contract for testing
+mod mock_voting {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+    #[contracttype]
+    pub enum DataKey {
+        Tree,
+        DepthVk(u64, u32),
+        Active(u64),
+    }
+
+    #[contract]
+    pub struct MockVoting;
+
+    #[contractimpl]
+    impl MockVoting {
+        pub fn set_tree(env: Env, tree: Address) {
+            env.storage().instance().set(&DataKey::Tree, &tree);
+        }
+
+        pub fn tree_contract(env: Env) -> Address {
+            env.storage().instance().get(&DataKey::Tree).unwrap()
+        }
+
+        pub fn set_depth_vk(env: Env, dao_id: u64, depth: u32, ic_len: u32) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::DepthVk(dao_id, depth), &ic_len);
+        }
+
+        pub fn depth_vk_ic_len(env: Env, dao_id: u64, depth: u32) -> Option<u32> {
+            env.storage()
+                .persistent()
+                .get(&DataKey::DepthVk(dao_id, depth))
+        }
+
+        pub fn set_active(env: Env, dao_id: u64, active: bool) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Active(dao_id), &active);
+        }
+
+        pub fn has_active_proposal(env: Env, dao_id: u64) -> bool {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Active(dao_id))
+                .unwrap_or(false)
+        }
     }
 }
 
@@ -84,6 +162,30 @@ fn setup_env() -> (Env, Address, Address, Address, Address) {
     (env, tree_id, sbt_id, registry_id, admin)
 }
 
+// Recompute a root from a leaf and the contract's Merkle path for it
+fn root_from_path(client: &MembershipTreeClient, dao_id: u64, leaf: U256, index: u64) -> U256 {
+    let (elements, indices) = client.get_merkle_path(&dao_id, &index);
+    fold_path(client, leaf, &elements, &indices)
+}
+
+fn fold_path(
+    client: &MembershipTreeClient,
+    leaf: U256,
+    elements: &soroban_sdk::Vec<U256>,
+    indices: &soroban_sdk::Vec<u32>,
+) -> U256 {
+    let mut current = leaf;
+    for i in 0..elements.len() {
+        let sibling = elements.get(i).unwrap();
+        current = if indices.get(i).unwrap() == 0 {
+            client.test_poseidon_hash(&current, &sibling)
+        } else {
+            client.test_poseidon_hash(&sibling, &current)
+        };
+    }
+    current
+}
+
 #[test]
 fn test_constructor() {
     let env = Env::default();
@@ -143,8 +245,8 @@ fn test_init_tree_depth_exceeds_max_fails() {
     let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
 
     registry_client.set_admin(&1u64, &admin);
-    // Depth 19 exceeds MAX_TREE_DEPTH of 18
-    client.init_tree(&1u64, &19u32, &admin);
+    // Depth 33 exceeds MAX_TREE_DEPTH of 32
+    client.init_tree(&1u64, &33u32, &admin);
 }
 
 #[test]
@@ -155,8 +257,8 @@ fn test_init_tree_depth_extremely_large_fails() {
     let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
 
     registry_client.set_admin(&1u64, &admin);
-    // Depth 32 far exceeds MAX_TREE_DEPTH
-    client.init_tree(&1u64, &32u32, &admin);
+    // Depth 64 far exceeds MAX_TREE_DEPTH
+    client.init_tree(&1u64, &64u32, &admin);
 }
 
 #[test]
@@ -417,3 +519,1432 @@ fn test_tree_full_small_depth() {
     let commitment5 = U256::from_u32(&env, 500);
     tree_client.register_with_caller(&1u64, &commitment5, &member5);
 }
+
+#[test]
+fn test_append_after_removing_last_leaf_excludes_removed_commitment() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &3u32, &admin);
+
+    let mut members = soroban_sdk::vec![&env];
+    for i in 0u32..3 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+        members.push_back(member);
+    }
+
+    // Remove the most recently appended leaf (index 2, a left child on the frontier)
+    tree_client.remove_member(&1u64, &members.get(2).unwrap(), &admin);
+
+    // Appending at index 3 must hash against the zeroed leaf, not the removed commitment
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    let commitment = U256::from_u32(&env, 999);
+    tree_client.register_with_caller(&1u64, &commitment, &member);
+
+    let expected = root_from_path(&tree_client, 1, commitment, 3);
+    assert_eq!(tree_client.current_root(&1u64), expected);
+}
+
+#[test]
+fn test_slot_reuse_refills_zeroed_slot() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    // Depth 2 = max 4 leaves
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &2u32, &admin);
+    tree_client.set_slot_reuse(&1u64, &true, &admin);
+    assert!(tree_client.slot_reuse(&1u64));
+
+    let mut members = soroban_sdk::vec![&env];
+    for i in 0u32..4 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+        members.push_back(member);
+    }
+
+    let removed = members.get(1).unwrap();
+    tree_client.remove_member(&1u64, &removed, &admin);
+    let removal_root_idx = tree_client.curr_idx(&1u64);
+    assert_eq!(tree_client.min_root(&1u64), removal_root_idx);
+
+    let (next_index, active, capacity) = tree_client.get_tree_usage(&1u64);
+    assert_eq!((next_index, active, capacity), (4, 3, 4));
+
+    // Tree is "full" by next_index, but the zeroed slot is refilled
+    let newcomer = Address::generate(&env);
+    sbt_client.set_member(&1u64, &newcomer, &true);
+    let commitment = U256::from_u32(&env, 555);
+    tree_client.register_with_caller(&1u64, &commitment, &newcomer);
+
+    assert_eq!(tree_client.get_leaf_index(&1u64, &commitment), 1);
+    assert_eq!(tree_client.curr_idx(&1u64), removal_root_idx + 1);
+    assert_eq!(tree_client.min_root(&1u64), removal_root_idx);
+    assert_eq!(
+        tree_client.current_root(&1u64),
+        root_from_path(&tree_client, 1, commitment, 1)
+    );
+
+    let (next_index, active, _) = tree_client.get_tree_usage(&1u64);
+    assert_eq!((next_index, active), (4, 4));
+
+    // The removed member no longer owns the slot and cannot zero the newcomer's leaf
+    let result = tree_client.try_remove_member(&1u64, &removed, &admin);
+    assert_eq!(result, Err(Ok(TreeError::MemberRemoved.into())));
+}
+
+#[test]
+#[should_panic(expected = "HostError")]
+fn test_zeroed_slot_not_reused_when_disabled() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &1u32, &admin);
+
+    for i in 0u32..2 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+        if i == 0 {
+            tree_client.remove_member(&1u64, &member, &admin);
+        }
+    }
+
+    // Append-only mode: the zeroed slot stays empty and the tree is full
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 999), &member);
+}
+
+fn setup_voting(env: &Env, tree_id: &Address) -> (Address, mock_voting::MockVotingClient<'static>) {
+    let voting_id = env.register(mock_voting::MockVoting, ());
+    let voting_client = mock_voting::MockVotingClient::new(env, &voting_id);
+    voting_client.set_tree(tree_id);
+    (voting_id, voting_client)
+}
+
+#[test]
+fn test_grow_tree_preserves_leaves_and_paths() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    // Depth 2 = max 4 leaves, fill it completely
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &2u32, &admin);
+    for i in 0u32..4 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+    }
+
+    let old_root = tree_client.current_root(&1u64);
+    let old_root_idx = tree_client.curr_idx(&1u64);
+    let (old_path, old_indices) = tree_client.get_merkle_path(&1u64, &1u64);
+
+    voting_client.set_depth_vk(&1u64, &4u32, &6u32);
+    tree_client.grow_tree(&1u64, &4u32, &voting_id, &admin);
+
+    assert_eq!(tree_client.get_depth(&1u64), 4);
+    assert_eq!(tree_client.curr_idx(&1u64), old_root_idx + 1);
+    assert!(tree_client.root_ok(&1u64, &old_root));
+
+    // Existing paths keep their prefix and gain zero siblings
+    let (path, indices) = tree_client.get_merkle_path(&1u64, &1u64);
+    assert_eq!(path.len(), 4);
+    for level in 0..2u32 {
+        assert_eq!(path.get(level), old_path.get(level));
+        assert_eq!(indices.get(level), old_indices.get(level));
+    }
+    assert_eq!(path.get(2).unwrap(), tree_client.test_zero_at_level(&2u32));
+    assert_eq!(
+        tree_client.current_root(&1u64),
+        root_from_path(&tree_client, 1, U256::from_u32(&env, 101), 1)
+    );
+
+    // The old capacity no longer limits registration
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    let commitment = U256::from_u32(&env, 555);
+    tree_client.register_with_caller(&1u64, &commitment, &member);
+
+    assert_eq!(tree_client.get_leaf_index(&1u64, &commitment), 4);
+    assert_eq!(
+        tree_client.current_root(&1u64),
+        root_from_path(&tree_client, 1, commitment, 4)
+    );
+    let (_, active, capacity) = tree_client.get_tree_usage(&1u64);
+    assert_eq!((active, capacity), (5, 16));
+}
+
+#[test]
+fn test_grow_tree_requires_vk_for_new_depth() {
+    let (env, tree_id, _, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &2u32, &admin);
+
+    // VK registered for a different depth only
+    voting_client.set_depth_vk(&1u64, &3u32, &6u32);
+    let result = tree_client.try_grow_tree(&1u64, &4u32, &voting_id, &admin);
+    assert_eq!(result, Err(Ok(TreeError::VkNotAvailable.into())));
+
+    // A voting contract bound to another tree is not trusted
+    let (other_voting_id, other_voting_client) = setup_voting(&env, &Address::generate(&env));
+    other_voting_client.set_depth_vk(&1u64, &4u32, &6u32);
+    let result = tree_client.try_grow_tree(&1u64, &4u32, &other_voting_id, &admin);
+    assert_eq!(result, Err(Ok(TreeError::VkNotAvailable.into())));
+
+    assert_eq!(tree_client.get_depth(&1u64), 2);
+}
+
+#[test]
+fn test_grow_tree_requires_vk_for_expiry_circuit() {
+    let (env, tree_id, _, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_expiring_tree(&1u64, &2u32, &admin);
+
+    // The plain circuit's VK cannot check the expiry signal
+    voting_client.set_depth_vk(&1u64, &4u32, &6u32);
+    let result = tree_client.try_grow_tree(&1u64, &4u32, &voting_id, &admin);
+    assert_eq!(result, Err(Ok(TreeError::VkCircuitMismatch.into())));
+    assert_eq!(tree_client.get_depth(&1u64), 2);
+
+    voting_client.set_depth_vk(&1u64, &4u32, &7u32);
+    tree_client.grow_tree(&1u64, &4u32, &voting_id, &admin);
+    assert_eq!(tree_client.get_depth(&1u64), 4);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+fn test_grow_tree_panics_for_strict_dao_without_strict_vk() {
+    let (env, tree_id, _, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &2u32, &admin);
+    tree_client.enable_strict_revocation(&1u64, &admin);
+
+    // Only the plain circuit exists at the new depth; the strict one has an extra signal
+    voting_client.set_depth_vk(&1u64, &4u32, &6u32);
+    tree_client.grow_tree(&1u64, &4u32, &voting_id, &admin);
+}
+
+#[test]
+fn test_grow_tree_rejects_invalid_depth() {
+    let (env, tree_id, _, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &4u32, &admin);
+    voting_client.set_depth_vk(&1u64, &4u32, &6u32);
+    voting_client.set_depth_vk(&1u64, &33u32, &6u32);
+
+    let result = tree_client.try_grow_tree(&1u64, &4u32, &voting_id, &admin);
+    assert_eq!(result, Err(Ok(TreeError::InvalidDepth.into())));
+    let result = tree_client.try_grow_tree(&1u64, &33u32, &voting_id, &admin);
+    assert_eq!(result, Err(Ok(TreeError::InvalidDepth.into())));
+}
+
+#[test]
+fn test_historical_path_after_appends_and_removal() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &3u32, &admin);
+
+    let mut members = soroban_sdk::vec![&env];
+    for i in 0u32..3 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+        members.push_back(member);
+    }
+
+    // Snapshot as a Fixed-mode proposal would
+    let snapshot_root = tree_client.current_root(&1u64);
+    let snapshot_path = tree_client.get_merkle_path(&1u64, &1u64);
+
+    for i in 3u32..5 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+    }
+    tree_client.remove_member(&1u64, &members.get(0).unwrap(), &admin);
+    assert_ne!(tree_client.current_root(&1u64), snapshot_root);
+
+    let (elements, indices) = tree_client.get_merkle_path_at(&1u64, &1u64, &snapshot_root);
+    assert_eq!((elements.clone(), indices.clone()), snapshot_path);
+    assert_eq!(
+        fold_path(&tree_client, U256::from_u32(&env, 101), &elements, &indices),
+        snapshot_root
+    );
+
+    // The removed member's leaf is still provable against the snapshot
+    let (elements, indices) = tree_client.get_merkle_path_at(&1u64, &0u64, &snapshot_root);
+    assert_eq!(
+        fold_path(&tree_client, U256::from_u32(&env, 100), &elements, &indices),
+        snapshot_root
+    );
+
+    // The current root matches the live path
+    let current_root = tree_client.current_root(&1u64);
+    let (elements, indices) = tree_client.get_merkle_path_at(&1u64, &4u64, &current_root);
+    assert_eq!(
+        (elements, indices),
+        tree_client.get_merkle_path(&1u64, &4u64)
+    );
+}
+
+#[test]
+fn test_historical_path_before_grow_and_empty_root() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &2u32, &admin);
+    let empty_root = tree_client.current_root(&1u64);
+
+    for i in 0u32..2 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+    }
+    let snapshot_root = tree_client.current_root(&1u64);
+
+    voting_client.set_depth_vk(&1u64, &4u32, &6u32);
+    tree_client.grow_tree(&1u64, &4u32, &voting_id, &admin);
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 555), &member);
+
+    // Paths against pre-growth roots use the depth at that root
+    let (elements, indices) = tree_client.get_merkle_path_at(&1u64, &1u64, &snapshot_root);
+    assert_eq!(elements.len(), 2);
+    assert_eq!(
+        fold_path(&tree_client, U256::from_u32(&env, 101), &elements, &indices),
+        snapshot_root
+    );
+
+    // The empty root shares root index 0 with the first insertion
+    let (elements, indices) = tree_client.get_merkle_path_at(&1u64, &0u64, &empty_root);
+    assert_eq!(
+        fold_path(&tree_client, U256::from_u32(&env, 0), &elements, &indices),
+        empty_root
+    );
+
+    let transition = tree_client.get_transition(&1u64, &2u32).unwrap();
+    assert_eq!((transition.old_depth, transition.new_depth), (2, 4));
+}
+
+#[test]
+fn test_transitions_leave_with_root_history() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &6u32, &admin);
+    for i in 0..MAX_ROOT_HISTORY + 5 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+    }
+
+    // Insertion i produced root index i (the first shares index 0 with the empty root); the
+    // oldest ones are gone, and their ring slots do not leak into older or future lookups
+    let latest = MAX_ROOT_HISTORY + 4;
+    assert_eq!(
+        tree_client.get_transition(&1u64, &(latest - MAX_ROOT_HISTORY)),
+        None
+    );
+    assert_eq!(
+        tree_client.get_transition(&1u64, &(latest + MAX_ROOT_HISTORY)),
+        None
+    );
+    for root_index in [latest + 1 - MAX_ROOT_HISTORY, latest] {
+        let transition = tree_client.get_transition(&1u64, &root_index).unwrap();
+        assert_eq!(transition.leaf_index, root_index as u64);
+        assert_eq!(transition.new_value, U256::from_u32(&env, 100 + root_index));
+    }
+    assert_eq!(
+        tree_client.get_transition(&1u64, &latest).unwrap().new_root,
+        tree_client.current_root(&1u64)
+    );
+}
+
+#[test]
+fn test_historical_path_unknown_root_fails() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &3u32, &admin);
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100), &member);
+
+    let result = tree_client.try_get_merkle_path_at(&1u64, &0u64, &U256::from_u32(&env, 999999));
+    assert_eq!(result, Err(Ok(TreeError::RootNotFound.into())));
+}
+
+#[test]
+fn test_consistency_proof_verifies_and_rejects_reversed_range() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &2u32, &admin);
+
+    let mut members = soroban_sdk::vec![&env];
+    for i in 0u32..2 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+        members.push_back(member);
+    }
+    let old_root = tree_client.current_root(&1u64);
+
+    tree_client.remove_member(&1u64, &members.get(1).unwrap(), &admin);
+    voting_client.set_depth_vk(&1u64, &3u32, &6u32);
+    tree_client.grow_tree(&1u64, &3u32, &voting_id, &admin);
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 200), &member);
+    let new_root = tree_client.current_root(&1u64);
+
+    let proof = tree_client.get_consistency_proof(&1u64, &old_root, &new_root);
+    assert_eq!(proof.len(), 3);
+    assert!(proof.get(1).unwrap().path.is_empty());
+    let result = crate::consistency::verify_consistency(&old_root, &new_root, &proof, |a, b| {
+        tree_client.test_poseidon_hash(a, b)
+    });
+    assert_eq!(result, Ok(()));
+
+    let result = tree_client.try_get_consistency_proof(&1u64, &new_root, &old_root);
+    assert_eq!(result, Err(Ok(TreeError::InvalidRootRange.into())));
+}
+
+#[test]
+fn test_depth_32_tree_paths() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &32u32, &admin);
+    assert_eq!(
+        tree_client.current_root(&1u64),
+        tree_client.test_zero_at_level(&32u32)
+    );
+
+    for i in 0u32..3 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        tree_client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+    }
+
+    let (depth, next_index, root) = tree_client.get_tree_info(&1u64);
+    assert_eq!((depth, next_index), (32, 3));
+    assert_eq!(tree_client.get_tree_usage(&1u64).2, 1u64 << 32);
+    for i in 0u32..3 {
+        let (path, _) = tree_client.get_merkle_path(&1u64, &(i as u64));
+        assert_eq!(path.len(), 32);
+        assert_eq!(
+            root_from_path(&tree_client, 1, U256::from_u32(&env, 100 + i), i as u64),
+            root
+        );
+    }
+}
+
+#[test]
+fn test_depth_32_tree_fills_its_last_slot() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &32u32, &admin);
+
+    // Jump the append frontier to the last slot; every earlier leaf stays empty
+    let last = (1u64 << 32) - 1;
+    env.as_contract(&tree_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::NextLeafIndex(1u64, GENERAL_GROUP), &last);
+    });
+
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    let commitment = U256::from_u32(&env, 4242);
+    tree_client.register_with_caller(&1u64, &commitment, &member);
+    assert_eq!(tree_client.get_leaf_index(&1u64, &commitment), last);
+
+    let (_, next_index, root) = tree_client.get_tree_info(&1u64);
+    assert_eq!(next_index, u32::MAX);
+    let (next_index, _, capacity) = tree_client.get_tree_usage(&1u64);
+    assert_eq!(next_index, capacity);
+    assert_eq!(root_from_path(&tree_client, 1, commitment, last), root);
+
+    let late = Address::generate(&env);
+    sbt_client.set_member(&1u64, &late, &true);
+    assert_eq!(
+        tree_client.try_register_with_caller(&1u64, &U256::from_u32(&env, 4243), &late),
+        Err(Ok(TreeError::TreeFull.into()))
+    );
+}
+
+#[test]
+fn test_paths_valid_across_spine_boundaries_with_removals() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    tree_client.init_tree(&1u64, &4u32, &admin);
+
+    // Removals before the tree grows past 2 and 4 leaves change left-spine nodes
+    // that only become siblings later
+    let mut values = soroban_sdk::vec![&env];
+    for i in 0u32..9 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        let commitment = U256::from_u32(&env, 100 + i);
+        tree_client.register_with_caller(&1u64, &commitment, &member);
+        values.push_back(commitment);
+        if i == 0 || i == 2 {
+            tree_client.remove_member(&1u64, &member, &admin);
+            values.set(i, U256::from_u32(&env, 0));
+        }
+    }
+
+    let root = tree_client.current_root(&1u64);
+    for i in 0u32..9 {
+        assert_eq!(
+            root_from_path(&tree_client, 1, values.get(i).unwrap(), i as u64),
+            root
+        );
+    }
+}
+
+#[test]
+fn test_removal_skips_siblings_past_append_frontier() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    // DAO 2 holds the same first two leaves and never saw the third
+    for dao_id in [1u64, 2u64] {
+        registry_client.set_admin(&dao_id, &admin);
+        tree_client.init_tree(&dao_id, &18u32, &admin);
+    }
+    let mut members = soroban_sdk::vec![&env];
+    for i in 0u32..3 {
+        for dao_id in [1u64, 2u64] {
+            if dao_id == 2 && i == 2 {
+                continue;
+            }
+            let member = Address::generate(&env);
+            sbt_client.set_member(&dao_id, &member, &true);
+            tree_client.register_with_caller(&dao_id, &U256::from_u32(&env, 100 + i), &member);
+            if dao_id == 1 {
+                members.push_back(member);
+            }
+        }
+    }
+
+    // Every sibling of leaf 2 except the one at level 1 lies past the frontier and is taken
+    // from the zero ladder; reading those 17 from storage would put this at 47 entries
+    tree_client.remove_member(&1u64, &members.get(2).unwrap(), &admin);
+    let reads = env.cost_estimate().resources().memory_read_entries;
+    assert!(reads <= 30, "removal read {reads} entries");
+
+    let root = tree_client.current_root(&1u64);
+    assert_eq!(root, tree_client.current_root(&2u64));
+    for i in 0u32..2 {
+        assert_eq!(
+            root_from_path(&tree_client, 1, U256::from_u32(&env, 100 + i), i as u64),
+            root
+        );
+    }
+    assert_eq!(
+        root_from_path(&tree_client, 1, U256::from_u32(&env, 0), 2),
+        root
+    );
+}
+
+#[test]
+fn test_zero_ladder_constants() {
+    let env = Env::default();
+    let sbt_id = env.register(mock_sbt::MockSbt, ());
+    let tree_id = env.register(MembershipTree, (sbt_id,));
+    let client = MembershipTreeClient::new(&env, &tree_id);
+
+    let mut current = U256::from_u32(&env, 0);
+    for level in 0u32..=32 {
+        assert_eq!(
+            client.test_zero_at_level(&level),
+            current,
+            "level {}",
+            level
+        );
+        current = client.test_poseidon_hash(&current, &current);
+    }
+}
+
+#[test]
+fn test_groups_keep_independent_trees() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let council = 1u32;
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &4u32, &admin);
+    client.create_group(&1u64, &council, &2u32, &admin);
+    assert_eq!(client.get_groups(&1u64), soroban_sdk::vec![&env, council]);
+    assert_eq!(
+        client.try_create_group(&1u64, &GENERAL_GROUP, &2u32, &admin),
+        Err(Ok(TreeError::InvalidGroup.into()))
+    );
+
+    let mut members = soroban_sdk::vec![&env];
+    for i in 0u32..3 {
+        let member = Address::generate(&env);
+        sbt_client.set_member(&1u64, &member, &true);
+        client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &member);
+        members.push_back(member);
+    }
+    let general_root = client.current_root(&1u64);
+
+    // Council seats are curated by the admin
+    let councillor = members.get(2).unwrap();
+    let commitment = U256::from_u32(&env, 102);
+    assert_eq!(
+        client.try_register_in_group(&1u64, &council, &commitment, &councillor),
+        Err(Ok(TreeError::NotGroupMember.into()))
+    );
+    client.add_group_member(&1u64, &council, &councillor, &admin);
+    assert!(client.is_group_member(&1u64, &council, &councillor));
+    client.register_in_group(&1u64, &council, &commitment, &councillor);
+
+    // The same commitment sits at index 0 of a depth-2 tree with its own history
+    assert_eq!(client.current_root(&1u64), general_root);
+    assert_eq!(client.group_leaf_idx(&1u64, &council, &commitment), 0);
+    assert_eq!(client.get_leaf_index(&1u64, &commitment), 2);
+    let (depth, next_index, council_root) = client.group_info(&1u64, &council);
+    assert_eq!((depth, next_index), (2, 1));
+    assert_eq!(client.group_usage(&1u64, &council).1, 1);
+    let (elements, indices) = client.group_path(&1u64, &council, &0u64);
+    assert_eq!(
+        fold_path(&client, commitment, &elements, &indices),
+        council_root
+    );
+    assert!(client.group_root_ok(&1u64, &council, &council_root));
+    assert!(!client.root_ok(&1u64, &council_root));
+    assert_eq!(client.group_curr_idx(&1u64, &council), 0);
+}
+
+#[test]
+fn test_group_history_queries_and_growth() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+    let council = 1u32;
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &4u32, &admin);
+    client.create_group(&1u64, &council, &1u32, &admin);
+    let empty_root = client.group_root(&1u64, &council);
+
+    let mut commitments = soroban_sdk::vec![&env];
+    for i in 0u32..2 {
+        let member = Address::generate(&env);
+        let commitment = U256::from_u32(&env, 200 + i);
+        sbt_client.set_member(&1u64, &member, &true);
+        client.add_group_member(&1u64, &council, &member, &admin);
+        client.register_in_group(&1u64, &council, &commitment, &member);
+        commitments.push_back(commitment);
+    }
+    let snapshot_root = client.group_root(&1u64, &council);
+
+    // The council is full at depth 1; growing it leaves the general tree alone
+    let general_root = client.current_root(&1u64);
+    voting_client.set_depth_vk(&1u64, &3u32, &6u32);
+    client.grow_group(&1u64, &council, &3u32, &voting_id, &admin);
+    assert_eq!(client.group_depth(&1u64, &council), 3);
+    assert_eq!(client.get_depth(&1u64), 4);
+    assert_eq!(client.current_root(&1u64), general_root);
+    let grow_root = client.group_root(&1u64, &council);
+
+    // Paths against the pre-growth root keep the old depth
+    let (elements, indices) = client.group_path_at(&1u64, &council, &1u64, &snapshot_root);
+    assert_eq!(elements.len(), 1);
+    assert_eq!(
+        fold_path(&client, commitments.get(1).unwrap(), &elements, &indices),
+        snapshot_root
+    );
+
+    let transition = client
+        .group_transition(&1u64, &council, &client.group_curr_idx(&1u64, &council))
+        .unwrap();
+    assert_eq!((transition.old_depth, transition.new_depth), (1, 3));
+    assert_eq!(transition.new_root, grow_root);
+
+    let proof = client.group_consistency_proof(&1u64, &council, &empty_root, &grow_root);
+    assert_eq!(proof.len(), 3);
+    let result = crate::consistency::verify_consistency(&empty_root, &grow_root, &proof, |a, b| {
+        client.test_poseidon_hash(a, b)
+    });
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn test_group_removal_and_dao_removal_cascade() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let council = 7u32;
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+    client.create_group(&1u64, &council, &3u32, &admin);
+
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 5), &member);
+    client.add_group_member(&1u64, &council, &member, &admin);
+    client.register_in_group(&1u64, &council, &U256::from_u32(&env, 5), &member);
+
+    // Leaving the council keeps general membership and allows rejoining with a new commitment
+    let general_root = client.current_root(&1u64);
+    client.remove_from_group(&1u64, &council, &member, &admin);
+    assert_eq!(client.current_root(&1u64), general_root);
+    assert!(!client.is_group_member(&1u64, &council, &member));
+    assert_eq!(client.group_min_root(&1u64, &council), 1);
+    assert_eq!(client.group_usage(&1u64, &council).1, 0);
+
+    client.add_group_member(&1u64, &council, &member, &admin);
+    client.register_in_group(&1u64, &council, &U256::from_u32(&env, 6), &member);
+    assert_eq!(client.group_usage(&1u64, &council).1, 1);
+
+    // Removing the member from the DAO also zeroes their council leaf
+    client.remove_member(&1u64, &member, &admin);
+    assert_eq!(client.group_usage(&1u64, &council).1, 0);
+    assert_eq!(
+        client.group_min_root(&1u64, &council),
+        client.group_curr_idx(&1u64, &council)
+    );
+    assert!(!client.is_group_member(&1u64, &council, &member));
+    let (elements, indices) = client.group_path(&1u64, &council, &1u64);
+    assert_eq!(
+        fold_path(&client, U256::from_u32(&env, 0), &elements, &indices),
+        client.group_root(&1u64, &council)
+    );
+}
+
+#[test]
+fn test_expiring_tree_bakes_and_renews_expiry() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_expiring_tree(&1u64, &4u32, &admin);
+    assert!(client.expiring(&1u64));
+
+    let annual = Address::generate(&env);
+    let permanent = Address::generate(&env);
+    sbt_client.set_member(&1u64, &annual, &true);
+    sbt_client.set_member(&1u64, &permanent, &true);
+    sbt_client.set_expiry(&1u64, &annual, &1_000);
+
+    let c1 = U256::from_u32(&env, 11);
+    let c2 = U256::from_u32(&env, 22);
+    client.register_with_caller(&1u64, &c1, &annual);
+    client.register_with_caller(&1u64, &c2, &permanent);
+    assert_eq!(client.get_leaf_index(&1u64, &c1), 0);
+
+    // Leaves are Poseidon(commitment, expires_at), NO_EXPIRY for permanent members
+    let leaf1 = client.test_poseidon_hash(&c1, &U256::from_u32(&env, 1_000));
+    let leaf2 = client.test_poseidon_hash(&c2, &U256::from_u128(&env, NO_EXPIRY as u128));
+    let root = client.current_root(&1u64);
+    assert_eq!(root_from_path(&client, 1, leaf1, 0), root);
+    assert_eq!(root_from_path(&client, 1, leaf2.clone(), 1), root);
+
+    // Renewal rebakes the leaf; a second call with the same expiry is a no-op
+    sbt_client.set_expiry(&1u64, &annual, &2_000);
+    client.renew_leaf(&1u64, &annual);
+    let renewed = client.test_poseidon_hash(&c1, &U256::from_u32(&env, 2_000));
+    let root = client.current_root(&1u64);
+    assert_eq!(root_from_path(&client, 1, renewed, 0), root);
+    client.renew_leaf(&1u64, &annual);
+    assert_eq!(client.current_root(&1u64), root);
+
+    let outsider = Address::generate(&env);
+    sbt_client.set_member(&1u64, &outsider, &true);
+    assert_eq!(
+        client.try_renew_leaf(&1u64, &outsider),
+        Err(Ok(TreeError::MemberNotInTree.into()))
+    );
+
+    // Plain trees have nothing to renew
+    registry_client.set_admin(&2u64, &admin);
+    client.init_tree(&2u64, &4u32, &admin);
+    assert!(!client.expiring(&2u64));
+    assert_eq!(
+        client.try_renew_leaf(&2u64, &annual),
+        Err(Ok(TreeError::NotExpiring.into()))
+    );
+}
+
+#[test]
+fn test_remove_departed_zeroes_leaf_and_bumps_min_root() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+
+    let member = Address::generate(&env);
+    let bystander = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    sbt_client.set_member(&1u64, &bystander, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 5), &member);
+
+    // Members without a leaf leave without touching the tree
+    let root = client.current_root(&1u64);
+    client.remove_departed(&1u64, &bystander);
+    assert_eq!(client.current_root(&1u64), root);
+
+    client.remove_departed(&1u64, &member);
+    assert_eq!(client.get_tree_usage(&1u64).1, 0);
+    assert_eq!(client.min_root(&1u64), client.curr_idx(&1u64));
+
+    // A repeated call (e.g. leave after an admin removal) is a no-op
+    let root = client.current_root(&1u64);
+    client.remove_departed(&1u64, &member);
+    assert_eq!(client.current_root(&1u64), root);
+}
+
+#[test]
+fn test_reinstate_member_reinserts_leaf_in_one_step() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+
+    let member = Address::generate(&env);
+    sbt_client.set_member(&1u64, &member, &true);
+    let old = U256::from_u32(&env, 5);
+    client.register_with_caller(&1u64, &old, &member);
+
+    // Still active: nothing to reinstate
+    assert_eq!(
+        client.try_reinstate_member(&1u64, &member, &None, &admin),
+        Err(Ok(TreeError::MemberNotRevoked.into()))
+    );
+
+    // Re-enable the removed commitment at a fresh index
+    client.remove_member(&1u64, &member, &admin);
+    assert!(!sbt_client.has(&1u64, &member));
+    client.reinstate_member(&1u64, &member, &None, &admin);
+    assert!(sbt_client.has(&1u64, &member));
+    assert_eq!(client.get_leaf_index(&1u64, &old), 1);
+    assert_eq!(
+        client.reinst_at(&1u64, &old),
+        Some(env.ledger().timestamp())
+    );
+
+    // Or replace it with a new commitment
+    client.remove_member(&1u64, &member, &admin);
+    let new = U256::from_u32(&env, 6);
+    client.reinstate_member(&1u64, &member, &Some(new.clone()), &admin);
+    assert_eq!(client.get_leaf_index(&1u64, &new), 2);
+
+    // Members who never registered have nothing to reinstate
+    let outsider = Address::generate(&env);
+    sbt_client.set_member(&1u64, &outsider, &true);
+    assert_eq!(
+        client.try_reinstate_member(&1u64, &outsider, &None, &admin),
+        Err(Ok(TreeError::MemberNotInTree.into()))
+    );
+
+    // Removals without a recorded commitment must be given a new one
+    client.remove_member(&1u64, &member, &admin);
+    env.as_contract(&tree_id, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RemovedCommitment(
+                1u64,
+                GENERAL_GROUP,
+                member.clone(),
+            ));
+    });
+    assert_eq!(
+        client.try_reinstate_member(&1u64, &member, &None, &admin),
+        Err(Ok(TreeError::NoPriorCommitment.into()))
+    );
+}
+
+// circomlib SMT leaf hash: Poseidon(key, value, 1) with value 1
+fn revocation_leaf_hash(env: &Env, commitment: &U256) -> U256 {
+    let one = U256::from_u32(env, 1);
+    zkvote_poseidon::hash(
+        env,
+        &soroban_sdk::vec![env, commitment.clone(), one.clone(), one],
+    )
+    .unwrap()
+}
+
+// Fold a revocation witness back to the root the way SMTVerifier does
+fn revocation_root_from(env: &Env, commitment: &U256, siblings: &Vec<U256>) -> U256 {
+    let zero = U256::from_u32(env, 0);
+    let depth = siblings
+        .iter()
+        .enumerate()
+        .filter(|(_, sibling)| *sibling != zero)
+        .map(|(level, _)| level + 1)
+        .max()
+        .unwrap_or(0);
+    let bits = commitment.to_u128().unwrap();
+    let poseidon = Poseidon::pair(env);
+    let mut node = revocation_leaf_hash(env, commitment);
+    for level in (0..depth).rev() {
+        let sibling = siblings.get(level as u32).unwrap();
+        node = if (bits >> level) & 1 == 0 {
+            poseidon.hash_pair(env, &node, &sibling)
+        } else {
+            poseidon.hash_pair(env, &sibling, &node)
+        };
+    }
+    node
+}
+
+#[test]
+fn test_strict_revocation_tracks_removed_commitments() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    env.cost_estimate().budget().reset_unlimited();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    assert_eq!(
+        client.try_enable_strict_revocation(&1u64, &admin),
+        Err(Ok(TreeError::TreeNotInitialized.into()))
+    );
+    client.init_tree(&1u64, &3u32, &admin);
+    client.enable_strict_revocation(&1u64, &admin);
+    assert!(client.strict_revocation(&1u64));
+    let zero = U256::from_u32(&env, 0);
+    assert_eq!(client.revocation_root(&1u64), zero);
+
+    // 5 = 0b0101 and 13 = 0b1101 share their three low bits; 6 splits off at bit 0
+    let members = [5u32, 6, 13].map(|c| (Address::generate(&env), U256::from_u32(&env, c)));
+    for (member, commitment) in &members {
+        sbt_client.set_member(&1u64, member, &true);
+        client.register_with_caller(&1u64, commitment, member);
+    }
+
+    // A single revoked commitment is the root leaf
+    client.remove_member(&1u64, &members[0].0, &admin);
+    let only = revocation_leaf_hash(&env, &members[0].1);
+    assert_eq!(client.revocation_root(&1u64), only);
+    assert_eq!(
+        client.revok_at(&1u64, &members[0].1),
+        Some(env.ledger().timestamp())
+    );
+
+    // Every witness folds back to the root, revoked or not
+    client.remove_member(&1u64, &members[1].0, &admin);
+    client.remove_member(&1u64, &members[2].0, &admin);
+    let root = client.revocation_root(&1u64);
+    for (_, commitment) in &members {
+        let (siblings, leaf) = client.revocation_proof(&1u64, commitment);
+        assert_eq!(siblings.len(), REVOCATION_DEPTH);
+        assert_eq!(leaf.as_ref(), Some(commitment));
+        assert_eq!(revocation_root_from(&env, commitment, &siblings), root);
+    }
+
+    // Exclusion: the path of 7 = 0b0111 ends at an empty subtree, 21 = 0b10101 at 5's leaf
+    let (_, leaf) = client.revocation_proof(&1u64, &U256::from_u32(&env, 7));
+    assert_eq!(leaf, None);
+    let (_, leaf) = client.revocation_proof(&1u64, &U256::from_u32(&env, 21));
+    assert_eq!(leaf, Some(members[0].1.clone()));
+
+    // Re-enabling commitments collapses the tree back to the remaining leaf
+    client.reinstate_member(&1u64, &members[2].0, &None, &admin);
+    client.reinstate_member(&1u64, &members[1].0, &None, &admin);
+    assert_eq!(client.revocation_root(&1u64), only);
+
+    // A revoked commitment cannot be registered anew
+    client.reinstate_member(&1u64, &members[0].0, &Some(U256::from_u32(&env, 8)), &admin);
+    let council = 1u32;
+    client.create_group(&1u64, &council, &2u32, &admin);
+    client.add_group_member(&1u64, &council, &members[0].0, &admin);
+    assert_eq!(
+        client.try_register_in_group(&1u64, &council, &members[0].1, &members[0].0),
+        Err(Ok(TreeError::CommitmentRevoked.into()))
+    );
+
+    // A commitment sharing all 64 path bits with another is refused at registration,
+    // so every member stays removable
+    let collider = U256::from_u128(&env, (1u128 << 64) + 5);
+    let other = Address::generate(&env);
+    sbt_client.set_member(&1u64, &other, &true);
+    assert_eq!(
+        client.try_register_with_caller(&1u64, &collider, &other),
+        Err(Ok(TreeError::RevocationPathTaken.into()))
+    );
+    client.register_with_caller(&1u64, &U256::from_u128(&env, (1u128 << 64) + 9), &other);
+    client.remove_member(&1u64, &other, &admin);
+    client.remove_member(&1u64, &members[0].0, &admin);
+}
+
+#[test]
+fn test_membership_freeze_queues_changes_until_voting_ends() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    sbt_client.set_member(&1u64, &alice, &true);
+    sbt_client.set_member(&1u64, &bob, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 5), &alice);
+
+    // Only a voting contract reading this tree can freeze it
+    let (stranger, stranger_client) = setup_voting(&env, &tree_id);
+    stranger_client.set_tree(&Address::generate(&env));
+    assert_eq!(
+        client.try_set_membership_freeze(&1u64, &Some(stranger), &admin),
+        Err(Ok(TreeError::VotingNotBound.into()))
+    );
+    client.set_membership_freeze(&1u64, &Some(voting_id.clone()), &admin);
+    assert_eq!(client.membership_freeze(&1u64), Some(voting_id.clone()));
+    assert!(!client.membership_frozen(&1u64));
+
+    // While a proposal is active, changes are queued and the root stays put
+    voting_client.set_active(&1u64, &true);
+    let root = client.current_root(&1u64);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 6), &bob);
+    client.remove_member(&1u64, &alice, &admin);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 6), &bob);
+    assert_eq!(client.current_root(&1u64), root);
+    assert!(sbt_client.has(&1u64, &alice));
+    assert_eq!(client.pending_changes(&1u64).len(), 3);
+    assert_eq!(
+        client.try_set_membership_freeze(&1u64, &Some(voting_id.clone()), &admin),
+        Err(Ok(TreeError::MembershipFrozen.into()))
+    );
+    assert_eq!(
+        client.try_apply_pending(&1u64, &10u32),
+        Err(Ok(TreeError::MembershipFrozen.into()))
+    );
+
+    // Closing the vote releases the first PENDING_BATCH changes and `apply_pending` the
+    // rest, in order, dropping the duplicate registration
+    voting_client.set_active(&1u64, &false);
+    assert_eq!(client.pending_changes(&1u64).len(), 3);
+    assert_eq!(client.current_root(&1u64), root);
+    assert_eq!(client.release_pending(&1u64), 1);
+    assert_eq!(client.get_leaf_index(&1u64, &U256::from_u32(&env, 6)), 1);
+    assert!(!sbt_client.has(&1u64, &alice));
+    assert_eq!(client.apply_pending(&1u64, &2u32), 0);
+    assert_eq!(client.pending_changes(&1u64).len(), 0);
+
+    // A change made after voting ends drains the queue ahead of itself
+    voting_client.set_active(&1u64, &true);
+    client.reinstate_member(&1u64, &alice, &None, &admin);
+    voting_client.set_active(&1u64, &false);
+    let carol = Address::generate(&env);
+    sbt_client.set_member(&1u64, &carol, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 7), &carol);
+    assert!(sbt_client.has(&1u64, &alice));
+    assert_eq!(client.get_leaf_index(&1u64, &U256::from_u32(&env, 5)), 2);
+    assert_eq!(client.get_leaf_index(&1u64, &U256::from_u32(&env, 7)), 3);
+    assert_eq!(client.pending_changes(&1u64).len(), 0);
+
+    // An emergency removal skips the delay but not the freeze
+    voting_client.set_active(&1u64, &true);
+    let root = client.current_root(&1u64);
+    client.emergency_remove(&1u64, &alice, &admin);
+    assert_eq!(client.current_root(&1u64), root);
+    assert!(sbt_client.has(&1u64, &alice));
+    assert_eq!(
+        client.pending_changes(&1u64),
+        soroban_sdk::vec![&env, PendingChange::Remove(alice.clone())]
+    );
+
+    // The queue is bounded
+    for i in 0..31u32 {
+        client.register_with_caller(&1u64, &U256::from_u32(&env, 100 + i), &carol);
+    }
+    assert_eq!(
+        client.try_register_with_caller(&1u64, &U256::from_u32(&env, 200), &carol),
+        Err(Ok(TreeError::PendingQueueFull.into()))
+    );
+
+    // The freeze can only be lifted once voting has ended; the queue then applies as usual
+    voting_client.set_active(&1u64, &false);
+    client.set_membership_freeze(&1u64, &None, &admin);
+    assert!(!client.membership_frozen(&1u64));
+    env.cost_estimate().budget().reset_unlimited();
+    assert_eq!(client.apply_pending(&1u64, &32u32), 0);
+    assert!(!sbt_client.has(&1u64, &alice));
+}
+
+#[test]
+fn test_membership_freeze_cannot_be_lifted_mid_vote() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+    client.set_membership_freeze(&1u64, &Some(voting_id.clone()), &admin);
+
+    let alice = Address::generate(&env);
+    sbt_client.set_member(&1u64, &alice, &true);
+    voting_client.set_active(&1u64, &true);
+    let root = client.current_root(&1u64);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 5), &alice);
+
+    // Turning the freeze off, registering and turning it back on would move the electorate
+    assert_eq!(
+        client.try_set_membership_freeze(&1u64, &None, &admin),
+        Err(Ok(TreeError::MembershipFrozen.into()))
+    );
+    assert_eq!(client.membership_freeze(&1u64), Some(voting_id));
+    assert!(client.membership_frozen(&1u64));
+    assert_eq!(client.current_root(&1u64), root);
+    assert_eq!(client.pending_changes(&1u64).len(), 1);
+
+    voting_client.set_active(&1u64, &false);
+    client.set_membership_freeze(&1u64, &None, &admin);
+    assert_eq!(client.membership_freeze(&1u64), None);
+}
+
+#[test]
+fn test_membership_freeze_queues_group_removals() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+    let council = 1u32;
+    client.create_group(&1u64, &council, &2u32, &admin);
+    client.set_membership_freeze(&1u64, &Some(voting_id), &admin);
+
+    let alice = Address::generate(&env);
+    sbt_client.set_member(&1u64, &alice, &true);
+    client.add_group_member(&1u64, &council, &alice, &admin);
+    client.register_in_group(&1u64, &council, &U256::from_u32(&env, 5), &alice);
+
+    // A group removal under an open proposal would move the group's root
+    voting_client.set_active(&1u64, &true);
+    let root = client.group_root(&1u64, &council);
+    client.remove_from_group(&1u64, &council, &alice, &admin);
+    assert_eq!(client.group_root(&1u64, &council), root);
+    assert!(client.is_group_member(&1u64, &council, &alice));
+    assert_eq!(
+        client.pending_changes(&1u64),
+        soroban_sdk::vec![&env, PendingChange::RemoveFromGroup(council, alice.clone())]
+    );
+
+    voting_client.set_active(&1u64, &false);
+    assert_eq!(client.apply_pending(&1u64, &1u32), 0);
+    assert_ne!(client.group_root(&1u64, &council), root);
+    assert!(!client.is_group_member(&1u64, &council, &alice));
+}
+
+#[test]
+fn test_departure_waits_behind_queued_changes() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+    client.set_membership_freeze(&1u64, &Some(voting_id), &admin);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    sbt_client.set_member(&1u64, &alice, &true);
+    sbt_client.set_member(&1u64, &bob, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 5), &alice);
+
+    voting_client.set_active(&1u64, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 6), &bob);
+    voting_client.set_active(&1u64, &false);
+
+    // Voting has ended but bob's registration is still queued: alice's exit goes after it
+    let root = client.current_root(&1u64);
+    client.remove_departed(&1u64, &alice);
+    assert_eq!(client.current_root(&1u64), root);
+    assert_eq!(
+        client.pending_changes(&1u64),
+        soroban_sdk::vec![
+            &env,
+            PendingChange::Register(GENERAL_GROUP, U256::from_u32(&env, 6), bob.clone()),
+            PendingChange::Depart(alice.clone()),
+        ]
+    );
+
+    assert_eq!(client.apply_pending(&1u64, &2u32), 0);
+    assert_eq!(client.get_leaf_index(&1u64, &U256::from_u32(&env, 6)), 1);
+    assert_eq!(
+        client.try_remove_member(&1u64, &alice, &admin),
+        Err(Ok(TreeError::MemberRemoved.into()))
+    );
+}
+
+#[test]
+fn test_recovery_refused_while_a_queued_change_names_the_member() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let (voting_id, voting_client) = setup_voting(&env, &tree_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+    client.set_membership_freeze(&1u64, &Some(voting_id), &admin);
+
+    let alice = Address::generate(&env);
+    sbt_client.set_member(&1u64, &alice, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 5), &alice);
+
+    // A removal queued mid-vote would otherwise miss the recovered address
+    voting_client.set_active(&1u64, &true);
+    client.remove_member(&1u64, &alice, &admin);
+    assert_eq!(
+        client.pending_changes(&1u64),
+        soroban_sdk::vec![&env, PendingChange::Remove(alice.clone())]
+    );
+    let new_wallet = Address::generate(&env);
+    assert_eq!(
+        client.try_recover_from_sbt(&1u64, &alice, &new_wallet),
+        Err(Ok(TreeError::MembershipFrozen.into()))
+    );
+
+    // Once the queue is applied the leaf is zeroed and recovery goes through
+    voting_client.set_active(&1u64, &false);
+    assert_eq!(client.apply_pending(&1u64, &1u32), 0);
+    client.recover_from_sbt(&1u64, &alice, &new_wallet);
+    assert_eq!(
+        client.try_remove_member(&1u64, &new_wallet, &admin),
+        Err(Ok(TreeError::MemberRemoved.into()))
+    );
+}
+
+#[test]
+fn test_removal_delay_schedules_cancellable_removals() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+    env.ledger().set_timestamp(1_000);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    sbt_client.set_member(&1u64, &alice, &true);
+    sbt_client.set_member(&1u64, &bob, &true);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 5), &alice);
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 6), &bob);
+    assert_eq!(client.removal_delay(&1u64), 0);
+
+    client.set_removal_delay(&1u64, &600u64, &admin);
+    let root = client.current_root(&1u64);
+    client.remove_member(&1u64, &alice, &admin);
+    assert_eq!(client.current_root(&1u64), root);
+    assert_eq!(
+        client.scheduled_removals(&1u64).get(alice.clone()),
+        Some(1_600)
+    );
+    assert_eq!(
+        client.try_remove_member(&1u64, &alice, &admin),
+        Err(Ok(TreeError::RemovalScheduled.into()))
+    );
+    assert_eq!(
+        client.try_remove_member(&1u64, &Address::generate(&env), &admin),
+        Err(Ok(TreeError::MemberNotInTree.into()))
+    );
+
+    // Anyone can execute, but only after the delay
+    env.ledger().set_timestamp(1_599);
+    assert_eq!(
+        client.try_execute_removal(&1u64, &alice),
+        Err(Ok(TreeError::RemovalNotDue.into()))
+    );
+    env.ledger().set_timestamp(1_600);
+    client.execute_removal(&1u64, &alice);
+    assert_ne!(client.current_root(&1u64), root);
+    assert!(!sbt_client.has(&1u64, &alice));
+    assert_eq!(client.scheduled_removals(&1u64).len(), 0);
+    assert_eq!(
+        client.try_execute_removal(&1u64, &alice),
+        Err(Ok(TreeError::RemovalNotScheduled.into()))
+    );
+
+    // Cancelled removals never apply
+    client.remove_member(&1u64, &bob, &admin);
+    client.cancel_removal(&1u64, &bob, &admin);
+    assert_eq!(
+        client.try_cancel_removal(&1u64, &bob, &admin),
+        Err(Ok(TreeError::RemovalNotScheduled.into()))
+    );
+    env.ledger().set_timestamp(5_000);
+    assert_eq!(
+        client.try_execute_removal(&1u64, &bob),
+        Err(Ok(TreeError::RemovalNotScheduled.into()))
+    );
+
+    // Lowering the delay only applies once the current delay has passed
+    client.set_removal_delay(&1u64, &0u64, &admin);
+    assert_eq!(client.removal_delay(&1u64), 600);
+    env.ledger().set_timestamp(5_600);
+    assert_eq!(client.removal_delay(&1u64), 0);
+    client.set_removal_delay(&1u64, &60u64, &admin);
+    assert_eq!(client.removal_delay(&1u64), 60);
+
+    // The emergency override replaces the scheduled removal
+    client.remove_member(&1u64, &bob, &admin);
+    client.emergency_remove(&1u64, &bob, &admin);
+    assert!(!sbt_client.has(&1u64, &bob));
+    assert_eq!(client.scheduled_removals(&1u64).len(), 0);
+}
+
+#[test]
+fn test_removal_delay_schedules_group_removals() {
+    let (env, tree_id, sbt_id, registry_id, admin) = setup_env();
+    let client = MembershipTreeClient::new(&env, &tree_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+
+    registry_client.set_admin(&1u64, &admin);
+    client.init_tree(&1u64, &3u32, &admin);
+    let council = 1u32;
+    client.create_group(&1u64, &council, &2u32, &admin);
+    env.ledger().set_timestamp(1_000);
+    client.set_removal_delay(&1u64, &600u64, &admin);
+
+    let [alice, bob, carol] = [0; 3].map(|_| Address::generate(&env));
+    for (i, member) in [&alice, &bob].into_iter().enumerate() {
+        sbt_client.set_member(&1u64, member, &true);
+        client.add_group_member(&1u64, &council, member, &admin);
+        client.register_in_group(&1u64, &council, &U256::from_u32(&env, 5 + i as u32), member);
+    }
+
+    // A group removal is only scheduled; the member keeps their leaf until it executes
+    let root = client.group_root(&1u64, &council);
+    client.remove_from_group(&1u64, &council, &alice, &admin);
+    assert_eq!(client.group_root(&1u64, &council), root);
+    assert!(client.is_group_member(&1u64, &council, &alice));
+    assert_eq!(
+        client
+            .scheduled_group_removals(&1u64, &council)
+            .get(alice.clone()),
+        Some(1_600)
+    );
+    assert_eq!(client.scheduled_removals(&1u64).len(), 0);
+    assert_eq!(
+        client.try_remove_from_group(&1u64, &council, &alice, &admin),
+        Err(Ok(TreeError::RemovalScheduled.into()))
+    );
+
+    // Eligibility of a member who never registered is withdrawn at once
+    client.add_group_member(&1u64, &council, &carol, &admin);
+    client.remove_from_group(&1u64, &council, &carol, &admin);
+    assert!(!client.is_group_member(&1u64, &council, &carol));
+
+    env.ledger().set_timestamp(1_599);
+    assert_eq!(
+        client.try_execute_group_removal(&1u64, &council, &alice),
+        Err(Ok(TreeError::RemovalNotDue.into()))
+    );
+    env.ledger().set_timestamp(1_600);
+    client.execute_group_removal(&1u64, &council, &alice);
+    assert_ne!(client.group_root(&1u64, &council), root);
+    assert!(!client.is_group_member(&1u64, &council, &alice));
+    assert!(sbt_client.has(&1u64, &alice));
+
+    // Cancelled group removals never apply, and removing the member from the DAO drops them
+    client.remove_from_group(&1u64, &council, &bob, &admin);
+    client.cancel_group_removal(&1u64, &council, &bob, &admin);
+    assert_eq!(
+        client.try_execute_group_removal(&1u64, &council, &bob),
+        Err(Ok(TreeError::RemovalNotScheduled.into()))
+    );
+    client.register_with_caller(&1u64, &U256::from_u32(&env, 9), &bob);
+    client.remove_from_group(&1u64, &council, &bob, &admin);
+    client.emergency_remove(&1u64, &bob, &admin);
+    assert_eq!(client.scheduled_group_removals(&1u64, &council).len(), 0);
+}
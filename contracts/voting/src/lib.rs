@@ -31,8 +31,8 @@
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype,
     crypto::bn254::{Fr, G1Affine, G2Affine},
-    panic_with_error, symbol_short, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
-    U256,
+    panic_with_error, symbol_short, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol,
+    TryFromVal, Val, Vec, U256,
 };
 
 // Re-export shared Groth16 types and utilities
@@ -77,6 +77,12 @@ pub enum VotingError {
     SignalNotInField = 25,
     /// Nullifier is zero (invalid)
     InvalidNullifier = 26,
+    /// Creator is not in the proposal's member group (and is not the DAO admin)
+    NotGroupMember = 27,
+    /// Proposals of a DAO with a membership freeze need an end time
+    FreezeNeedsEndTime = 28,
+    /// The DAO already has `MAX_OPEN_PROPOSALS` proposals freezing its membership
+    TooManyOpenProposals = 29,
 }
 
 // Maximum allowed IC vector length (num_public_inputs + 1)
@@ -88,11 +94,24 @@ const MAX_IC_LENGTH: u32 = 21;
 const MAX_TITLE_LEN: u32 = 100; // Max proposal title length (100 bytes)
 const MAX_CID_LEN: u32 = 64; // Max IPFS CID length (CIDv1 is ~59 chars)
 
+// Membership freeze bounds (see `has_active_proposal`)
+const MAX_FREEZE_WINDOW: u64 = 30 * 24 * 60 * 60; // A proposal freezes membership for at most 30 days
+const MAX_OPEN_PROPOSALS: u32 = 20; // Concurrent proposals freezing one DAO's membership
+
 // Circuit constants
 /// Vote circuit public signals: nullifier, root, dao_id, proposal_id, vote_choice
 const NUM_PUBLIC_SIGNALS: u32 = 5;
 // IC (inner commitment) vector length for Groth16 VK = num_public_inputs + 1
 const VOTE_CIRCUIT_IC_LEN: u32 = NUM_PUBLIC_SIGNALS + 1;
+// Expiring-tree circuit (vote_expiry.circom) adds the proposal's creation time as a
+// sixth public signal, checked in-circuit against the expiry baked into the leaf
+const EXPIRY_CIRCUIT_IC_LEN: u32 = VOTE_CIRCUIT_IC_LEN + 1;
+// Strict-revocation circuits (vote_strict.circom) append the DAO's revocation root as the
+// last public signal, proving the voter's commitment is absent from the revocation tree
+const STRICT_SIGNALS: u32 = 1;
+
+/// Tree group holding the DAO's general membership (see membership-tree `GENERAL_GROUP`)
+pub const GENERAL_GROUP: u32 = 0;
 
 #[contracttype]
 #[derive(Clone)]
@@ -103,7 +122,10 @@ pub enum DataKey {
     VotingKey(u64),            // dao_id -> latest VerificationKey
     VkVersion(u64),            // dao_id -> current VK version
     VkByVersion(u64, u32),     // (dao_id, vk_version) -> VerificationKey
+    DepthVk(u64, u32),         // (dao_id, tree_depth) -> vk_version compiled for that depth
+    GenericVk(u64),            // dao_id -> newest vk_version not registered for a tree depth
     VerifyOverride,            // Test-only: force verify_groth16 result (unused in prod)
+    OpenProposals(u64), // dao_id -> Map<proposal_id, frozen_until> of proposals freezing membership
 }
 
 #[contracttype]
@@ -139,6 +161,8 @@ pub struct ProposalInfo {
     pub eligible_root: U256, // Merkle root at creation - defines eligible voter set
     pub vote_mode: VoteMode, // Fixed or Trailing voting
     pub earliest_root_index: u32, // For Trailing mode: earliest valid root index
+    pub group_id: u32, // Member group whose tree defines eligibility (GENERAL_GROUP = all members)
+    pub strict_revocation: bool, // Votes also prove non-revocation against the tree's revocation root
 }
 
 // Typed Events
@@ -308,10 +332,63 @@ impl Voting {
         env.storage()
             .persistent()
             .set(&DataKey::VkByVersion(dao_id, new_version), &vk);
+        env.storage()
+            .persistent()
+            .set(&DataKey::GenericVk(dao_id), &new_version);
+
+        VKSetEvent { dao_id }.publish(&env);
+    }
+
+    /// Set verification key for a specific Merkle tree depth (admin only)
+    /// Circuits are compiled for a fixed tree depth, so a DAO must register a VK for
+    /// the new depth before growing its tree (see membership-tree `grow_tree`).
+    /// The VK becomes the latest version; proposals created while the tree has this
+    /// depth default to it.
+    pub fn set_depth_vk(env: Env, dao_id: u64, depth: u32, vk: VerificationKey, admin: Address) {
+        admin.require_auth();
+        Self::assert_admin(&env, dao_id, &admin);
+        Self::validate_vk(&env, &vk);
+
+        let new_version = Self::bump_vk_version(&env, dao_id);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::VotingKey(dao_id), &vk);
+        env.storage()
+            .persistent()
+            .set(&DataKey::VkByVersion(dao_id, new_version), &vk);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DepthVk(dao_id, depth), &new_version);
 
         VKSetEvent { dao_id }.publish(&env);
     }
 
+    /// Get the VK version registered for a tree depth (None if no depth-specific VK)
+    pub fn vk_for_depth(env: Env, dao_id: u64, depth: u32) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DepthVk(dao_id, depth))
+    }
+
+    /// Get the IC length of the VK registered for a tree depth (None if no depth-specific VK)
+    /// The tree checks it against the DAO's circuit variant before growing to that depth.
+    pub fn depth_vk_ic_len(env: Env, dao_id: u64, depth: u32) -> Option<u32> {
+        let version = Self::vk_for_depth(env.clone(), dao_id, depth)?;
+        Some(Self::get_vk_by_version(&env, dao_id, version).ic.len())
+    }
+
+    /// Internal: Pick the default VK version for a tree depth
+    /// Prefers the VK registered for that depth, otherwise the newest VK that was not
+    /// registered for any depth.
+    fn default_vk_version(env: &Env, dao_id: u64, depth: u32, current_version: u32) -> u32 {
+        let storage = env.storage().persistent();
+        storage
+            .get(&DataKey::DepthVk(dao_id, depth))
+            .or_else(|| storage.get(&DataKey::GenericVk(dao_id)))
+            .unwrap_or(current_version)
+    }
+
     /// Internal helper to fetch a VK by version or fail with a clear error
     fn get_vk_by_version(env: &Env, dao_id: u64, version: u32) -> VerificationKey {
         env.storage()
@@ -335,8 +412,58 @@ impl Voting {
         }
     }
 
+    /// Admin check that also admits SBT holders with the role the registry assigns to `action`
+    fn assert_admin_or_role(env: &Env, dao_id: u64, caller: &Address, action: Symbol) {
+        let registry: Address = env.storage().instance().get(&REGISTRY).unwrap();
+
+        let dao_admin: Address = env.invoke_contract(
+            &registry,
+            &symbol_short!("get_admin"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        );
+
+        if &dao_admin != caller && !Self::has_action_role(env, &registry, dao_id, caller, action) {
+            panic_with_error!(env, VotingError::NotAdmin);
+        }
+    }
+
+    fn has_action_role(
+        env: &Env,
+        registry: &Address,
+        dao_id: u64,
+        member: &Address,
+        action: Symbol,
+    ) -> bool {
+        let min_role: Option<u32> = env.invoke_contract(
+            registry,
+            &Symbol::new(env, "action_role"),
+            soroban_sdk::vec![env, dao_id.into_val(env), action.into_val(env)],
+        );
+        let Some(min_role) = min_role else {
+            return false;
+        };
+
+        let tree_contract: Address = Self::tree_contract(env.clone());
+        let sbt_contract: Address = env.invoke_contract(
+            &tree_contract,
+            &symbol_short!("sbt_contr"),
+            soroban_sdk::vec![env],
+        );
+        env.invoke_contract(
+            &sbt_contract,
+            &symbol_short!("has_role"),
+            soroban_sdk::vec![
+                env,
+                dao_id.into_val(env),
+                member.clone().into_val(env),
+                min_role.into_val(env),
+            ],
+        )
+    }
+
     fn validate_vk(env: &Env, vk: &VerificationKey) {
-        if vk.ic.len() != VOTE_CIRCUIT_IC_LEN {
+        if vk.ic.len() < VOTE_CIRCUIT_IC_LEN || vk.ic.len() > EXPIRY_CIRCUIT_IC_LEN + STRICT_SIGNALS
+        {
             panic_with_error!(env, VotingError::VkIcLengthMismatch);
         }
         if vk.ic.len() > MAX_IC_LENGTH {
@@ -366,6 +493,9 @@ impl Voting {
         env.storage()
             .persistent()
             .set(&DataKey::VkByVersion(dao_id, new_version), &vk);
+        env.storage()
+            .persistent()
+            .set(&DataKey::GenericVk(dao_id), &new_version);
 
         VKSetEvent { dao_id }.publish(&env);
     }
@@ -387,6 +517,34 @@ impl Voting {
         Self::create_proposal_with_version(
             env,
             dao_id,
+            GENERAL_GROUP,
+            title,
+            content_cid,
+            end_time,
+            creator,
+            vote_mode,
+            None,
+        )
+    }
+
+    /// Create a proposal restricted to one member group (e.g. a council)
+    /// The group's current root is snapshotted, so only members registered in that
+    /// group's tree can vote. The creator must belong to the group or be the DAO admin.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_group_proposal(
+        env: Env,
+        dao_id: u64,
+        group_id: u32,
+        title: String,
+        content_cid: String,
+        end_time: u64,
+        creator: Address,
+        vote_mode: VoteMode,
+    ) -> u64 {
+        Self::create_proposal_with_version(
+            env,
+            dao_id,
+            group_id,
             title,
             content_cid,
             end_time,
@@ -410,6 +568,7 @@ impl Voting {
         Self::create_proposal_with_version(
             env,
             dao_id,
+            GENERAL_GROUP,
             title,
             content_cid,
             end_time,
@@ -419,9 +578,11 @@ impl Voting {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn create_proposal_with_version(
         env: Env,
         dao_id: u64,
+        group_id: u32,
         title: String,
         content_cid: String,
         end_time: u64,
@@ -478,7 +639,7 @@ impl Voting {
             soroban_sdk::vec![&env, dao_id.into_val(&env)],
         );
 
-        // If members cannot propose, only admin can create proposals
+        // If members cannot propose, only admin (or a holder of the propose role) can
         if !members_can_propose {
             let dao_admin: Address = env.invoke_contract(
                 &registry,
@@ -486,11 +647,41 @@ impl Voting {
                 soroban_sdk::vec![&env, dao_id.into_val(&env)],
             );
 
-            if creator != dao_admin {
+            if creator != dao_admin
+                && !Self::has_action_role(
+                    &env,
+                    &registry,
+                    dao_id,
+                    &creator,
+                    symbol_short!("propose"),
+                )
+            {
                 panic_with_error!(&env, VotingError::OnlyAdminCanPropose);
             }
         }
 
+        // Group proposals can only be raised from inside the group (or by the admin)
+        if group_id != GENERAL_GROUP {
+            let in_group: bool = env.invoke_contract(
+                &tree_contract,
+                &Symbol::new(&env, "is_group_member"),
+                soroban_sdk::vec![
+                    &env,
+                    dao_id.into_val(&env),
+                    group_id.into_val(&env),
+                    creator.clone().into_val(&env),
+                ],
+            );
+            let dao_admin: Address = env.invoke_contract(
+                &registry,
+                &symbol_short!("get_admin"),
+                soroban_sdk::vec![&env, dao_id.into_val(&env)],
+            );
+            if !in_group && creator != dao_admin {
+                panic_with_error!(&env, VotingError::NotGroupMember);
+            }
+        }
+
         let now = env.ledger().timestamp();
 
         // Validate end_time: 0 = no deadline, otherwise must be in the future
@@ -498,13 +689,38 @@ impl Voting {
             panic_with_error!(&env, VotingError::EndTimeInvalid);
         }
 
+        // Only proposals of a DAO whose tree defers to this contract freeze membership
+        let freeze: Option<Address> = env.invoke_contract(
+            &tree_contract,
+            &Symbol::new(&env, "membership_freeze"),
+            soroban_sdk::vec![&env, dao_id.into_val(&env)],
+        );
+        let freezes = freeze == Some(env.current_contract_address());
+        if freezes && end_time == 0 {
+            panic_with_error!(&env, VotingError::FreezeNeedsEndTime);
+        }
+
         // Resolve VK version to use
         let current_version: u32 = env
             .storage()
             .persistent()
             .get(&DataKey::VkVersion(dao_id))
             .unwrap_or_else(|| panic_with_error!(&env, VotingError::VkNotSet));
-        let selected_version = vk_version.unwrap_or(current_version);
+        let selected_version = match vk_version {
+            Some(version) => version,
+            None => {
+                // Default to a VK compiled for the tree's current depth
+                let depth: u32 = Self::tree_query(
+                    &env,
+                    dao_id,
+                    group_id,
+                    symbol_short!("get_depth"),
+                    "group_depth",
+                    None,
+                );
+                Self::default_vk_version(&env, dao_id, depth, current_version)
+            }
+        };
         if selected_version == 0 || selected_version > current_version {
             panic_with_error!(&env, VotingError::VkNotSet);
         }
@@ -512,21 +728,53 @@ impl Voting {
         // Snapshot VK by version
         let vk = Self::get_vk_by_version(&env, dao_id, selected_version);
 
+        // Trees with expiring leaves need the circuit that checks expiry; the plain
+        // circuit would accept Poseidon(commitment, expiry) as a bare commitment
+        let expiring: bool = env.invoke_contract(
+            &tree_contract,
+            &Symbol::new(&env, "expiring"),
+            soroban_sdk::vec![&env, dao_id.into_val(&env)],
+        );
+        let mut expected_ic_len = if expiring {
+            EXPIRY_CIRCUIT_IC_LEN
+        } else {
+            VOTE_CIRCUIT_IC_LEN
+        };
+        // Strict revocation needs the circuit with the non-revocation proof; proposals
+        // created before it was enabled keep the Fixed-mode snapshot semantics
+        let strict_revocation: bool = env.invoke_contract(
+            &tree_contract,
+            &Symbol::new(&env, "strict_revocation"),
+            soroban_sdk::vec![&env, dao_id.into_val(&env)],
+        );
+        if strict_revocation {
+            expected_ic_len += STRICT_SIGNALS;
+        }
+        if vk.ic.len() != expected_ic_len {
+            panic_with_error!(&env, VotingError::VkIcLengthMismatch);
+        }
+
         // Compute VK hash for immutability during proposal lifetime
         let vk_hash = Self::hash_vk(&env, &vk);
 
         // Snapshot current Merkle root - defines the eligible voter set
-        let eligible_root: U256 = env.invoke_contract(
-            &tree_contract,
-            &symbol_short!("get_root"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
+        let eligible_root: U256 = Self::tree_query(
+            &env,
+            dao_id,
+            group_id,
+            symbol_short!("get_root"),
+            "group_root",
+            None,
         );
 
         // Get current root index for Open mode validation
-        let earliest_root_index: u32 = env.invoke_contract(
-            &tree_contract,
-            &symbol_short!("curr_idx"),
-            soroban_sdk::vec![&env, dao_id.into_val(&env)],
+        let earliest_root_index: u32 = Self::tree_query(
+            &env,
+            dao_id,
+            group_id,
+            symbol_short!("curr_idx"),
+            "group_curr_idx",
+            None,
         );
 
         let proposal_id = Self::next_proposal_id(&env, dao_id);
@@ -547,10 +795,16 @@ impl Voting {
             eligible_root,
             vote_mode,
             earliest_root_index,
+            group_id,
+            strict_revocation,
         };
 
         let key = DataKey::Proposal(dao_id, proposal_id);
         env.storage().persistent().set(&key, &proposal);
+        if freezes {
+            let frozen_until = end_time.min(now.saturating_add(MAX_FREEZE_WINDOW));
+            Self::track_open(&env, dao_id, proposal_id, frozen_until, now);
+        }
 
         ProposalEvent {
             dao_id,
@@ -564,6 +818,70 @@ impl Voting {
         proposal_id
     }
 
+    // Internal: Record a new proposal in the DAO's open set, dropping ones past their deadline
+    fn track_open(env: &Env, dao_id: u64, proposal_id: u64, frozen_until: u64, now: u64) {
+        let key = DataKey::OpenProposals(dao_id);
+        let open: Map<u64, u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(env));
+        let mut live = Map::new(env);
+        for (id, end) in open.iter() {
+            if end >= now {
+                live.set(id, end);
+            }
+        }
+        if live.len() >= MAX_OPEN_PROPOSALS {
+            panic_with_error!(env, VotingError::TooManyOpenProposals);
+        }
+        live.set(proposal_id, frozen_until);
+        env.storage().persistent().set(&key, &live);
+    }
+
+    // Internal: Start applying the tree's queued membership changes once voting has ended
+    // Skipped when the tree's freeze no longer points here, since the tree would refuse.
+    fn release_membership(env: &Env, dao_id: u64) {
+        let tree_contract: Address = Self::tree_contract(env.clone());
+        let freeze: Option<Address> = env.invoke_contract(
+            &tree_contract,
+            &Symbol::new(env, "membership_freeze"),
+            soroban_sdk::vec![env, dao_id.into_val(env)],
+        );
+        if freeze == Some(env.current_contract_address()) {
+            env.invoke_contract::<u32>(
+                &tree_contract,
+                &Symbol::new(env, "release_pending"),
+                soroban_sdk::vec![env, dao_id.into_val(env)],
+            );
+        }
+    }
+
+    // Internal: Call a tree view for a member group
+    // The general group uses the original single-tree entrypoints (`get_root`, ...),
+    // other groups the `group_*` variants that take the group id after the DAO id.
+    fn tree_query<T: TryFromVal<Env, Val>>(
+        env: &Env,
+        dao_id: u64,
+        group_id: u32,
+        general_fn: Symbol,
+        group_fn: &str,
+        extra: Option<Val>,
+    ) -> T {
+        let tree_contract: Address = Self::tree_contract(env.clone());
+        let mut args: Vec<Val> = soroban_sdk::vec![env, dao_id.into_val(env)];
+        let func = if group_id == GENERAL_GROUP {
+            general_fn
+        } else {
+            args.push_back(group_id.into_val(env));
+            Symbol::new(env, group_fn)
+        };
+        if let Some(arg) = extra {
+            args.push_back(arg);
+        }
+        env.invoke_contract(&tree_contract, &func, args)
+    }
+
     /// Compute SHA256 hash of verification key for immutability tracking
     fn hash_vk(env: &Env, vk: &VerificationKey) -> BytesN<32> {
         // Serialize VK components into bytes
@@ -652,24 +970,28 @@ impl Voting {
                 // AND not predate the most recent member removal
                 // This allows new members to vote while preventing removed members from using old roots
 
-                // Get tree contract address
-                let tree_contract: Address = Self::tree_contract(env.clone());
-
-                // Check root is in valid history
-                let root_valid: bool = env.invoke_contract(
-                    &tree_contract,
-                    &symbol_short!("root_ok"),
-                    soroban_sdk::vec![&env, dao_id.into_val(&env), root.clone().into_val(&env)],
+                // Check root is in valid history of the proposal's group
+                let group_id = proposal.group_id;
+                let root_valid: bool = Self::tree_query(
+                    &env,
+                    dao_id,
+                    group_id,
+                    symbol_short!("root_ok"),
+                    "group_root_ok",
+                    Some(root.clone().into_val(&env)),
                 );
                 if !root_valid {
                     panic_with_error!(&env, VotingError::RootNotInHistory);
                 }
 
                 // Check root index >= earliest_root_index (prevents using roots from before proposal)
-                let root_index: u32 = env.invoke_contract(
-                    &tree_contract,
-                    &symbol_short!("root_idx"),
-                    soroban_sdk::vec![&env, dao_id.into_val(&env), root.clone().into_val(&env)],
+                let root_index: u32 = Self::tree_query(
+                    &env,
+                    dao_id,
+                    group_id,
+                    symbol_short!("root_idx"),
+                    "group_root_idx",
+                    Some(root.clone().into_val(&env)),
                 );
                 if root_index < proposal.earliest_root_index {
                     panic_with_error!(&env, VotingError::RootPredatesProposal);
@@ -677,10 +999,13 @@ impl Voting {
 
                 // Check root index >= min_valid_root_index (prevents using roots from before member removal)
                 // This ensures revoked members cannot vote even on old proposals using their pre-revocation proofs
-                let min_valid_root: u32 = env.invoke_contract(
-                    &tree_contract,
-                    &symbol_short!("min_root"),
-                    soroban_sdk::vec![&env, dao_id.into_val(&env)],
+                let min_valid_root: u32 = Self::tree_query(
+                    &env,
+                    dao_id,
+                    group_id,
+                    symbol_short!("min_root"),
+                    "group_min_root",
+                    None,
                 );
                 if root_index < min_valid_root {
                     panic_with_error!(&env, VotingError::RootPredatesRemoval);
@@ -710,7 +1035,7 @@ impl Voting {
         let dao_signal = U256::from_u128(&env, dao_id as u128);
         let proposal_signal = U256::from_u128(&env, proposal_id as u128);
 
-        let pub_signals = soroban_sdk::vec![
+        let mut pub_signals = soroban_sdk::vec![
             &env,
             root.clone(),
             nullifier.clone(),
@@ -718,6 +1043,25 @@ impl Voting {
             proposal_signal,
             vote_signal
         ];
+        // Expiring trees: leaves must not have lapsed when the proposal was created
+        let strict_signals = if proposal.strict_revocation {
+            STRICT_SIGNALS
+        } else {
+            0
+        };
+        if vk.ic.len() == EXPIRY_CIRCUIT_IC_LEN + strict_signals {
+            pub_signals.push_back(U256::from_u128(&env, proposal.created_at as u128));
+        }
+        // Strict revocation: the commitment must be absent from the revocation tree now,
+        // so members removed after the snapshot can no longer vote
+        if proposal.strict_revocation {
+            let revocation_root: U256 = env.invoke_contract(
+                &Self::tree_contract(env.clone()),
+                &Symbol::new(&env, "revocation_root"),
+                soroban_sdk::vec![&env, dao_id.into_val(&env)],
+            );
+            pub_signals.push_back(revocation_root);
+        }
 
         if !Self::verify_groth16(&env, &vk, &proof, &pub_signals) {
             panic_with_error!(&env, VotingError::InvalidProof);
@@ -767,6 +1111,26 @@ impl Voting {
         proposal.eligible_root
     }
 
+    /// Get the member group a proposal is restricted to (GENERAL_GROUP for all members)
+    /// Used by comments contract to check Trailing mode roots against the right tree
+    pub fn get_group(env: Env, dao_id: u64, proposal_id: u64) -> u32 {
+        let proposal = Self::get_proposal(env, dao_id, proposal_id);
+        proposal.group_id
+    }
+
+    /// Get a proposal's creation time (the expiry snapshot for expiring trees)
+    pub fn get_created_at(env: Env, dao_id: u64, proposal_id: u64) -> u64 {
+        let proposal = Self::get_proposal(env, dao_id, proposal_id);
+        proposal.created_at
+    }
+
+    /// Check whether a proposal's votes must prove non-revocation
+    /// Used by comments contract to build the same public signals as `vote`
+    pub fn get_strict(env: Env, dao_id: u64, proposal_id: u64) -> bool {
+        let proposal = Self::get_proposal(env, dao_id, proposal_id);
+        proposal.strict_revocation
+    }
+
     /// Get earliest root index for a proposal (for Trailing mode)
     /// Used by comments contract for Trailing mode eligibility checks
     pub fn get_earliest_idx(env: Env, dao_id: u64, proposal_id: u64) -> u32 {
@@ -774,6 +1138,18 @@ impl Voting {
         proposal.earliest_root_index
     }
 
+    /// Check whether any proposal of the DAO still freezes its membership
+    /// Only proposals created while the tree's freeze points at this contract count, and
+    /// each until it is closed or its end time passes, capped at `MAX_FREEZE_WINDOW`
+    /// after creation. The membership tree queries this to freeze the electorate during votes.
+    pub fn has_active_proposal(env: Env, dao_id: u64) -> bool {
+        let now = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .get::<DataKey, Map<u64, u64>>(&DataKey::OpenProposals(dao_id))
+            .is_some_and(|open| open.values().iter().any(|end| now <= end))
+    }
+
     /// Get proposal count for a DAO
     pub fn proposal_count(env: Env, dao_id: u64) -> u64 {
         env.storage()
@@ -811,9 +1187,12 @@ impl Voting {
     }
 
     /// Close a proposal explicitly (idempotent). End time still enforced in vote.
+    /// Open to the admin and to SBT holders of the DAO's `close` action role.
+    /// Closing the DAO's last proposal that freezes its membership has the tree apply the
+    /// first of the membership changes queued meanwhile (see membership-tree `release_pending`).
     pub fn close_proposal(env: Env, dao_id: u64, proposal_id: u64, admin: Address) {
         admin.require_auth();
-        Self::assert_admin(&env, dao_id, &admin);
+        Self::assert_admin_or_role(&env, dao_id, &admin, symbol_short!("close"));
         let key = DataKey::Proposal(dao_id, proposal_id);
         let mut proposal: ProposalInfo = env
             .storage()
@@ -827,6 +1206,22 @@ impl Voting {
         if proposal.state != ProposalState::Closed {
             proposal.state = ProposalState::Closed;
             env.storage().persistent().set(&key, &proposal);
+
+            let open_key = DataKey::OpenProposals(dao_id);
+            if let Some(mut open) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Map<u64, u64>>(&open_key)
+            {
+                if open.contains_key(proposal_id) {
+                    open.remove(proposal_id);
+                    env.storage().persistent().set(&open_key, &open);
+                    // The last freezing proposal is gone: apply what the freeze held back
+                    if !Self::has_active_proposal(env.clone(), dao_id) {
+                        Self::release_membership(&env, dao_id);
+                    }
+                }
+            }
             ProposalClosedEvent {
                 dao_id,
                 proposal_id,
@@ -839,7 +1234,7 @@ impl Voting {
     /// Archive a proposal (idempotent). Prevents further votes and signals off-chain cleanup.
     pub fn archive_proposal(env: Env, dao_id: u64, proposal_id: u64, admin: Address) {
         admin.require_auth();
-        Self::assert_admin(&env, dao_id, &admin);
+        Self::assert_admin_or_role(&env, dao_id, &admin, symbol_short!("close"));
         let key = DataKey::Proposal(dao_id, proposal_id);
         let mut proposal: ProposalInfo = env
             .storage()
@@ -889,6 +1284,13 @@ impl Voting {
         Self::get_vk_by_version(&env, dao_id, version)
     }
 
+    /// Get the VK a proposal's votes are verified against (pinned at creation)
+    /// Used by comments contract, so anonymous comments survive VK rotations like votes do
+    pub fn get_proposal_vk(env: Env, dao_id: u64, proposal_id: u64) -> VerificationKey {
+        let proposal = Self::get_proposal(env.clone(), dao_id, proposal_id);
+        Self::get_vk_by_version(&env, dao_id, proposal.vk_version)
+    }
+
     /// Get a specific VK version for observability/off-chain verification
     pub fn vk_for_version(env: Env, dao_id: u64, version: u32) -> VerificationKey {
         Self::get_vk_by_version(&env, dao_id, version)
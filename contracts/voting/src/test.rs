@@ -11,6 +11,11 @@ mod mock_tree {
     pub enum DataKey {
         SbtContract,
         CurrentRoot(u64),
+        Expiring(u64),
+        Strict(u64),
+        RevocationRoot(u64),
+        Freeze(u64),
+        Released(u64),
     }
 
     #[contract]
@@ -42,6 +47,74 @@ mod mock_tree {
                 .unwrap_or(U256::from_u32(&env, 0))
         }
 
+        pub fn set_expiring(env: Env, dao_id: u64, expiring: bool) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Expiring(dao_id), &expiring);
+        }
+
+        pub fn expiring(env: Env, dao_id: u64) -> bool {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Expiring(dao_id))
+                .unwrap_or(false)
+        }
+
+        pub fn set_strict(env: Env, dao_id: u64, strict: bool) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Strict(dao_id), &strict);
+        }
+
+        pub fn strict_revocation(env: Env, dao_id: u64) -> bool {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Strict(dao_id))
+                .unwrap_or(false)
+        }
+
+        pub fn set_freeze(env: Env, dao_id: u64, voting: Address) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Freeze(dao_id), &voting);
+        }
+
+        pub fn membership_freeze(env: Env, dao_id: u64) -> Option<Address> {
+            env.storage().persistent().get(&DataKey::Freeze(dao_id))
+        }
+
+        pub fn release_pending(env: Env, dao_id: u64) -> u32 {
+            let key = DataKey::Released(dao_id);
+            let released: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage().persistent().set(&key, &(released + 1));
+            0
+        }
+
+        pub fn released(env: Env, dao_id: u64) -> u32 {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Released(dao_id))
+                .unwrap_or(0)
+        }
+
+        pub fn set_revocation_root(env: Env, dao_id: u64, root: U256) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::RevocationRoot(dao_id), &root);
+        }
+
+        pub fn revocation_root(env: Env, dao_id: u64) -> U256 {
+            env.storage()
+                .persistent()
+                .get(&DataKey::RevocationRoot(dao_id))
+                .unwrap_or(U256::from_u32(&env, 0))
+        }
+
+        pub fn get_depth(_env: Env, _dao_id: u64) -> u32 {
+            // Mock implementation: default circuit depth
+            18
+        }
+
         pub fn curr_idx(_env: Env, _dao_id: u64) -> u32 {
             // Mock implementation: return index 0 for current root
             // Real contract tracks root history, mock doesn't need to
@@ -64,13 +137,14 @@ mod mock_tree {
 
 // Mock Registry contract
 mod mock_registry {
-    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
 
     #[contracttype]
     pub enum DataKey {
         Admin(u64),
         MembershipOpen(u64),
         MembersCanPropose(u64),
+        ActionRole(u64, Symbol),
     }
 
     #[contract]
@@ -116,6 +190,18 @@ mod mock_registry {
                 .get(&DataKey::MembersCanPropose(dao_id))
                 .unwrap_or(true) // Default to true so existing tests pass
         }
+
+        pub fn set_action_role(env: Env, dao_id: u64, action: Symbol, min_role: u32) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ActionRole(dao_id, action), &min_role);
+        }
+
+        pub fn action_role(env: Env, dao_id: u64, action: Symbol) -> Option<u32> {
+            env.storage()
+                .persistent()
+                .get(&DataKey::ActionRole(dao_id, action))
+        }
     }
 }
 
@@ -128,6 +214,7 @@ mod mock_sbt {
     #[contracttype]
     pub enum DataKey {
         Member(u64, Address),
+        Role(u64, Address),
     }
 
     #[contract]
@@ -152,6 +239,20 @@ mod mock_sbt {
             let key = DataKey::Member(dao_id, of);
             env.storage().persistent().get(&key).unwrap_or(false)
         }
+
+        pub fn set_role(env: Env, dao_id: u64, member: Address, role: u32) {
+            let key = DataKey::Role(dao_id, member);
+            env.storage().persistent().set(&key, &role);
+        }
+
+        pub fn has_role(env: Env, dao_id: u64, member: Address, min_role: u32) -> bool {
+            let role: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Role(dao_id, member.clone()))
+                .unwrap_or(0);
+            Self::has(env, dao_id, member) && role >= min_role
+        }
     }
 }
 
@@ -634,6 +735,123 @@ fn test_close_proposal_emits_event_once() {
     // Event emission is best-effort in this test environment; state closed is the key guard.
 }
 
+#[test]
+fn test_has_active_proposal_tracks_close_and_expiry() {
+    let (env, voting_id, tree_id, sbt_id, registry_id, member) = setup_env_with_registry();
+    let voting_client = VotingClient::new(&env, &voting_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let tree_client = mock_tree::MockTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let admin = Address::generate(&env);
+    let title = String::from_str(&env, "Timed");
+    let cid = String::from_str(&env, "");
+
+    sbt_client.set_member(&1u64, &member, &true);
+    tree_client.set_root(&1u64, &U256::from_u32(&env, 12345));
+    registry_client.set_admin(&1u64, &admin);
+    voting_client.set_vk(&1u64, &create_dummy_vk(&env), &admin);
+    assert!(!voting_client.has_active_proposal(&1u64));
+
+    // Without a freeze set in the tree, proposals are not tracked
+    let now = env.ledger().timestamp();
+    voting_client.create_proposal(
+        &1u64,
+        &title,
+        &cid,
+        &(now + 3600),
+        &member,
+        &VoteMode::Trailing,
+    );
+    assert!(!voting_client.has_active_proposal(&1u64));
+
+    tree_client.set_freeze(&1u64, &voting_id);
+    let timed = voting_client.create_proposal(
+        &1u64,
+        &title,
+        &cid,
+        &(now + 3600),
+        &member,
+        &VoteMode::Trailing,
+    );
+    let open_ended = voting_client.create_proposal(
+        &1u64,
+        &title,
+        &cid,
+        &(now + 365 * 24 * 3600),
+        &member,
+        &VoteMode::Trailing,
+    );
+    assert!(voting_client.has_active_proposal(&1u64));
+    assert!(!voting_client.has_active_proposal(&2u64));
+
+    // Freezing proposals need a deadline
+    assert_eq!(
+        voting_client.try_create_proposal(&1u64, &title, &cid, &0, &member, &VoteMode::Trailing),
+        Err(Ok(VotingError::FreezeNeedsEndTime.into()))
+    );
+
+    // Closing a proposal early lifts its freeze; the queue waits for the other one
+    voting_client.close_proposal(&1u64, &open_ended, &admin);
+    assert!(voting_client.has_active_proposal(&1u64));
+    assert_eq!(tree_client.released(&1u64), 0);
+
+    // The last vote can still be cast at end_time
+    env.ledger().with_mut(|li| li.timestamp = now + 3600);
+    assert!(voting_client.has_active_proposal(&1u64));
+    env.ledger().with_mut(|li| li.timestamp = now + 3601);
+    assert!(!voting_client.has_active_proposal(&1u64));
+
+    // Closing the last freezing proposal starts applying the tree's queue
+    voting_client.close_proposal(&1u64, &timed, &admin);
+    assert!(!voting_client.has_active_proposal(&1u64));
+    assert_eq!(tree_client.released(&1u64), 1);
+
+    // A long vote freezes membership for at most MAX_FREEZE_WINDOW
+    let now = now + 3601;
+    let long = voting_client.create_proposal(
+        &1u64,
+        &title,
+        &cid,
+        &(now + 365 * 24 * 3600),
+        &member,
+        &VoteMode::Trailing,
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp = now + MAX_FREEZE_WINDOW);
+    assert!(voting_client.has_active_proposal(&1u64));
+    env.ledger()
+        .with_mut(|li| li.timestamp = now + MAX_FREEZE_WINDOW + 1);
+    assert!(!voting_client.has_active_proposal(&1u64));
+    assert_eq!(
+        voting_client.get_proposal(&1u64, &long).state,
+        ProposalState::Active
+    );
+
+    // The open set is bounded
+    let now = now + MAX_FREEZE_WINDOW + 1;
+    for _ in 0..MAX_OPEN_PROPOSALS {
+        voting_client.create_proposal(
+            &1u64,
+            &title,
+            &cid,
+            &(now + 3600),
+            &member,
+            &VoteMode::Trailing,
+        );
+    }
+    assert_eq!(
+        voting_client.try_create_proposal(
+            &1u64,
+            &title,
+            &cid,
+            &(now + 3600),
+            &member,
+            &VoteMode::Trailing
+        ),
+        Err(Ok(VotingError::TooManyOpenProposals.into()))
+    );
+}
+
 #[test]
 #[should_panic(expected = "HostError")]
 fn test_vote_after_archive_fails() {
@@ -968,6 +1186,53 @@ fn test_close_proposal_non_admin_fails() {
     voting_client.close_proposal(&1u64, &proposal_id, &fake);
 }
 
+#[test]
+fn test_action_roles_open_propose_and_close_to_sbt_roles() {
+    let (env, voting_id, tree_id, sbt_id, registry_id, member) = setup_env_with_registry();
+    let voting_client = VotingClient::new(&env, &voting_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+    let tree_client = mock_tree::MockTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let admin = Address::generate(&env);
+    let council = Address::generate(&env);
+
+    sbt_client.set_member(&1u64, &member, &true);
+    sbt_client.set_member(&1u64, &council, &true);
+    sbt_client.set_role(&1u64, &council, &2u32);
+    tree_client.set_root(&1u64, &U256::from_u32(&env, 12345));
+    registry_client.set_admin(&1u64, &admin);
+    registry_client.set_members_can_propose(&1u64, &false);
+    voting_client.set_vk(&1u64, &create_dummy_vk(&env), &admin);
+
+    let title = String::from_str(&env, "Council proposal");
+    let cid = String::from_str(&env, "");
+    let end = env.ledger().timestamp() + 3600;
+
+    // Without a propose role only the admin may create proposals
+    let result =
+        voting_client.try_create_proposal(&1u64, &title, &cid, &end, &council, &VoteMode::Fixed);
+    assert_eq!(result, Err(Ok(VotingError::OnlyAdminCanPropose.into())));
+
+    registry_client.set_action_role(&1u64, &symbol_short!("propose"), &2u32);
+    registry_client.set_action_role(&1u64, &symbol_short!("close"), &2u32);
+
+    let proposal_id =
+        voting_client.create_proposal(&1u64, &title, &cid, &end, &council, &VoteMode::Fixed);
+    let result =
+        voting_client.try_create_proposal(&1u64, &title, &cid, &end, &member, &VoteMode::Fixed);
+    assert_eq!(result, Err(Ok(VotingError::OnlyAdminCanPropose.into())));
+
+    // Closing follows the close role; plain members are still refused
+    let result = voting_client.try_close_proposal(&1u64, &proposal_id, &member);
+    assert_eq!(result, Err(Ok(VotingError::NotAdmin.into())));
+    voting_client.close_proposal(&1u64, &proposal_id, &council);
+    voting_client.archive_proposal(&1u64, &proposal_id, &council);
+    assert_eq!(
+        voting_client.get_proposal(&1u64, &proposal_id).state,
+        ProposalState::Archived
+    );
+}
+
 #[test]
 #[should_panic(expected = "HostError")]
 fn test_vote_with_invalid_root_fails() {
@@ -2167,7 +2432,7 @@ fn test_set_vk_ic_length_5_fails() {
 
 #[test]
 #[should_panic(expected = "HostError")]
-fn test_set_vk_ic_length_7_fails() {
+fn test_set_vk_ic_length_9_fails() {
     let (env, voting_id, _tree_id, _sbt_id, registry_id, _member) = setup_env_with_registry();
     let voting_client = VotingClient::new(&env, &voting_id);
     let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
@@ -2175,7 +2440,7 @@ fn test_set_vk_ic_length_7_fails() {
     let admin = Address::generate(&env);
     registry_client.set_admin(&1u64, &admin);
 
-    // Create VK with IC length = 7 (need exactly 6 for vote circuit: 5 public signals + 1)
+    // Create VK with IC length = 9 (6 for the vote circuit, +1 each for expiry and strict revocation)
     let g1 = bn254_g1_generator(&env);
     let g2 = bn254_g2_generator(&env);
     let invalid_vk = VerificationKey {
@@ -2191,11 +2456,13 @@ fn test_set_vk_ic_length_7_fails() {
             g1.clone(),
             g1.clone(),
             g1.clone(),
+            g1.clone(),
+            g1.clone(),
             g1.clone()
         ],
     };
 
-    // Should panic - need exactly 6 elements
+    // Should panic - need 6 to 8 elements
     voting_client.set_vk(&1u64, &invalid_vk, &admin);
 }
 
@@ -2733,3 +3000,124 @@ fn test_vote_rejects_zero_nullifier() {
     // This should panic with InvalidNullifier
     voting_client.vote(&dao_id, &proposal_id, &true, &zero_nullifier, &root, &proof);
 }
+
+#[test]
+fn test_depth_vk_selected_for_current_tree_depth() {
+    let (env, voting_id, _, sbt_id, registry_id, member) = setup_env_with_registry();
+    let voting_client = VotingClient::new(&env, &voting_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+
+    let admin = Address::generate(&env);
+    registry_client.set_admin(&1u64, &admin);
+    sbt_client.set_member(&1u64, &member, &true);
+
+    // v1: generic VK, v2..v6: VKs for deeper trees the DAO has not grown into yet
+    voting_client.set_vk(&1u64, &create_dummy_vk(&env), &admin);
+    for depth in 20u32..25 {
+        voting_client.set_depth_vk(&1u64, &depth, &create_dummy_vk(&env), &admin);
+    }
+    assert_eq!(voting_client.vk_version(&1u64), 6);
+    assert_eq!(voting_client.vk_for_depth(&1u64, &20u32), Some(2));
+    assert_eq!(voting_client.vk_for_depth(&1u64, &18u32), None);
+    assert_eq!(voting_client.depth_vk_ic_len(&1u64, &20u32), Some(6));
+    assert_eq!(voting_client.depth_vk_ic_len(&1u64, &18u32), None);
+
+    // Mock tree reports depth 18, so the deeper VKs are skipped
+    let title = String::from_str(&env, "Before growth");
+    let cid = String::from_str(&env, "");
+    let p1 = voting_client.create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert_eq!(voting_client.get_proposal(&1u64, &p1).vk_version, 1);
+
+    // v7: VK registered for the current depth takes precedence
+    voting_client.set_depth_vk(&1u64, &18u32, &create_dummy_vk(&env), &admin);
+    let p2 = voting_client.create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert_eq!(voting_client.get_proposal(&1u64, &p2).vk_version, 7);
+}
+
+#[test]
+fn test_expiring_tree_requires_expiry_circuit_vk() {
+    let (env, voting_id, tree_id, sbt_id, registry_id, member) = setup_env_with_registry();
+    let voting_client = VotingClient::new(&env, &voting_id);
+    let tree_client = mock_tree::MockTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+
+    let admin = Address::generate(&env);
+    registry_client.set_admin(&1u64, &admin);
+    sbt_client.set_member(&1u64, &member, &true);
+    tree_client.set_expiring(&1u64, &true);
+
+    let title = String::from_str(&env, "Annual budget");
+    let cid = String::from_str(&env, "");
+
+    // The plain vote circuit cannot check expiry
+    voting_client.set_vk(&1u64, &create_dummy_vk(&env), &admin);
+    let result =
+        voting_client.try_create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert_eq!(result, Err(Ok(VotingError::VkIcLengthMismatch.into())));
+
+    // Expiring circuit VK: one extra IC point for the snapshot-time signal
+    let mut expiry_vk = create_dummy_vk(&env);
+    expiry_vk.ic.push_back(bn254_g1_generator(&env));
+    voting_client.set_vk(&1u64, &expiry_vk, &admin);
+    let proposal_id =
+        voting_client.create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert_eq!(
+        voting_client.get_created_at(&1u64, &proposal_id),
+        env.ledger().timestamp()
+    );
+
+    // ...and that VK is rejected again once the tree is a plain one
+    tree_client.set_expiring(&1u64, &false);
+    let result =
+        voting_client.try_create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert_eq!(result, Err(Ok(VotingError::VkIcLengthMismatch.into())));
+}
+
+#[test]
+fn test_strict_revocation_requires_strict_circuit_vk() {
+    let (env, voting_id, tree_id, sbt_id, registry_id, member) = setup_env_with_registry();
+    let voting_client = VotingClient::new(&env, &voting_id);
+    let tree_client = mock_tree::MockTreeClient::new(&env, &tree_id);
+    let registry_client = mock_registry::MockRegistryClient::new(&env, &registry_id);
+    let sbt_client = mock_sbt::MockSbtClient::new(&env, &sbt_id);
+
+    let admin = Address::generate(&env);
+    registry_client.set_admin(&1u64, &admin);
+    sbt_client.set_member(&1u64, &member, &true);
+
+    let title = String::from_str(&env, "Treasury move");
+    let cid = String::from_str(&env, "");
+
+    // Proposals created before strict revocation keep the plain circuit
+    voting_client.set_vk(&1u64, &create_dummy_vk(&env), &admin);
+    let before =
+        voting_client.create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert!(!voting_client.get_strict(&1u64, &before));
+
+    // Once enabled, the plain circuit cannot prove non-revocation
+    tree_client.set_strict(&1u64, &true);
+    let result =
+        voting_client.try_create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert_eq!(result, Err(Ok(VotingError::VkIcLengthMismatch.into())));
+
+    // Strict circuit VK: one extra IC point for the revocation root
+    let mut strict_vk = create_dummy_vk(&env);
+    strict_vk.ic.push_back(bn254_g1_generator(&env));
+    voting_client.set_vk(&1u64, &strict_vk, &admin);
+    let proposal_id =
+        voting_client.create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert!(voting_client.get_strict(&1u64, &proposal_id));
+
+    // Expiring trees with strict revocation need both extra signals
+    tree_client.set_expiring(&1u64, &true);
+    let result =
+        voting_client.try_create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert_eq!(result, Err(Ok(VotingError::VkIcLengthMismatch.into())));
+    strict_vk.ic.push_back(bn254_g1_generator(&env));
+    voting_client.set_vk(&1u64, &strict_vk, &admin);
+    let proposal_id =
+        voting_client.create_proposal(&1u64, &title, &cid, &0u64, &member, &VoteMode::Fixed);
+    assert!(voting_client.get_strict(&1u64, &proposal_id));
+}
@@ -0,0 +1,86 @@
+//! # ZKVote Admission Types
+//!
+//! Self-service admission settings a DAO keeps on its registry `DaoInfo` and the SBT
+//! contract reads when members join: paid membership and token-gated joining.
+//!
+//! `DaoInfo` holds them as `Dues` and `Gate` rather than `Option`s, since a contract
+//! type field cannot be an optional struct; the registry's `dues` and `token_gate`
+//! views return the `Option` form.
+
+#![no_std]
+
+use soroban_sdk::{contracttype, Address};
+
+/// Fees a DAO charges for self-service membership, paid in a SEP-41 token (e.g. a SAC)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuesConfig {
+    /// Token contract the fees are paid in
+    pub token: Address,
+    /// DAO-controlled address receiving the fees
+    pub treasury: Address,
+    /// One-off fee for `self_join` / `join_with_invite`
+    pub join_fee: i128,
+    /// Fee per period; 0 disables dues
+    pub dues: i128,
+    /// Seconds a dues payment keeps the membership active
+    pub period: u64,
+}
+
+/// Token holding that lets an address `self_join` while membership is closed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenGate {
+    /// SEP-41 token contract whose `balance` is checked
+    pub token: Address,
+    /// Smallest balance that qualifies
+    pub min_balance: i128,
+}
+
+/// A DAO's membership fees
+// Contract types cannot box the config, so `Free` pads to its size
+#[allow(clippy::large_enum_variant)]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Dues {
+    Free,
+    Paid(DuesConfig),
+}
+
+/// Whether token holders may `self_join` a DAO whose membership is closed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Gate {
+    Ungated,
+    Token(TokenGate),
+}
+
+impl From<Option<DuesConfig>> for Dues {
+    fn from(config: Option<DuesConfig>) -> Self {
+        config.map_or(Dues::Free, Dues::Paid)
+    }
+}
+
+impl From<Dues> for Option<DuesConfig> {
+    fn from(dues: Dues) -> Self {
+        match dues {
+            Dues::Free => None,
+            Dues::Paid(config) => Some(config),
+        }
+    }
+}
+
+impl From<Option<TokenGate>> for Gate {
+    fn from(gate: Option<TokenGate>) -> Self {
+        gate.map_or(Gate::Ungated, Gate::Token)
+    }
+}
+
+impl From<Gate> for Option<TokenGate> {
+    fn from(gate: Gate) -> Self {
+        match gate {
+            Gate::Ungated => None,
+            Gate::Token(gate) => Some(gate),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! # ZKVote Poseidon Library
+//!
+//! Circomlib-compatible Poseidon hashing over the BN254 scalar field for 1 to 5
+//! inputs, shared by ZKVote contracts and usable from any `no_std` Soroban crate.
+//!
+//! `Poseidon::new(env, n)` matches circomlib's `Poseidon(n)` template: the state is
+//! `[0, in_1, .., in_n]` (t = n + 1) and the output is the first state element.
+//! Commitments are `Poseidon(2)`, vote nullifiers `Poseidon(3)` and comment
+//! nullifiers `Poseidon(4)`.
+//!
+//! Parameters are built once per `Poseidon` value, so callers hashing in a loop
+//! (e.g. up a Merkle path) should construct it once and reuse it.
+//!
+//! Hashing runs on the `poseidon_permutation` host function (CAP-0075). The host
+//! exposes no whole-hash Poseidon function, so there is no faster native path to
+//! switch to; only the round constants and MDS matrix are supplied by this crate.
+//!
+//! `zeros::zero_at` gives the empty-subtree roots of the `Poseidon(2)` Merkle tree.
+
+#![no_std]
+
+mod params;
+pub mod zeros;
+
+use soroban_sdk::{Bytes, Env, Symbol, Vec, U256};
+
+/// Largest supported number of inputs (t = 6)
+pub const MAX_INPUTS: u32 = 5;
+
+/// BN254 scalar field modulus (Fr) in big-endian bytes
+/// r = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+pub const BN254_FR_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PoseidonError {
+    /// Input count outside 1..=MAX_INPUTS
+    InvalidArity,
+    /// An input is not reduced modulo the BN254 scalar field
+    InputOutOfField,
+}
+
+/// Poseidon instance for a fixed number of inputs
+pub struct Poseidon {
+    inputs: u32,
+    mds: Vec<Vec<U256>>,
+    rc: Vec<Vec<U256>>,
+}
+
+impl Poseidon {
+    /// Build the parameters for hashing `inputs` values (1..=MAX_INPUTS)
+    pub fn new(env: &Env, inputs: u32) -> Result<Self, PoseidonError> {
+        if inputs == 0 || inputs > MAX_INPUTS {
+            return Err(PoseidonError::InvalidArity);
+        }
+        Ok(Self::build(env, inputs))
+    }
+
+    /// Two-input instance used for commitments and Merkle nodes
+    pub fn pair(env: &Env) -> Self {
+        Self::build(env, 2)
+    }
+
+    /// Number of inputs this instance hashes
+    pub fn inputs(&self) -> u32 {
+        self.inputs
+    }
+
+    /// Hash exactly `self.inputs()` field elements
+    /// Inputs must already be reduced modulo r (see `in_field`); the permutation
+    /// does not reduce them, so unreduced aliases would collide.
+    pub fn hash(&self, env: &Env, inputs: &Vec<U256>) -> Result<U256, PoseidonError> {
+        if inputs.len() != self.inputs {
+            return Err(PoseidonError::InvalidArity);
+        }
+        if !inputs.iter().all(|x| in_field(env, &x)) {
+            return Err(PoseidonError::InputOutOfField);
+        }
+        Ok(self.permute(env, inputs))
+    }
+
+    /// Hash two values with a two-input instance (no range checks)
+    pub fn hash_pair(&self, env: &Env, left: &U256, right: &U256) -> U256 {
+        self.permute(env, &soroban_sdk::vec![env, left.clone(), right.clone()])
+    }
+
+    fn build(env: &Env, inputs: u32) -> Self {
+        let (mds, rc): (&[[u8; 32]], &[[u8; 32]]) = match inputs {
+            1 => (&params::MDS_T2, &params::RC_T2),
+            2 => (&params::MDS_T3, &params::RC_T3),
+            3 => (&params::MDS_T4, &params::RC_T4),
+            4 => (&params::MDS_T5, &params::RC_T5),
+            _ => (&params::MDS_T6, &params::RC_T6),
+        };
+        let t = inputs + 1;
+        Poseidon {
+            inputs,
+            mds: matrix(env, mds, t),
+            rc: matrix(env, rc, t),
+        }
+    }
+
+    fn permute(&self, env: &Env, inputs: &Vec<U256>) -> U256 {
+        let t = self.inputs + 1;
+        let mut state = Vec::new(env);
+        state.push_back(U256::from_u32(env, 0));
+        state.append(inputs);
+
+        let result: Vec<U256> = env.crypto_hazmat().poseidon_permutation(
+            &state,
+            Symbol::new(env, "BN254"),
+            t,
+            params::SBOX_D,
+            params::ROUNDS_F,
+            params::ROUNDS_P[(t - 2) as usize],
+            &self.mds,
+            &self.rc,
+        );
+        result.get_unchecked(0)
+    }
+}
+
+/// Hash 1..=MAX_INPUTS field elements, as circomlib's `Poseidon(inputs.len())`
+pub fn hash(env: &Env, inputs: &Vec<U256>) -> Result<U256, PoseidonError> {
+    Poseidon::new(env, inputs.len())?.hash(env, inputs)
+}
+
+/// Whether a value is a canonical BN254 scalar field element (< r)
+pub fn in_field(env: &Env, value: &U256) -> bool {
+    let modulus = U256::from_be_bytes(env, &soroban_sdk::Bytes::from_array(env, &BN254_FR_MODULUS));
+    *value < modulus
+}
+
+// Internal: Rows of `width` U256 values from flat big-endian constants
+fn matrix(env: &Env, values: &[[u8; 32]], width: u32) -> Vec<Vec<U256>> {
+    let mut rows = Vec::new(env);
+    for chunk in values.chunks(width as usize) {
+        let mut row = Vec::new(env);
+        for bytes in chunk {
+            row.push_back(U256::from_be_bytes(env, &Bytes::from_array(env, bytes)));
+        }
+        rows.push_back(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(env: &Env, s: &str) -> U256 {
+        // Parse a decimal string into U256 by repeated multiply-add
+        let ten = U256::from_u32(env, 10);
+        let mut acc = U256::from_u32(env, 0);
+        for c in s.bytes() {
+            acc = acc.mul(&ten).add(&U256::from_u32(env, (c - b'0') as u32));
+        }
+        acc
+    }
+
+    fn seq(env: &Env, n: u32) -> Vec<U256> {
+        let mut v = Vec::new(env);
+        for i in 1..=n {
+            v.push_back(U256::from_u32(env, i));
+        }
+        v
+    }
+
+    #[test]
+    fn test_circomlib_vectors_for_each_arity() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+
+        // circomlibjs poseidon([1..=n])
+        let expected = [
+            "18586133768512220936620570745912940619677854269274689475585506675881198879027",
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+            "6542985608222806190361240322586112750744169038454362455181422643027100751666",
+            "18821383157269793795438455681495246036402687001665670618754263018637548127333",
+            "6183221330272524995739186171720101788151706631170188140075976616310159254464",
+        ];
+        for (i, want) in expected.iter().enumerate() {
+            let n = i as u32 + 1;
+            assert_eq!(
+                hash(&env, &seq(&env, n)),
+                Ok(dec(&env, want)),
+                "arity {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_pair_matches_hash() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+
+        let poseidon = Poseidon::pair(&env);
+        let (a, b) = (U256::from_u32(&env, 0), U256::from_u32(&env, 0));
+        assert_eq!(
+            poseidon.hash_pair(&env, &a, &b),
+            dec(
+                &env,
+                "14744269619966411208579211824598458697587494354926760081771325075741142829156"
+            )
+        );
+        assert_eq!(
+            poseidon.hash(&env, &soroban_sdk::vec![&env, a.clone(), b.clone()]),
+            Ok(poseidon.hash_pair(&env, &a, &b))
+        );
+    }
+
+    #[test]
+    fn test_zero_ladder_chains() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+
+        let poseidon = Poseidon::pair(&env);
+        let mut current = U256::from_u32(&env, 0);
+        for level in 0..=32u32 {
+            assert_eq!(zeros::zero_at(&env, level), Some(current.clone()));
+            current = poseidon.hash_pair(&env, &current, &current);
+        }
+        assert_eq!(zeros::zero_at(&env, 33), None);
+    }
+
+    #[test]
+    fn test_rejects_bad_arity_and_unreduced_inputs() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+
+        assert_eq!(
+            hash(&env, &Vec::new(&env)),
+            Err(PoseidonError::InvalidArity)
+        );
+        assert_eq!(hash(&env, &seq(&env, 6)), Err(PoseidonError::InvalidArity));
+
+        let modulus = U256::from_be_bytes(&env, &Bytes::from_array(&env, &BN254_FR_MODULUS));
+        let inputs = soroban_sdk::vec![&env, modulus];
+        assert_eq!(hash(&env, &inputs), Err(PoseidonError::InputOutOfField));
+
+        let poseidon = Poseidon::new(&env, 3).unwrap();
+        assert_eq!(
+            poseidon.hash(&env, &seq(&env, 2)),
+            Err(PoseidonError::InvalidArity)
+        );
+    }
+}
@@ -0,0 +1,761 @@
+//! # ZKVote Tree Replay
+//!
+//! Off-chain reference implementation of the `membership-tree` Merkle trees, rebuilt
+//! purely from the contract's events. Clients feed every tree event in ledger order to
+//! `TreeReplay::apply`, which recomputes each root with the contract's own Poseidon
+//! and zero ladder (`zkvote-poseidon`) and rejects the stream as soon as a computed
+//! root or root index differs from the emitted `new_root`/`root_index`.
+//!
+//! Replayed trees answer the questions voters need: the current root, the leaf index
+//! of a commitment, and Merkle paths against the current or any earlier root. The
+//! contract is differentially tested against this crate in the integration tests.
+//!
+//! `U256` values are host objects, so a replay is bound to one `Env` (for example
+//! `Env::default()` with the SDK's `testutils` feature) and events must be decoded
+//! into that same `Env`.
+
+use std::collections::BTreeMap;
+
+use membership_tree::{
+    CommitEvent, LeafRenewEvent, ReinstatementEvent, RemovalEvent, TreeGrowEvent, TreeInitEvent,
+    GENERAL_GROUP,
+};
+use soroban_sdk::{Env, Map, Symbol, TryFromVal, Val, Vec, U256};
+use zkvote_poseidon::{zeros, Poseidon};
+
+/// A tree-changing event emitted by `membership-tree`, without its member topic
+#[derive(Clone, Debug, PartialEq)]
+pub enum TreeEvent {
+    Init {
+        dao_id: u64,
+        group_id: u32,
+        depth: u32,
+        empty_root: U256,
+        root_index: u32,
+    },
+    Commit {
+        dao_id: u64,
+        group_id: u32,
+        commitment: U256,
+        index: u64,
+        new_root: U256,
+        root_index: u32,
+        expires_at: Option<u64>,
+    },
+    Removal {
+        dao_id: u64,
+        group_id: u32,
+        index: u64,
+        new_root: U256,
+        root_index: u32,
+    },
+    Renew {
+        dao_id: u64,
+        group_id: u32,
+        index: u64,
+        expires_at: u64,
+        new_root: U256,
+        root_index: u32,
+    },
+    /// Reinstatements always land in the general group
+    Reinstate {
+        dao_id: u64,
+        commitment: U256,
+        index: u64,
+        new_root: U256,
+        root_index: u32,
+        expires_at: Option<u64>,
+    },
+    Grow {
+        dao_id: u64,
+        group_id: u32,
+        old_depth: u32,
+        new_depth: u32,
+        new_root: U256,
+        root_index: u32,
+    },
+}
+
+/// Reason an event stream or query was rejected
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ReplayError {
+    /// No `TreeInitEvent` seen for the (dao_id, group_id)
+    UnknownTree,
+    /// A second `TreeInitEvent` for the same tree
+    AlreadyInitialized,
+    /// Depth is zero, above the zero ladder, or growth does not match the tree
+    InvalidDepth,
+    /// Event carries a different root index than the next one the contract assigns
+    RootIndexMismatch { expected: u32, found: u32 },
+    /// Recomputed root differs from the emitted one (field is the root index)
+    RootMismatch(u32),
+    /// Leaf index past the appended leaves or the tree capacity
+    LeafOutOfBounds,
+    /// Renewal of a leaf whose commitment never appeared in the stream
+    UnknownCommitment(u64),
+    /// Root not in the replayed history of the tree
+    UnknownRoot,
+}
+
+impl TreeEvent {
+    /// Decode a raw contract event (topics and map-format data, as returned by the
+    /// SDK or RPC) emitted by the tree contract. Returns None for other events.
+    pub fn decode(env: &Env, topics: &Vec<Val>, data: &Val) -> Option<Self> {
+        let name = Symbol::try_from_val(env, &topics.get(0)?).ok()?;
+        let dao_id = u64::try_from_val(env, &topics.get(1)?).ok()?;
+        let data = Map::<Symbol, Val>::try_from_val(env, data).ok()?;
+
+        let event = if name == Symbol::new(env, "tree_init_event") {
+            TreeEvent::Init {
+                dao_id,
+                group_id: field(env, &data, "group_id")?,
+                depth: field(env, &data, "depth")?,
+                empty_root: field(env, &data, "empty_root")?,
+                root_index: field(env, &data, "root_index")?,
+            }
+        } else if name == Symbol::new(env, "commit_event") {
+            TreeEvent::Commit {
+                dao_id,
+                group_id: field(env, &data, "group_id")?,
+                commitment: field(env, &data, "commitment")?,
+                index: field(env, &data, "index")?,
+                new_root: field(env, &data, "new_root")?,
+                root_index: field(env, &data, "root_index")?,
+                expires_at: field(env, &data, "expires_at").flatten(),
+            }
+        } else if name == Symbol::new(env, "removal_event") {
+            TreeEvent::Removal {
+                dao_id,
+                group_id: field(env, &data, "group_id")?,
+                index: field(env, &data, "index")?,
+                new_root: field(env, &data, "new_root")?,
+                root_index: field(env, &data, "root_index")?,
+            }
+        } else if name == Symbol::new(env, "leaf_renew_event") {
+            TreeEvent::Renew {
+                dao_id,
+                group_id: field(env, &data, "group_id")?,
+                index: field(env, &data, "index")?,
+                expires_at: field(env, &data, "expires_at")?,
+                new_root: field(env, &data, "new_root")?,
+                root_index: field(env, &data, "root_index")?,
+            }
+        } else if name == Symbol::new(env, "reinstatement_event") {
+            TreeEvent::Reinstate {
+                dao_id,
+                commitment: field(env, &data, "commitment")?,
+                index: field(env, &data, "index")?,
+                new_root: field(env, &data, "new_root")?,
+                root_index: field(env, &data, "root_index")?,
+                expires_at: field(env, &data, "expires_at").flatten(),
+            }
+        } else if name == Symbol::new(env, "tree_grow_event") {
+            TreeEvent::Grow {
+                dao_id,
+                group_id: field(env, &data, "group_id")?,
+                old_depth: field(env, &data, "old_depth")?,
+                new_depth: field(env, &data, "new_depth")?,
+                new_root: field(env, &data, "new_root")?,
+                root_index: field(env, &data, "root_index")?,
+            }
+        } else {
+            return None;
+        };
+        Some(event)
+    }
+}
+
+impl From<TreeInitEvent> for TreeEvent {
+    fn from(e: TreeInitEvent) -> Self {
+        TreeEvent::Init {
+            dao_id: e.dao_id,
+            group_id: e.group_id,
+            depth: e.depth,
+            empty_root: e.empty_root,
+            root_index: e.root_index,
+        }
+    }
+}
+
+impl From<CommitEvent> for TreeEvent {
+    fn from(e: CommitEvent) -> Self {
+        TreeEvent::Commit {
+            dao_id: e.dao_id,
+            group_id: e.group_id,
+            commitment: e.commitment,
+            index: e.index,
+            new_root: e.new_root,
+            root_index: e.root_index,
+            expires_at: e.expires_at,
+        }
+    }
+}
+
+impl From<RemovalEvent> for TreeEvent {
+    fn from(e: RemovalEvent) -> Self {
+        TreeEvent::Removal {
+            dao_id: e.dao_id,
+            group_id: e.group_id,
+            index: e.index,
+            new_root: e.new_root,
+            root_index: e.root_index,
+        }
+    }
+}
+
+impl From<LeafRenewEvent> for TreeEvent {
+    fn from(e: LeafRenewEvent) -> Self {
+        TreeEvent::Renew {
+            dao_id: e.dao_id,
+            group_id: e.group_id,
+            index: e.index,
+            expires_at: e.expires_at,
+            new_root: e.new_root,
+            root_index: e.root_index,
+        }
+    }
+}
+
+impl From<ReinstatementEvent> for TreeEvent {
+    fn from(e: ReinstatementEvent) -> Self {
+        TreeEvent::Reinstate {
+            dao_id: e.dao_id,
+            commitment: e.commitment,
+            index: e.index,
+            new_root: e.new_root,
+            root_index: e.root_index,
+            expires_at: e.expires_at,
+        }
+    }
+}
+
+impl From<TreeGrowEvent> for TreeEvent {
+    fn from(e: TreeGrowEvent) -> Self {
+        TreeEvent::Grow {
+            dao_id: e.dao_id,
+            group_id: e.group_id,
+            old_depth: e.old_depth,
+            new_depth: e.new_depth,
+            new_root: e.new_root,
+            root_index: e.root_index,
+        }
+    }
+}
+
+// Internal: Read and convert one field of a map-format event body
+fn field<T: TryFromVal<Env, Val>>(env: &Env, data: &Map<Symbol, Val>, name: &str) -> Option<T> {
+    T::try_from_val(env, &data.get(Symbol::new(env, name))?).ok()
+}
+
+// A change to one tree, in the order the contract recorded roots
+#[derive(Clone)]
+enum Change {
+    Leaf {
+        index: u64,
+        value: U256,
+        commitment: Option<U256>,
+    },
+    Grow {
+        new_depth: u32,
+    },
+}
+
+// A root in the replayed history and how many changes produced it
+#[derive(Clone)]
+struct Snapshot {
+    root: U256,
+    root_index: u32,
+    changes: usize,
+}
+
+// Replayed state of one (dao_id, group_id) tree
+#[derive(Clone)]
+struct Tree {
+    initial_depth: u32,
+    depth: u32,
+    next_index: u64,
+    next_root_index: u32,
+    // (level, index) -> node hash; level 0 holds the leaves. Absent nodes are empty.
+    nodes: BTreeMap<(u32, u64), U256>,
+    // leaf index -> commitment behind the leaf (differs from the leaf on expiring trees)
+    commitments: BTreeMap<u64, U256>,
+    changes: std::vec::Vec<Change>,
+    history: std::vec::Vec<Snapshot>,
+}
+
+impl Tree {
+    fn new(depth: u32, empty_root: U256) -> Self {
+        Tree {
+            initial_depth: depth,
+            depth,
+            next_index: 0,
+            // The empty root and the first insert share root index 0
+            next_root_index: 0,
+            nodes: BTreeMap::new(),
+            commitments: BTreeMap::new(),
+            changes: std::vec::Vec::new(),
+            history: std::vec::Vec::from([Snapshot {
+                root: empty_root,
+                root_index: 0,
+                changes: 0,
+            }]),
+        }
+    }
+
+    fn node(&self, env: &Env, level: u32, index: u64) -> U256 {
+        self.nodes
+            .get(&(level, index))
+            .cloned()
+            .unwrap_or_else(|| zero(env, level))
+    }
+
+    fn root(&self, env: &Env) -> U256 {
+        self.node(env, self.depth, 0)
+    }
+
+    fn apply(
+        &mut self,
+        env: &Env,
+        poseidon: &Poseidon,
+        change: &Change,
+    ) -> Result<(), ReplayError> {
+        match change {
+            Change::Leaf {
+                index,
+                value,
+                commitment,
+            } => {
+                if *index > self.next_index || *index >= 1u64 << self.depth {
+                    return Err(ReplayError::LeafOutOfBounds);
+                }
+                if *index == self.next_index {
+                    self.next_index += 1;
+                }
+                match commitment {
+                    Some(commitment) => self.commitments.insert(*index, commitment.clone()),
+                    None => self.commitments.remove(index),
+                };
+                self.nodes.insert((0, *index), value.clone());
+                self.rehash(env, poseidon, 0, *index, self.depth);
+            }
+            Change::Grow { new_depth } => {
+                let old_depth = self.depth;
+                self.depth = *new_depth;
+                self.rehash(env, poseidon, old_depth, 0, *new_depth);
+            }
+        }
+        self.changes.push(change.clone());
+        Ok(())
+    }
+
+    // Recompute the ancestors of node (level, index) up to `top`
+    fn rehash(&mut self, env: &Env, poseidon: &Poseidon, level: u32, index: u64, top: u32) {
+        let mut index = index;
+        for level in level..top {
+            let (node, sibling) = (
+                self.node(env, level, index),
+                self.node(env, level, index ^ 1),
+            );
+            let (left, right) = if index & 1 == 0 {
+                (node, sibling)
+            } else {
+                (sibling, node)
+            };
+            index /= 2;
+            self.nodes
+                .insert((level + 1, index), poseidon.hash_pair(env, &left, &right));
+        }
+    }
+
+    fn path(&self, env: &Env, leaf_index: u64) -> Result<(Vec<U256>, Vec<u32>), ReplayError> {
+        if leaf_index >= self.next_index || leaf_index >= 1u64 << self.depth {
+            return Err(ReplayError::LeafOutOfBounds);
+        }
+        let mut elements = Vec::new(env);
+        let mut indices = Vec::new(env);
+        let mut index = leaf_index;
+        for level in 0..self.depth {
+            indices.push_back((index % 2) as u32);
+            elements.push_back(self.node(env, level, index ^ 1));
+            index /= 2;
+        }
+        Ok((elements, indices))
+    }
+}
+
+/// Event-sourced copies of every membership tree seen in an event stream
+pub struct TreeReplay {
+    env: Env,
+    poseidon: Poseidon,
+    trees: BTreeMap<(u64, u32), Tree>,
+}
+
+impl TreeReplay {
+    pub fn new(env: &Env) -> Self {
+        TreeReplay {
+            env: env.clone(),
+            poseidon: Poseidon::pair(env),
+            trees: BTreeMap::new(),
+        }
+    }
+
+    /// Apply the next tree event and check the root and root index it reports
+    /// A rejected event leaves the replay unchanged.
+    pub fn apply(&mut self, event: &TreeEvent) -> Result<(), ReplayError> {
+        let env = &self.env;
+        let (key, change, new_root, root_index) = match event {
+            TreeEvent::Init {
+                dao_id,
+                group_id,
+                depth,
+                empty_root,
+                root_index,
+            } => {
+                let key = (*dao_id, *group_id);
+                if self.trees.contains_key(&key) {
+                    return Err(ReplayError::AlreadyInitialized);
+                }
+                if *depth == 0 || zeros::zero_at(env, *depth).is_none() {
+                    return Err(ReplayError::InvalidDepth);
+                }
+                if *root_index != 0 {
+                    return Err(ReplayError::RootIndexMismatch {
+                        expected: 0,
+                        found: *root_index,
+                    });
+                }
+                let tree = Tree::new(*depth, empty_root.clone());
+                if tree.root(env) != *empty_root {
+                    return Err(ReplayError::RootMismatch(0));
+                }
+                self.trees.insert(key, tree);
+                return Ok(());
+            }
+            TreeEvent::Commit {
+                dao_id,
+                group_id,
+                commitment,
+                index,
+                new_root,
+                root_index,
+                expires_at,
+            } => (
+                (*dao_id, *group_id),
+                self.leaf_change(*index, commitment, *expires_at),
+                new_root,
+                *root_index,
+            ),
+            TreeEvent::Removal {
+                dao_id,
+                group_id,
+                index,
+                new_root,
+                root_index,
+            } => (
+                (*dao_id, *group_id),
+                Change::Leaf {
+                    index: *index,
+                    value: zero(env, 0),
+                    commitment: None,
+                },
+                new_root,
+                *root_index,
+            ),
+            TreeEvent::Renew {
+                dao_id,
+                group_id,
+                index,
+                expires_at,
+                new_root,
+                root_index,
+            } => {
+                let key = (*dao_id, *group_id);
+                let commitment = self
+                    .trees
+                    .get(&key)
+                    .ok_or(ReplayError::UnknownTree)?
+                    .commitments
+                    .get(index)
+                    .cloned()
+                    .ok_or(ReplayError::UnknownCommitment(*index))?;
+                (
+                    key,
+                    self.leaf_change(*index, &commitment, Some(*expires_at)),
+                    new_root,
+                    *root_index,
+                )
+            }
+            TreeEvent::Reinstate {
+                dao_id,
+                commitment,
+                index,
+                new_root,
+                root_index,
+                expires_at,
+            } => (
+                (*dao_id, GENERAL_GROUP),
+                self.leaf_change(*index, commitment, *expires_at),
+                new_root,
+                *root_index,
+            ),
+            TreeEvent::Grow {
+                dao_id,
+                group_id,
+                old_depth,
+                new_depth,
+                new_root,
+                root_index,
+            } => {
+                let key = (*dao_id, *group_id);
+                let depth = self.trees.get(&key).ok_or(ReplayError::UnknownTree)?.depth;
+                if *old_depth != depth
+                    || new_depth <= old_depth
+                    || zeros::zero_at(env, *new_depth).is_none()
+                {
+                    return Err(ReplayError::InvalidDepth);
+                }
+                (
+                    key,
+                    Change::Grow {
+                        new_depth: *new_depth,
+                    },
+                    new_root,
+                    *root_index,
+                )
+            }
+        };
+
+        let current = self.trees.get(&key).ok_or(ReplayError::UnknownTree)?;
+        if root_index != current.next_root_index {
+            return Err(ReplayError::RootIndexMismatch {
+                expected: current.next_root_index,
+                found: root_index,
+            });
+        }
+        let mut tree = current.clone();
+        tree.apply(env, &self.poseidon, &change)?;
+        let root = tree.root(env);
+        if root != *new_root {
+            return Err(ReplayError::RootMismatch(root_index));
+        }
+        tree.next_root_index += 1;
+        tree.history.push(Snapshot {
+            root,
+            root_index,
+            changes: tree.changes.len(),
+        });
+        self.trees.insert(key, tree);
+        Ok(())
+    }
+
+    /// Current root of a tree
+    pub fn root(&self, dao_id: u64, group_id: u32) -> Option<U256> {
+        Some(self.trees.get(&(dao_id, group_id))?.root(&self.env))
+    }
+
+    /// Root index of the current root, as the contract's `group_curr_idx`
+    pub fn root_index(&self, dao_id: u64, group_id: u32) -> Option<u32> {
+        Some(
+            self.trees
+                .get(&(dao_id, group_id))?
+                .history
+                .last()?
+                .root_index,
+        )
+    }
+
+    /// Current depth of a tree
+    pub fn depth(&self, dao_id: u64, group_id: u32) -> Option<u32> {
+        Some(self.trees.get(&(dao_id, group_id))?.depth)
+    }
+
+    /// Index of the active leaf holding `commitment`, if any
+    pub fn leaf_index(&self, dao_id: u64, group_id: u32, commitment: &U256) -> Option<u64> {
+        let tree = self.trees.get(&(dao_id, group_id))?;
+        tree.commitments
+            .iter()
+            .find(|(_, c)| *c == commitment)
+            .map(|(index, _)| *index)
+    }
+
+    /// Merkle path for a leaf in the current tree, as the contract's `group_path`
+    /// Returns (pathElements, pathIndices), both of length `depth`.
+    pub fn path(
+        &self,
+        dao_id: u64,
+        group_id: u32,
+        leaf_index: u64,
+    ) -> Result<(Vec<U256>, Vec<u32>), ReplayError> {
+        let tree = self
+            .trees
+            .get(&(dao_id, group_id))
+            .ok_or(ReplayError::UnknownTree)?;
+        tree.path(&self.env, leaf_index)
+    }
+
+    /// Merkle path for a leaf against any root the tree has had
+    /// Unlike the contract's `get_merkle_path_at`, roots older than its history
+    /// window are supported. The path length is the depth at that root.
+    pub fn path_at(
+        &self,
+        dao_id: u64,
+        group_id: u32,
+        leaf_index: u64,
+        root: &U256,
+    ) -> Result<(Vec<U256>, Vec<u32>), ReplayError> {
+        let tree = self
+            .trees
+            .get(&(dao_id, group_id))
+            .ok_or(ReplayError::UnknownTree)?;
+        let snapshot = tree
+            .history
+            .iter()
+            .rev()
+            .find(|s| s.root == *root)
+            .ok_or(ReplayError::UnknownRoot)?;
+
+        // Replay the prefix of changes that produced the root
+        let mut past = Tree::new(tree.initial_depth, tree.history[0].root.clone());
+        for change in &tree.changes[..snapshot.changes] {
+            past.apply(&self.env, &self.poseidon, change)?;
+        }
+        past.path(&self.env, leaf_index)
+    }
+
+    // Internal: Leaf change for a commitment, baking in the expiry on expiring trees
+    fn leaf_change(&self, index: u64, commitment: &U256, expires_at: Option<u64>) -> Change {
+        let value = match expires_at {
+            Some(expiry) => self.poseidon.hash_pair(
+                &self.env,
+                commitment,
+                &U256::from_u128(&self.env, expiry as u128),
+            ),
+            None => commitment.clone(),
+        };
+        Change::Leaf {
+            index,
+            value,
+            commitment: Some(commitment.clone()),
+        }
+    }
+}
+
+// Internal: Empty subtree root at `level` (levels are bounded by the init/grow checks)
+fn zero(env: &Env, level: u32) -> U256 {
+    zeros::zero_at(env, level).expect("depth within the zero ladder")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init(env: &Env, depth: u32) -> TreeEvent {
+        TreeEvent::Init {
+            dao_id: 1,
+            group_id: GENERAL_GROUP,
+            depth,
+            empty_root: zero(env, depth),
+            root_index: 0,
+        }
+    }
+
+    fn commit(commitment: &U256, index: u64, new_root: &U256, root_index: u32) -> TreeEvent {
+        TreeEvent::Commit {
+            dao_id: 1,
+            group_id: GENERAL_GROUP,
+            commitment: commitment.clone(),
+            index,
+            new_root: new_root.clone(),
+            root_index,
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn test_replays_inserts_and_checks_roots() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let poseidon = Poseidon::pair(&env);
+        let mut replay = TreeReplay::new(&env);
+        replay.apply(&init(&env, 2)).unwrap();
+
+        let (a, b) = (U256::from_u32(&env, 7), U256::from_u32(&env, 9));
+        let root_a = poseidon.hash_pair(
+            &env,
+            &poseidon.hash_pair(&env, &a, &zero(&env, 0)),
+            &zero(&env, 1),
+        );
+        let root_ab = poseidon.hash_pair(&env, &poseidon.hash_pair(&env, &a, &b), &zero(&env, 1));
+
+        // The first insert shares root index 0 with the empty root
+        replay.apply(&commit(&a, 0, &root_a, 0)).unwrap();
+
+        // A wrong root or root index is rejected and leaves the replay untouched
+        assert_eq!(
+            replay.apply(&commit(&b, 1, &root_a, 1)),
+            Err(ReplayError::RootMismatch(1))
+        );
+        assert_eq!(
+            replay.apply(&commit(&b, 1, &root_ab, 2)),
+            Err(ReplayError::RootIndexMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(replay.root(1, GENERAL_GROUP), Some(root_a.clone()));
+
+        replay.apply(&commit(&b, 1, &root_ab, 1)).unwrap();
+        assert_eq!(replay.root_index(1, GENERAL_GROUP), Some(1));
+        assert_eq!(replay.leaf_index(1, GENERAL_GROUP, &b), Some(1));
+
+        let (elements, indices) = replay.path(1, GENERAL_GROUP, 1).unwrap();
+        assert_eq!(elements, soroban_sdk::vec![&env, a.clone(), zero(&env, 1)]);
+        assert_eq!(indices, soroban_sdk::vec![&env, 1u32, 0u32]);
+
+        // Paths against the older root see leaf 1 as empty
+        let (elements, _) = replay.path_at(1, GENERAL_GROUP, 0, &root_a).unwrap();
+        assert_eq!(
+            elements,
+            soroban_sdk::vec![&env, zero(&env, 0), zero(&env, 1)]
+        );
+        assert_eq!(
+            replay.path_at(1, GENERAL_GROUP, 1, &root_a),
+            Err(ReplayError::LeafOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_trees_and_bad_depths() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let mut replay = TreeReplay::new(&env);
+        let leaf = U256::from_u32(&env, 1);
+
+        assert_eq!(
+            replay.apply(&commit(&leaf, 0, &leaf, 0)),
+            Err(ReplayError::UnknownTree)
+        );
+        assert_eq!(replay.apply(&init(&env, 0)), Err(ReplayError::InvalidDepth));
+
+        replay.apply(&init(&env, 3)).unwrap();
+        assert_eq!(
+            replay.apply(&init(&env, 3)),
+            Err(ReplayError::AlreadyInitialized)
+        );
+        assert_eq!(
+            replay.apply(&TreeEvent::Grow {
+                dao_id: 1,
+                group_id: GENERAL_GROUP,
+                old_depth: 2,
+                new_depth: 4,
+                new_root: zero(&env, 4),
+                root_index: 0,
+            }),
+            Err(ReplayError::InvalidDepth)
+        );
+        assert_eq!(
+            replay.path_at(1, GENERAL_GROUP, 0, &leaf),
+            Err(ReplayError::UnknownRoot)
+        );
+    }
+}
@@ -239,7 +239,7 @@ export const ERROR_MESSAGES: Record<string, Record<number, string>> = {
   },
   Tree: {
     [TreeError.NotAdmin]: 'Only DAO admin can perform this action',
-    [TreeError.InvalidDepth]: 'Invalid tree depth (must be 1-18)',
+    [TreeError.InvalidDepth]: 'Invalid tree depth (must be 1-32)',
     [TreeError.TreeInitialized]: 'Tree already initialized for this DAO',
     [TreeError.TreeNotInitialized]: 'Tree not initialized for this DAO',
     [TreeError.CommitmentExists]: 'Identity commitment already registered',
@@ -54,7 +54,7 @@ success "Deployment version: $DEPLOY_VERSION"
 
 # Step 1: Build contracts
 step "Building all contracts..."
-cargo build --target wasm32v1-none --release
+cargo build --target wasm32v1-none --release --workspace --exclude zkvote-tree-replay
 success "Contracts built successfully"
 
 # Step 2: Deploy contracts
@@ -135,7 +135,7 @@ success "DAO Registry deployed: $REGISTRY_ID"
 sleep 5  # Wait for sequence number to sync
 
 # Deploy Membership SBT
-SBT_ID=$(deploy_contract "Membership SBT" "target/wasm32v1-none/release/membership_sbt.wasm" --registry "$REGISTRY_ID")
+SBT_ID=$(deploy_contract "Membership SBT" "target/wasm32v1-none/release/membership_sbt.wasm" --registry "$REGISTRY_ID" --deployer "$(stellar keys address "$KEY_NAME")")
 if [ -z "$SBT_ID" ]; then
   echo "ERROR: Failed to deploy Membership SBT after multiple attempts"
   exit 1
@@ -152,6 +152,17 @@ fi
 success "Membership Tree deployed: $TREE_ID"
 sleep 5  # Wait for sequence number to sync
 
+# Bind the tree to the SBT contract (deployer only)
+stellar contract invoke \
+  --id "$SBT_ID" \
+  --source "$KEY_NAME" \
+  --rpc-url "$RPC_URL" \
+  --network-passphrase "$NETWORK_PASSPHRASE" \
+  -- bind_tree \
+  --tree "$TREE_ID" > /dev/null
+success "Membership Tree bound to SBT"
+sleep 5  # Wait for sequence number to sync
+
 # Deploy Voting
 VOTING_ID=$(deploy_contract "Voting" "target/wasm32v1-none/release/voting.wasm" --tree_contract "$TREE_ID" --registry "$REGISTRY_ID")
 if [ -z "$VOTING_ID" ]; then
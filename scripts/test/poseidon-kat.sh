@@ -78,7 +78,7 @@ echo "  DAORegistry: $REGISTRY_ID"
 SBT_ID=$(stellar contract deploy \
   --wasm target/wasm32v1-none/release/membership_sbt.wasm \
   --source "$KEY_NAME" --rpc-url "$RPC_URL" --network-passphrase "$NETWORK_PASSPHRASE" \
-  -- --registry "$REGISTRY_ID" 2>&1 | tail -1)
+  -- --registry "$REGISTRY_ID" --deployer "$PUBKEY" 2>&1 | tail -1)
 echo "  MembershipSBT: $SBT_ID"
 
 TREE_ID=$(stellar contract deploy \
@@ -86,6 +86,11 @@ TREE_ID=$(stellar contract deploy \
   --source "$KEY_NAME" --rpc-url "$RPC_URL" --network-passphrase "$NETWORK_PASSPHRASE" \
   -- --sbt_contract "$SBT_ID" 2>&1 | tail -1)
 echo "  MembershipTree: $TREE_ID"
+
+stellar contract invoke \
+  --id "$SBT_ID" \
+  --source "$KEY_NAME" --rpc-url "$RPC_URL" --network-passphrase "$NETWORK_PASSPHRASE" \
+  -- bind_tree --tree "$TREE_ID" > /dev/null
 echo ""
 
 # Step 5: Contracts initialized via CAP-0058 constructors at deploy time
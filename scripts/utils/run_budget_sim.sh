@@ -33,8 +33,9 @@ echo
 
 echo "Deploying temporary contracts (simulation mode)..."
 REG_ID=$(soroban contract deploy --source-account "$SOURCE" --wasm "$REGISTRY_WASM" --rpc-url "$STELLAR_RPC_URL" --network-passphrase "$STELLAR_NETWORK_PASSPHRASE")
-SBT_ID=$(soroban contract deploy --source-account "$SOURCE" --wasm "$SBT_WASM" --rpc-url "$STELLAR_RPC_URL" --network-passphrase "$STELLAR_NETWORK_PASSPHRASE" -- --registry "$REG_ID")
+SBT_ID=$(soroban contract deploy --source-account "$SOURCE" --wasm "$SBT_WASM" --rpc-url "$STELLAR_RPC_URL" --network-passphrase "$STELLAR_NETWORK_PASSPHRASE" -- --registry "$REG_ID" --deployer "$(soroban keys address "$SOURCE")")
 TREE_ID=$(soroban contract deploy --source-account "$SOURCE" --wasm "$TREE_WASM" --rpc-url "$STELLAR_RPC_URL" --network-passphrase "$STELLAR_NETWORK_PASSPHRASE" -- --sbt "$SBT_ID")
+soroban contract invoke --source-account "$SOURCE" --id "$SBT_ID" --rpc-url "$STELLAR_RPC_URL" --network-passphrase "$STELLAR_NETWORK_PASSPHRASE" -- bind_tree --tree "$TREE_ID" > /dev/null
 VOTING_ID=$(soroban contract deploy --source-account "$SOURCE" --wasm "$VOTING_WASM" --rpc-url "$STELLAR_RPC_URL" --network-passphrase "$STELLAR_NETWORK_PASSPHRASE" -- --tree "$TREE_ID")
 
 echo "Registry: $REG_ID"
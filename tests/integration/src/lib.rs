@@ -29,8 +29,12 @@ mod tests {
 
             // Register all contracts with CAP-0058 constructors
             let registry = env.register(dao_registry::DaoRegistry, ());
-            let sbt = env.register(membership_sbt::MembershipSbt, (registry.clone(),));
+            let sbt = env.register(
+                membership_sbt::MembershipSbt,
+                (registry.clone(), Address::generate(&env)),
+            );
             let tree = env.register(membership_tree::MembershipTree, (sbt.clone(),));
+            membership_sbt::MembershipSbtClient::new(&env, &sbt).bind_tree(&tree);
             // Pass both tree and registry to voting constructor (registry cached to reduce cross-contract calls)
             let voting = env.register(voting::Voting, (tree.clone(), registry.clone()));
 
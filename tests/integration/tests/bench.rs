@@ -0,0 +1,246 @@
+//! Lightweight benchmarks for tree registration cost and large-tree/proposal scenarios.
+//! Budget numbers are printed with `cargo test -p zkvote-integration-tests --test bench -- --nocapture`.
+//! The stress scenario is ignored to avoid CI time unless profiling.
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String, U256};
+
+// Import actual contract clients from crates (not WASM)
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::MembershipTreeClient;
+use voting::{VoteMode, VotingClient};
+
+// Dummy VK with 6 IC elements (5 public signals + 1); proofs are never verified here
+fn create_dummy_vk(env: &Env) -> voting::VerificationKey {
+    let g1 = soroban_sdk::BytesN::from_array(env, &[0u8; 64]);
+    let g2 = soroban_sdk::BytesN::from_array(env, &[0u8; 128]);
+    voting::VerificationKey {
+        alpha: g1.clone(),
+        beta: g2.clone(),
+        gamma: g2.clone(),
+        delta: g2,
+        ic: soroban_sdk::vec![
+            env,
+            g1.clone(),
+            g1.clone(),
+            g1.clone(),
+            g1.clone(),
+            g1.clone(),
+            g1
+        ],
+    }
+}
+
+// Register `count` members in a fresh tree of `depth`, then return the
+// (cpu, mem) cost of one more registration and of removing that member.
+fn measure_tree_ops(depth: u32, count: u32) -> ((u64, u64), (u64, u64)) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Bench DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &depth, &admin);
+
+    for i in 0..count {
+        let member = Address::generate(&env);
+        sbt.mint(&dao_id, &member, &admin, &None);
+        tree.register_with_caller(&dao_id, &U256::from_u32(&env, 1000 + i), &member);
+    }
+
+    let member = Address::generate(&env);
+    sbt.mint(&dao_id, &member, &admin, &None);
+
+    let mut budget = env.cost_estimate().budget();
+    budget.reset_unlimited();
+    tree.register_with_caller(&dao_id, &U256::from_u32(&env, 99_999), &member);
+    let register = (budget.cpu_instruction_cost(), budget.memory_bytes_cost());
+
+    budget.reset_unlimited();
+    tree.remove_member(&dao_id, &member, &admin);
+    let remove = (budget.cpu_instruction_cost(), budget.memory_bytes_cost());
+
+    (register, remove)
+}
+
+// Registration and removal cpu with 9 existing members, measured in the native test env
+// while Poseidon params were still read from persistent storage on every hash and the zero
+// ladder from instance storage per level. Current costs must stay at or below these; the
+// bound is loose enough to absorb cost-model drift, but catches a return to per-hash
+// storage reads.
+const BASELINE_CPU: [(u32, u64, u64); 2] =
+    [(18, 23_191_340, 24_233_417), (32, 40_050_600, 41_931_984)];
+
+#[test]
+fn bench_tree_registration_and_removal() {
+    for (depth, register_baseline, remove_baseline) in BASELINE_CPU {
+        let (register, remove) = measure_tree_ops(depth, 9);
+        println!(
+            "depth {}: register cpu={} mem={} ({}% of baseline) | remove cpu={} mem={} ({}% of baseline)",
+            depth,
+            register.0,
+            register.1,
+            register.0 * 100 / register_baseline,
+            remove.0,
+            remove.1,
+            remove.0 * 100 / remove_baseline
+        );
+        assert!(
+            register.0 <= register_baseline,
+            "depth {} register cpu {} above baseline {}",
+            depth,
+            register.0,
+            register_baseline
+        );
+        assert!(
+            remove.0 <= remove_baseline,
+            "depth {} remove cpu {} above baseline {}",
+            depth,
+            remove.0,
+            remove_baseline
+        );
+    }
+}
+
+// Import one member as leaf 2^k of a depth-18 tree, where its path writes k node hashes
+// plus the spine node, and return (write entries, footprint entries) of the call.
+fn measure_import(k: u32) -> (u32, u32) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Bench DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+
+    for i in 0..1u32 << k {
+        let entry = (
+            Address::generate(&env),
+            None,
+            Some(U256::from_u32(&env, 1000 + i)),
+        );
+        tree.import_members(&dao_id, &soroban_sdk::vec![&env, entry], &admin);
+    }
+
+    let entry = (
+        Address::generate(&env),
+        None,
+        Some(U256::from_u32(&env, 99_999)),
+    );
+    tree.import_members(&dao_id, &soroban_sdk::vec![&env, entry], &admin);
+    let resources = env.cost_estimate().resources();
+    (
+        resources.write_entries,
+        resources.write_entries + resources.memory_read_entries + resources.disk_read_entries,
+    )
+}
+
+// Network limits per transaction on written and footprint entries
+const WRITE_ENTRY_LIMIT: u32 = 50;
+const FOOTPRINT_LIMIT: u32 = 100;
+
+#[test]
+fn bench_import_members_fits_full_depth() {
+    // Entries grow by about one write and one read per level the path reaches; extrapolate
+    // from leaf 64 to leaf 2^17, the longest path of a depth-18 tree
+    let (writes, footprint) = measure_import(6);
+    let extra_levels = 17 - 6;
+    println!(
+        "import at leaf 64: writes={} footprint={} | at leaf 2^17: writes~{} footprint~{}",
+        writes,
+        footprint,
+        writes + extra_levels,
+        footprint + 2 * extra_levels
+    );
+    assert!(writes + extra_levels <= WRITE_ENTRY_LIMIT);
+    assert!(footprint + 2 * extra_levels <= FOOTPRINT_LIMIT);
+}
+
+#[test]
+#[ignore]
+fn stress_many_proposals_and_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+    let voting = VotingClient::new(&env, &voting_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Stress DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &20, &admin);
+    voting.set_vk(&dao_id, &create_dummy_vk(&env), &admin);
+    sbt.mint(&dao_id, &admin, &admin, &None);
+
+    // Populate 100 members
+    for i in 0..100u32 {
+        let member = Address::generate(&env);
+        sbt.mint(&dao_id, &member, &admin, &None);
+        tree.register_with_caller(&dao_id, &U256::from_u32(&env, 1000 + i), &member);
+    }
+
+    // Create 100 proposals
+    for i in 0..100u32 {
+        let desc = String::from_str(&env, &format!("Prop {}", i));
+        let _pid = voting.create_proposal(
+            &dao_id,
+            &desc,
+            &String::from_str(&env, ""),
+            &0u64,
+            &admin,
+            &VoteMode::Fixed,
+        );
+    }
+}
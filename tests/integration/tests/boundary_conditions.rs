@@ -14,8 +14,12 @@ use voting::{VerificationKey, VoteMode, VotingClient};
 
 fn setup_contracts(env: &Env) -> (Address, Address, Address, Address, Address) {
     let registry_id = env.register(dao_registry::DaoRegistry, ());
-    let sbt_id = env.register(membership_sbt::MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(env)),
+    );
     let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(env, &sbt_id).bind_tree(&tree_id);
     let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
 
     let admin = Address::generate(env);
@@ -372,7 +376,7 @@ fn test_duplicate_commitment_rejected() {
     tree_client.register_with_caller(&dao_id, &commitment, &member2);
 }
 
-// Test: Maximum tree depth is enforced (MAX_TREE_DEPTH = 18)
+// Test: Maximum tree depth is enforced (MAX_TREE_DEPTH = 32)
 #[test]
 #[should_panic(expected = "HostError")]
 fn test_tree_depth_exceeds_max() {
@@ -393,8 +397,8 @@ fn test_tree_depth_exceeds_max() {
         &None,
     );
 
-    // Try to initialize tree with depth 19 (exceeds MAX_TREE_DEPTH of 18)
-    tree_client.init_tree(&dao_id, &19, &admin);
+    // Try to initialize tree with depth 33 (exceeds MAX_TREE_DEPTH of 32)
+    tree_client.init_tree(&dao_id, &33, &admin);
 }
 
 // Test: Zero tree depth is rejected
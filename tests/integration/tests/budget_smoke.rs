@@ -50,8 +50,12 @@ fn setup(
     env.mock_all_auths();
 
     let registry_id = env.register(dao_registry::DaoRegistry, ());
-    let sbt_id = env.register(membership_sbt::MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(env)),
+    );
     let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(env, &sbt_id).bind_tree(&tree_id);
     let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
 
     let registry = DaoRegistryClient::new(env, &registry_id);
@@ -120,8 +124,12 @@ fn budget_set_vk_within_limit() {
     env.mock_all_auths();
 
     let registry_id = env.register(dao_registry::DaoRegistry, ());
-    let sbt_id = env.register(membership_sbt::MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
     let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
     let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
 
     let registry = DaoRegistryClient::new(&env, &registry_id);
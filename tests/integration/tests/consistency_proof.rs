@@ -0,0 +1,138 @@
+// Consistency Proof Tests
+//
+// An auditor fetches a consistency proof between a proposal's eligible root and the
+// current root, then checks it natively with membership_tree::consistency. The proof
+// must show the eligible set only changed by appends and removals.
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String, U256};
+
+// Import actual contract clients from crates (not WASM)
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::consistency::{verify_consistency, ConsistencyError};
+use membership_tree::MembershipTreeClient;
+
+fn setup_dao(env: &Env) -> (u64, Address, Address, MembershipTreeClient<'_>) {
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(env, &sbt_id).bind_tree(&tree_id);
+    let admin = Address::generate(env);
+
+    let registry_client = DaoRegistryClient::new(env, &registry_id);
+    let dao_id = registry_client.create_dao(
+        &String::from_str(env, "Audit DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    let tree_client = MembershipTreeClient::new(env, &tree_id);
+    tree_client.init_tree(&dao_id, &5, &admin);
+
+    (dao_id, sbt_id, admin, tree_client)
+}
+
+fn add_member(
+    env: &Env,
+    sbt_client: &MembershipSbtClient,
+    tree_client: &MembershipTreeClient,
+    dao_id: u64,
+    admin: &Address,
+    commitment: u32,
+) -> Address {
+    let member = Address::generate(env);
+    sbt_client.mint(&dao_id, &member, admin, &None);
+    tree_client.register_with_caller(&dao_id, &U256::from_u32(env, commitment), &member);
+    member
+}
+
+#[test]
+fn test_consistency_proof_across_appends_and_removals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let (dao_id, sbt_id, admin, tree_client) = setup_dao(&env);
+    let sbt_client = MembershipSbtClient::new(&env, &sbt_id);
+
+    let empty_root = tree_client.current_root(&dao_id);
+    let first = add_member(&env, &sbt_client, &tree_client, dao_id, &admin, 1001);
+    add_member(&env, &sbt_client, &tree_client, dao_id, &admin, 1002);
+    let eligible_root = tree_client.current_root(&dao_id);
+
+    add_member(&env, &sbt_client, &tree_client, dao_id, &admin, 1003);
+    tree_client.remove_member(&dao_id, &first, &admin);
+    add_member(&env, &sbt_client, &tree_client, dao_id, &admin, 1004);
+    let current_root = tree_client.current_root(&dao_id);
+
+    let hash = |a: &U256, b: &U256| tree_client.test_poseidon_hash(a, b);
+
+    let proof = tree_client.get_consistency_proof(&dao_id, &eligible_root, &current_root);
+    assert_eq!(proof.len(), 3);
+    assert_eq!(
+        verify_consistency(&eligible_root, &current_root, &proof, hash),
+        Ok(())
+    );
+
+    // The empty root shares root index 0 with the first insertion
+    let full = tree_client.get_consistency_proof(&dao_id, &empty_root, &current_root);
+    assert_eq!(full.len(), 5);
+    assert_eq!(
+        verify_consistency(&empty_root, &current_root, &full, hash),
+        Ok(())
+    );
+
+    // Same root: empty proof
+    let none = tree_client.get_consistency_proof(&dao_id, &current_root, &current_root);
+    assert!(none.is_empty());
+    assert_eq!(
+        verify_consistency(&current_root, &current_root, &none, hash),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_consistency_proof_rejects_rewritten_leaf() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let (dao_id, sbt_id, admin, tree_client) = setup_dao(&env);
+    let sbt_client = MembershipSbtClient::new(&env, &sbt_id);
+
+    add_member(&env, &sbt_client, &tree_client, dao_id, &admin, 2001);
+    let old_root = tree_client.current_root(&dao_id);
+    add_member(&env, &sbt_client, &tree_client, dao_id, &admin, 2002);
+    let new_root = tree_client.current_root(&dao_id);
+
+    let hash = |a: &U256, b: &U256| tree_client.test_poseidon_hash(a, b);
+    let proof = tree_client.get_consistency_proof(&dao_id, &old_root, &new_root);
+
+    // Claim the append overwrote leaf 0 instead of filling leaf 1
+    let mut step = proof.get(0).unwrap();
+    step.transition.leaf_index = 0;
+    step.transition.old_value = U256::from_u32(&env, 2001);
+    let mut forged = proof.clone();
+    forged.set(0, step.clone());
+    assert_eq!(
+        verify_consistency(&old_root, &new_root, &forged, hash),
+        Err(ConsistencyError::PathMismatch(0))
+    );
+
+    // A non-empty to non-empty rewrite is rejected even with a valid starting path
+    let mut step = proof.get(0).unwrap();
+    step.transition.old_value = U256::from_u32(&env, 7);
+    let mut forged = proof.clone();
+    forged.set(0, step);
+    assert!(verify_consistency(&old_root, &new_root, &forged, hash).is_err());
+
+    // Proof does not reach an unrelated root
+    assert_eq!(
+        verify_consistency(&old_root, &U256::from_u32(&env, 1), &proof, hash),
+        Err(ConsistencyError::EndRootMismatch)
+    );
+}
@@ -0,0 +1,134 @@
+// Golden vectors shared with the circuits (circuits/utils/golden_vectors.json).
+// Checks the tree contract's Poseidon view and zero ladder against circomlib outputs.
+
+use serde::Deserialize;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use membership_tree::{MembershipTreeClient, TreeError};
+
+#[derive(Deserialize)]
+struct PoseidonVector {
+    label: String,
+    inputs: Vec<u64>,
+    expected: String,
+}
+
+#[derive(Deserialize)]
+struct MerkleVectors {
+    depth: u32,
+    zeros: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct GoldenVectors {
+    poseidon: PoseidonSection,
+    merkle_zeros: MerkleVectors,
+}
+
+#[derive(Deserialize)]
+struct PoseidonSection {
+    inputs: Vec<PoseidonVector>,
+}
+
+fn parse_u256(env: &Env, s: &str) -> soroban_sdk::U256 {
+    let val = num_bigint::BigUint::parse_bytes(s.as_bytes(), 10).expect("invalid decimal");
+    let mut bytes = [0u8; 32];
+    let val_bytes = val.to_bytes_be();
+    let start = 32 - val_bytes.len();
+    bytes[start..].copy_from_slice(&val_bytes);
+    soroban_sdk::U256::from_be_bytes(env, &soroban_sdk::Bytes::from_array(env, &bytes))
+}
+
+fn load_vectors() -> GoldenVectors {
+    let data = include_str!("../../../circuits/utils/golden_vectors.json");
+    serde_json::from_str(data).expect("golden vectors json")
+}
+
+fn setup_tree(env: &Env) -> MembershipTreeClient<'_> {
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id, Address::generate(env)),
+    );
+    let tree_id: Address = env.register(membership_tree::MembershipTree, (sbt_id,));
+    MembershipTreeClient::new(env, &tree_id)
+}
+
+#[test]
+fn poseidon_golden_vectors_match_contract() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let tree = setup_tree(&env);
+
+    for v in load_vectors().poseidon.inputs {
+        let mut inputs = soroban_sdk::Vec::new(&env);
+        for x in v.inputs {
+            inputs.push_back(soroban_sdk::U256::from_u128(&env, x as u128));
+        }
+        let expected = parse_u256(&env, &v.expected);
+        assert_eq!(
+            tree.poseidon_hash(&inputs),
+            expected,
+            "poseidon vector {}",
+            v.label
+        );
+    }
+}
+
+#[test]
+fn merkle_zero_chaining_matches_vectors() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let tree = setup_tree(&env);
+    let vectors = load_vectors();
+
+    let mut current = parse_u256(&env, "0");
+    let mut zeros = vec![current.clone()];
+    for _ in 0..vectors.merkle_zeros.depth {
+        let pair = soroban_sdk::vec![&env, current.clone(), current.clone()];
+        current = tree.poseidon_hash(&pair);
+        zeros.push(current.clone());
+    }
+
+    assert_eq!(zeros.len(), vectors.merkle_zeros.zeros.len());
+    for (i, expected) in vectors.merkle_zeros.zeros.iter().enumerate() {
+        let expected_u = parse_u256(&env, expected);
+        assert_eq!(zeros[i], expected_u, "zero index {}", i);
+        assert_eq!(
+            tree.test_zero_at_level(&(i as u32)),
+            expected_u,
+            "contract zero level {}",
+            i
+        );
+    }
+}
+
+fn invalid_input() -> soroban_sdk::Error {
+    soroban_sdk::Error::from_contract_error(TreeError::InvalidHashInput as u32)
+}
+
+#[test]
+fn poseidon_hash_rejects_invalid_inputs() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let tree = setup_tree(&env);
+
+    let empty = soroban_sdk::Vec::new(&env);
+    assert_eq!(tree.try_poseidon_hash(&empty), Err(Ok(invalid_input())));
+
+    let mut six = soroban_sdk::Vec::new(&env);
+    for i in 1..=6u32 {
+        six.push_back(soroban_sdk::U256::from_u32(&env, i));
+    }
+    assert_eq!(tree.try_poseidon_hash(&six), Err(Ok(invalid_input())));
+
+    // BN254 scalar field modulus itself is out of range
+    let modulus = parse_u256(
+        &env,
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+    );
+    assert_eq!(
+        tree.try_poseidon_hash(&soroban_sdk::vec![&env, modulus]),
+        Err(Ok(invalid_input()))
+    );
+}
@@ -0,0 +1,256 @@
+// Member Group Tests
+//
+// Named groups (councils, committees) keep their own Merkle tree and root
+// history inside a DAO. Group proposals snapshot and validate against the
+// group's roots, so only group members can produce a valid membership proof.
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, U256};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::MembershipTreeClient;
+use voting::{Proof, VerificationKey, VoteMode, VotingClient, VotingError};
+
+const COUNCIL: u32 = 1;
+
+struct Setup<'a> {
+    env: Env,
+    dao_id: u64,
+    admin: Address,
+    sbt: MembershipSbtClient<'a>,
+    tree: MembershipTreeClient<'a>,
+    voting: VotingClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+    let voting = VotingClient::new(&env, &voting_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Group DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+    tree.create_group(&dao_id, &COUNCIL, &18, &admin);
+    voting.set_vk(&dao_id, &mock_vk(&env), &admin);
+
+    Setup {
+        env,
+        dao_id,
+        admin,
+        sbt,
+        tree,
+        voting,
+    }
+}
+
+fn g1(env: &Env) -> BytesN<64> {
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1;
+    bytes[63] = 2;
+    BytesN::from_array(env, &bytes)
+}
+
+fn g2(env: &Env) -> BytesN<128> {
+    let bytes: [u8; 128] = [
+        0x18, 0x00, 0x50, 0x6a, 0x06, 0x12, 0x86, 0xeb, 0x6a, 0x84, 0xa5, 0x73, 0x0b, 0x8f, 0x10,
+        0x29, 0x3e, 0x29, 0x81, 0x6c, 0xd1, 0x91, 0x3d, 0x53, 0x38, 0xf7, 0x15, 0xde, 0x3e, 0x98,
+        0xf9, 0xad, 0x19, 0x83, 0x90, 0x42, 0x11, 0xa5, 0x3f, 0x6e, 0x0b, 0x08, 0x53, 0xa9, 0x0a,
+        0x00, 0xef, 0xbf, 0xf1, 0x70, 0x0c, 0x7b, 0x1d, 0xc0, 0x06, 0x32, 0x4d, 0x85, 0x9d, 0x75,
+        0xe3, 0xca, 0xa5, 0xa2, 0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71,
+        0x8e, 0x80, 0x6a, 0x51, 0xa5, 0x66, 0x08, 0x21, 0x4c, 0x3f, 0x62, 0x8b, 0x96, 0x2c, 0xf1,
+        0x91, 0xea, 0xcd, 0xc8, 0x0e, 0x7a, 0x09, 0x0d, 0x97, 0xc0, 0x9c, 0xe1, 0x48, 0x60, 0x63,
+        0xb3, 0x59, 0xf3, 0xdd, 0x89, 0xb7, 0xc4, 0x3c, 0x5f, 0x18, 0x95, 0x8f, 0xb3, 0xe6, 0xb9,
+        0x6d, 0xb5, 0x5e, 0x19, 0xa3, 0xb7, 0xc0, 0xfb,
+    ];
+    BytesN::from_array(env, &bytes)
+}
+
+// Mock VK/proof; proof verification is bypassed in the testutils build
+fn mock_vk(env: &Env) -> VerificationKey {
+    VerificationKey {
+        alpha: g1(env),
+        beta: g2(env),
+        gamma: g2(env),
+        delta: g2(env),
+        ic: soroban_sdk::vec![env, g1(env), g1(env), g1(env), g1(env), g1(env), g1(env)],
+    }
+}
+
+fn mock_proof(env: &Env) -> Proof {
+    Proof {
+        a: g1(env),
+        b: g2(env),
+        c: g1(env),
+    }
+}
+
+fn voting_error(err: VotingError) -> soroban_sdk::Error {
+    soroban_sdk::Error::from_contract_error(err as u32)
+}
+
+#[test]
+fn test_group_proposal_snapshots_group_root() {
+    let s = setup();
+    let env = &s.env;
+
+    let councillor = Address::generate(env);
+    let member = Address::generate(env);
+    s.sbt.mint(&s.dao_id, &councillor, &s.admin, &None);
+    s.sbt.mint(&s.dao_id, &member, &s.admin, &None);
+    s.tree
+        .register_with_caller(&s.dao_id, &U256::from_u32(env, 11), &councillor);
+    s.tree
+        .register_with_caller(&s.dao_id, &U256::from_u32(env, 22), &member);
+
+    s.tree
+        .add_group_member(&s.dao_id, &COUNCIL, &councillor, &s.admin);
+    s.tree
+        .register_in_group(&s.dao_id, &COUNCIL, &U256::from_u32(env, 33), &councillor);
+
+    let council_root = s.tree.group_root(&s.dao_id, &COUNCIL);
+    let general_root = s.tree.current_root(&s.dao_id);
+    assert_ne!(council_root, general_root);
+
+    let proposal_id = s.voting.create_group_proposal(
+        &s.dao_id,
+        &COUNCIL,
+        &String::from_str(env, "Council budget"),
+        &String::from_str(env, ""),
+        &(env.ledger().timestamp() + 3600),
+        &councillor,
+        &VoteMode::Fixed,
+    );
+
+    let proposal = s.voting.get_proposal(&s.dao_id, &proposal_id);
+    assert_eq!(proposal.group_id, COUNCIL);
+    assert_eq!(proposal.eligible_root, council_root);
+    assert_eq!(s.voting.get_group(&s.dao_id, &proposal_id), COUNCIL);
+
+    // The general tree's root is not accepted for a council vote
+    let result = s.voting.try_vote(
+        &s.dao_id,
+        &proposal_id,
+        &true,
+        &U256::from_u32(env, 9001),
+        &general_root,
+        &mock_proof(env),
+    );
+    assert_eq!(result, Err(Ok(voting_error(VotingError::RootMismatch))));
+
+    s.voting.vote(
+        &s.dao_id,
+        &proposal_id,
+        &true,
+        &U256::from_u32(env, 9002),
+        &council_root,
+        &mock_proof(env),
+    );
+    let results = s.voting.get_results(&s.dao_id, &proposal_id);
+    assert_eq!(results, (1, 0));
+}
+
+#[test]
+fn test_trailing_group_proposal_uses_group_history() {
+    let s = setup();
+    let env = &s.env;
+
+    let first = Address::generate(env);
+    let late = Address::generate(env);
+    for who in [&first, &late] {
+        s.sbt.mint(&s.dao_id, who, &s.admin, &None);
+        s.tree.add_group_member(&s.dao_id, &COUNCIL, who, &s.admin);
+    }
+    s.tree
+        .register_in_group(&s.dao_id, &COUNCIL, &U256::from_u32(env, 44), &first);
+
+    let proposal_id = s.voting.create_group_proposal(
+        &s.dao_id,
+        &COUNCIL,
+        &String::from_str(env, "Trailing council vote"),
+        &String::from_str(env, ""),
+        &(env.ledger().timestamp() + 3600),
+        &first,
+        &VoteMode::Trailing,
+    );
+
+    // A late council registration is accepted in trailing mode
+    s.tree
+        .register_in_group(&s.dao_id, &COUNCIL, &U256::from_u32(env, 55), &late);
+    let late_root = s.tree.group_root(&s.dao_id, &COUNCIL);
+    s.voting.vote(
+        &s.dao_id,
+        &proposal_id,
+        &false,
+        &U256::from_u32(env, 9003),
+        &late_root,
+        &mock_proof(env),
+    );
+
+    // A general-tree root never appears in the council history
+    let outsider = Address::generate(env);
+    s.sbt.mint(&s.dao_id, &outsider, &s.admin, &None);
+    s.tree
+        .register_with_caller(&s.dao_id, &U256::from_u32(env, 66), &outsider);
+    let result = s.voting.try_vote(
+        &s.dao_id,
+        &proposal_id,
+        &true,
+        &U256::from_u32(env, 9004),
+        &s.tree.current_root(&s.dao_id),
+        &mock_proof(env),
+    );
+    assert_eq!(result, Err(Ok(voting_error(VotingError::RootNotInHistory))));
+}
+
+#[test]
+fn test_group_proposal_requires_group_member_or_admin() {
+    let s = setup();
+    let env = &s.env;
+
+    let member = Address::generate(env);
+    s.sbt.mint(&s.dao_id, &member, &s.admin, &None);
+
+    let result = s.voting.try_create_group_proposal(
+        &s.dao_id,
+        &COUNCIL,
+        &String::from_str(env, "Not a councillor"),
+        &String::from_str(env, ""),
+        &(env.ledger().timestamp() + 3600),
+        &member,
+        &VoteMode::Fixed,
+    );
+    assert_eq!(result, Err(Ok(voting_error(VotingError::NotGroupMember))));
+
+    // The DAO admin may open council proposals without being on the council
+    s.sbt.mint(&s.dao_id, &s.admin, &s.admin, &None);
+    let proposal_id = s.voting.create_group_proposal(
+        &s.dao_id,
+        &COUNCIL,
+        &String::from_str(env, "Admin council proposal"),
+        &String::from_str(env, ""),
+        &(env.ledger().timestamp() + 3600),
+        &s.admin,
+        &VoteMode::Fixed,
+    );
+    assert_eq!(s.voting.get_group(&s.dao_id, &proposal_id), COUNCIL);
+}
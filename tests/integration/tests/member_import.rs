@@ -0,0 +1,168 @@
+// Member Import Tests
+//
+// Admins onboarding an existing community use the tree's `import_members`, which mints an SBT
+// through the SBT's `mint_batch` and registers the supplied commitment in one call.
+
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String, U256};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::{MembershipTreeClient, TreeError};
+
+struct Setup<'a> {
+    env: Env,
+    dao_id: u64,
+    admin: Address,
+    sbt: MembershipSbtClient<'a>,
+    tree: MembershipTreeClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Imported DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+
+    Setup {
+        env,
+        dao_id,
+        admin,
+        sbt,
+        tree,
+    }
+}
+
+#[test]
+fn test_import_mints_and_registers() {
+    let s = setup();
+    let env = &s.env;
+
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    let carol = Address::generate(env);
+    let alias = String::from_str(env, "encrypted-alias");
+    let alice_commitment = U256::from_u32(env, 1111);
+    let carol_commitment = U256::from_u32(env, 3333);
+
+    let root_before = s.tree.current_root(&s.dao_id);
+    for entry in [
+        (alice.clone(), None, Some(alice_commitment.clone())),
+        (bob.clone(), Some(alias.clone()), None),
+        (carol.clone(), None, Some(carol_commitment.clone())),
+    ] {
+        let skipped = s
+            .tree
+            .import_members(&s.dao_id, &vec![env, entry], &s.admin);
+        assert!(skipped.is_empty());
+    }
+
+    assert!(s.sbt.has(&s.dao_id, &alice));
+    assert!(s.sbt.has(&s.dao_id, &bob));
+    assert!(s.sbt.has(&s.dao_id, &carol));
+    assert_eq!(s.sbt.get_member_count(&s.dao_id), 3);
+    assert_eq!(s.sbt.get_alias(&s.dao_id, &bob), Some(alias));
+    assert_eq!(s.tree.get_leaf_index(&s.dao_id, &alice_commitment), 0);
+    assert_eq!(s.tree.get_leaf_index(&s.dao_id, &carol_commitment), 1);
+    assert_ne!(s.tree.current_root(&s.dao_id), root_before);
+}
+
+#[test]
+fn test_import_skips_existing_members() {
+    let s = setup();
+    let env = &s.env;
+
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    s.sbt.mint(&s.dao_id, &alice, &s.admin, &None);
+
+    let skipped = s.tree.import_members(
+        &s.dao_id,
+        &vec![env, (alice.clone(), None, Some(U256::from_u32(env, 1111)))],
+        &s.admin,
+    );
+    assert_eq!(skipped, vec![env, alice.clone()]);
+    let skipped = s.tree.import_members(
+        &s.dao_id,
+        &vec![env, (bob.clone(), None, Some(U256::from_u32(env, 2222)))],
+        &s.admin,
+    );
+    assert!(skipped.is_empty());
+
+    // The skipped member's commitment is not registered on their behalf
+    assert!(s
+        .tree
+        .try_get_leaf_index(&s.dao_id, &U256::from_u32(env, 1111))
+        .is_err());
+    assert_eq!(
+        s.tree.get_leaf_index(&s.dao_id, &U256::from_u32(env, 2222)),
+        0
+    );
+}
+
+#[test]
+fn test_mint_batch_fits_with_aliases_and_term() {
+    let s = setup();
+    let env = &s.env;
+
+    // The costliest first mints: an alias under a published key plus an expiry each
+    s.sbt
+        .set_alias_key(&s.dao_id, &BytesN::from_array(env, &[7u8; 32]), &s.admin);
+    s.sbt.set_term(&s.dao_id, &86_400u64, &s.admin);
+    let alias = String::from_str(env, "encrypted-alias-0123456789abcdef");
+    let mut members = vec![env];
+    for _ in 0..6 {
+        members.push_back((Address::generate(env), Some(alias.clone())));
+    }
+    assert!(s.sbt.mint_batch(&s.dao_id, &members, &s.admin).is_empty());
+    assert_eq!(s.sbt.get_member_count(&s.dao_id), 6);
+}
+
+#[test]
+fn test_import_batch_limit() {
+    let s = setup();
+    let env = &s.env;
+
+    let mut members = vec![env];
+    for i in 0..2u32 {
+        members.push_back((
+            Address::generate(env),
+            None,
+            Some(U256::from_u32(env, 1000 + i)),
+        ));
+    }
+    assert_eq!(
+        s.tree.try_import_members(&s.dao_id, &members, &s.admin),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            TreeError::BatchTooLarge as u32
+        )))
+    );
+
+    // One fewer is exactly the limit
+    members.pop_back();
+    assert!(s
+        .tree
+        .import_members(&s.dao_id, &members, &s.admin)
+        .is_empty());
+    assert_eq!(s.sbt.get_member_count(&s.dao_id), 1);
+}
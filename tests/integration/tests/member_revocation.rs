@@ -54,8 +54,12 @@ const REAL_NULLIFIER_HEX: &str = "0cbc551a937e12107e513efd646a4f32eec3f0d2c13053
 fn setup_contracts(env: &Env) -> (Address, Address, Address, Address, Address) {
     // Deploy contracts
     let registry_id = env.register(dao_registry::DaoRegistry, ());
-    let sbt_id = env.register(membership_sbt::MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(env)),
+    );
     let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(env, &sbt_id).bind_tree(&tree_id);
     let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
 
     let admin = Address::generate(env);
@@ -125,8 +129,8 @@ fn test_admin_can_revoke_member() {
     println!("✅ Admin can successfully revoke member (leaf zeroed, new root created)");
 }
 
-/// Test that admin can reinstate a revoked member (allows re-registration)
-/// Note: reinstate_member clears the mapping so member can re-register, does NOT restore the leaf
+/// Test that admin can reinstate a revoked member in one step
+/// reinstate_member clears the SBT revocation and inserts the member's new commitment
 #[test]
 fn test_admin_can_reinstate_member() {
     let env = Env::default();
@@ -158,8 +162,11 @@ fn test_admin_can_reinstate_member() {
 
     let root_before_removal = tree_client.current_root(&dao_id);
 
-    // Remove member (zeros the leaf, changes root)
+    // Remove member (zeros the leaf, changes root, revokes SBT)
     tree_client.remove_member(&dao_id, &member, &admin);
+    assert!(!sbt_client.has(&dao_id, &member));
+    assert_eq!(sbt_client.active_member_count(&dao_id), 0);
+    assert_eq!(sbt_client.get_member_count(&dao_id), 1);
 
     let root_after_removal = tree_client.current_root(&dao_id);
     assert_ne!(
@@ -167,31 +174,25 @@ fn test_admin_can_reinstate_member() {
         "Root should change after removal"
     );
 
-    // Reinstate member (clears mapping so they can re-register)
-    // Note: This does NOT restore the leaf, root stays the same
-    tree_client.reinstate_member(&dao_id, &member, &admin);
-
-    let root_after_reinstate = tree_client.current_root(&dao_id);
+    // Reinstate with a new commitment: no re-mint or re-registration needed
+    let new_commitment = U256::from_u32(&env, 67890);
+    tree_client.reinstate_member(&dao_id, &member, &Some(new_commitment.clone()), &admin);
 
-    // Root does NOT change after reinstatement (leaf is still zeroed)
+    assert!(
+        sbt_client.has(&dao_id, &member),
+        "Reinstatement should clear the SBT revocation"
+    );
     assert_eq!(
-        root_after_removal, root_after_reinstate,
-        "Root should NOT change after reinstatement (leaf stays zeroed)"
+        sbt_client.get_active_members(&dao_id, &0u64, &10u64),
+        soroban_sdk::vec![&env, member.clone()]
     );
-
-    // Admin must re-mint SBT before member can re-register
-    // (remove_member also revokes SBT, reinstate_member does NOT restore it)
-    sbt_client.mint(&dao_id, &member, &admin, &None);
-
-    // Member can now re-register with a new commitment
-    let new_commitment = U256::from_u32(&env, 67890);
-    tree_client.register_with_caller(&dao_id, &new_commitment, &member);
-
-    let root_after_reregister = tree_client.current_root(&dao_id);
+    let root_after_reinstate = tree_client.current_root(&dao_id);
     assert_ne!(
-        root_after_reinstate, root_after_reregister,
-        "Root should change after re-registration"
+        root_after_removal, root_after_reinstate,
+        "Root should change after reinstatement (new leaf inserted)"
     );
+    assert_eq!(tree_client.get_leaf_index(&dao_id, &new_commitment), 1);
+    assert_eq!(tree_client.get_tree_usage(&dao_id).1, 1);
 
     // All roots should be valid in history
     assert!(
@@ -199,15 +200,14 @@ fn test_admin_can_reinstate_member() {
         "Original root should still be in history"
     );
     assert!(
-        tree_client.root_ok(&dao_id, &root_after_reregister),
+        tree_client.root_ok(&dao_id, &root_after_reinstate),
         "New root should be valid"
     );
 
-    println!("✅ Admin can reinstate revoked member (can re-register with new commitment)");
+    println!("✅ Admin can reinstate revoked member in one step");
 }
 
-/// Test multiple revoke/reinstate/re-register cycles
-/// Note: reinstate_member only clears the mapping so member can re-register
+/// Test multiple revoke/reinstate cycles, alternating new and re-enabled commitments
 #[test]
 fn test_multiple_revoke_reinstate_cycles() {
     let env = Env::default();
@@ -246,53 +246,34 @@ fn test_multiple_revoke_reinstate_cycles() {
         "Root should change after first revoke"
     );
 
-    // First reinstate (just clears mapping, root stays same)
-    tree_client.reinstate_member(&dao_id, &member, &admin);
-    let root_after_reinstate_1 = tree_client.current_root(&dao_id);
-    assert_eq!(
-        root_after_revoke_1, root_after_reinstate_1,
-        "Root should NOT change after reinstate"
-    );
-
-    // Re-mint SBT (remove_member revokes SBT, reinstate doesn't restore it)
-    sbt_client.mint(&dao_id, &member, &admin, &None);
-
-    // Re-register with new commitment
+    // First reinstate with a new commitment
     let commitment2 = U256::from_u32(&env, 67890);
-    tree_client.register_with_caller(&dao_id, &commitment2, &member);
-    let root_after_reregister = tree_client.current_root(&dao_id);
+    tree_client.reinstate_member(&dao_id, &member, &Some(commitment2.clone()), &admin);
+    let root_after_reinstate_1 = tree_client.current_root(&dao_id);
     assert_ne!(
-        root_after_reinstate_1, root_after_reregister,
-        "Root should change after re-registration"
+        root_after_revoke_1, root_after_reinstate_1,
+        "Root should change after reinstate"
     );
+    assert!(sbt_client.has(&dao_id, &member));
 
     // Second revoke
     tree_client.remove_member(&dao_id, &member, &admin);
     let root_after_revoke_2 = tree_client.current_root(&dao_id);
     assert_ne!(
-        root_after_reregister, root_after_revoke_2,
+        root_after_reinstate_1, root_after_revoke_2,
         "Root should change after second revoke"
     );
+    assert!(!sbt_client.has(&dao_id, &member));
 
-    // Second reinstate (clears mapping again)
-    tree_client.reinstate_member(&dao_id, &member, &admin);
-    let root_after_reinstate_2 = tree_client.current_root(&dao_id);
-    assert_eq!(
-        root_after_revoke_2, root_after_reinstate_2,
-        "Root should NOT change after second reinstate"
-    );
-
-    // Re-mint SBT again for second cycle
-    sbt_client.mint(&dao_id, &member, &admin, &None);
-
-    // Re-register again
-    let commitment3 = U256::from_u32(&env, 11111);
-    tree_client.register_with_caller(&dao_id, &commitment3, &member);
+    // Second reinstate re-enables commitment2 at a fresh index
+    tree_client.reinstate_member(&dao_id, &member, &None, &admin);
     let final_root = tree_client.current_root(&dao_id);
     assert_ne!(
-        root_after_reinstate_2, final_root,
-        "Root should change after third registration"
+        root_after_revoke_2, final_root,
+        "Root should change after second reinstate"
     );
+    assert_eq!(tree_client.get_leaf_index(&dao_id, &commitment2), 2);
+    assert!(sbt_client.has(&dao_id, &member));
 
     // All historical roots should be valid
     assert!(
@@ -304,7 +285,7 @@ fn test_multiple_revoke_reinstate_cycles() {
         "Final root should be valid"
     );
 
-    println!("✅ Multiple revoke/reinstate/re-register cycles work correctly");
+    println!("✅ Multiple revoke/reinstate cycles work correctly");
 }
 
 /// Test that only admin can remove members
@@ -377,7 +358,7 @@ fn test_only_admin_can_reinstate_member() {
     tree_client.remove_member(&dao_id, &member, &admin);
 
     // Try to reinstate as non-admin (should fail)
-    tree_client.reinstate_member(&dao_id, &member, &non_admin);
+    tree_client.reinstate_member(&dao_id, &member, &None, &non_admin);
 }
 
 /// Member revoked after proposal creation cannot vote.
@@ -480,7 +461,7 @@ fn test_revoked_then_reinstated_only_new_proposals_accept_vote() {
     // Revoke then reinstate before creating Proposal B
     tree_client.remove_member(&dao_id, &member, &admin);
     env.ledger().with_mut(|li| li.timestamp += 10);
-    tree_client.reinstate_member(&dao_id, &member, &admin);
+    tree_client.reinstate_member(&dao_id, &member, &None, &admin);
 
     env.ledger().with_mut(|li| li.timestamp += 10);
     let proposal_b = voting_client.create_proposal(
@@ -502,3 +483,107 @@ fn test_revoked_then_reinstated_only_new_proposals_accept_vote() {
     // Vote on proposal A should panic due to revocation during its lifetime
     voting_client.vote(&dao_id, &proposal_a, &true, &nullifier, &root, &proof);
 }
+
+/// Leaving voluntarily zeroes the member's leaf like an admin removal, so
+/// Trailing-mode votes with a pre-departure root are rejected.
+#[test]
+fn test_leave_zeroes_leaf_and_invalidates_old_roots() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let (registry_id, sbt_id, tree_id, voting_id, admin) = setup_contracts(&env);
+
+    let registry_client = DaoRegistryClient::new(&env, &registry_id);
+    let sbt_client = MembershipSbtClient::new(&env, &sbt_id);
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+    let voting_client = VotingClient::new(&env, &voting_id);
+    assert_eq!(sbt_client.tree(), Some(tree_id.clone()));
+
+    let dao_id = registry_client.create_dao(
+        &String::from_str(&env, "Leave DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree_client.init_tree(&dao_id, &18, &admin);
+    voting_client.set_vk(&dao_id, &get_real_vk(&env), &admin);
+
+    let member = Address::generate(&env);
+    sbt_client.mint(&dao_id, &member, &admin, &None);
+    let commitment = hex_str_to_u256(&env, REAL_COMMITMENT_HEX);
+    tree_client.register_with_caller(&dao_id, &commitment, &member);
+    let root_before_leave = tree_client.current_root(&dao_id);
+
+    let proposal_id = voting_client.create_proposal(
+        &dao_id,
+        &String::from_str(&env, "Trailing vote"),
+        &String::from_str(&env, ""),
+        &(env.ledger().timestamp() + 3600),
+        &member,
+        &VoteMode::Trailing,
+    );
+
+    sbt_client.leave(&dao_id, &member);
+    assert!(!sbt_client.has(&dao_id, &member));
+    assert_eq!(tree_client.get_tree_usage(&dao_id).1, 0);
+    assert_eq!(tree_client.min_root(&dao_id), tree_client.curr_idx(&dao_id));
+
+    let result = voting_client.try_vote(
+        &dao_id,
+        &proposal_id,
+        &true,
+        &hex_str_to_u256(&env, REAL_NULLIFIER_HEX),
+        &root_before_leave,
+        &get_real_proof(&env),
+    );
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            voting::VotingError::RootPredatesRemoval as u32
+        )))
+    );
+}
+
+/// A member who left can be reinstated with their old commitment re-enabled.
+#[test]
+fn test_reinstate_after_leave_reenables_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let (registry_id, sbt_id, tree_id, _voting_id, admin) = setup_contracts(&env);
+
+    let registry_client = DaoRegistryClient::new(&env, &registry_id);
+    let sbt_client = MembershipSbtClient::new(&env, &sbt_id);
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+
+    let dao_id = registry_client.create_dao(
+        &String::from_str(&env, "Return DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree_client.init_tree(&dao_id, &18, &admin);
+
+    let member = Address::generate(&env);
+    sbt_client.mint(&dao_id, &member, &admin, &None);
+    let commitment = U256::from_u32(&env, 4242);
+    tree_client.register_with_caller(&dao_id, &commitment, &member);
+
+    sbt_client.leave(&dao_id, &member);
+    assert_eq!(tree_client.get_tree_usage(&dao_id).1, 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    tree_client.reinstate_member(&dao_id, &member, &None, &admin);
+
+    assert!(sbt_client.has(&dao_id, &member));
+    assert_eq!(tree_client.get_tree_usage(&dao_id).1, 1);
+    assert_eq!(tree_client.get_leaf_index(&dao_id, &commitment), 1);
+    assert_eq!(
+        tree_client.reinst_at(&dao_id, &commitment),
+        Some(env.ledger().timestamp())
+    );
+}
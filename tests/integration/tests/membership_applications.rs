@@ -0,0 +1,130 @@
+// Membership Application Tests
+//
+// Outsiders ask to join a closed DAO with the SBT's `apply`. The admin approves through the
+// tree's `approve_application`, which mints the SBT and registers the applicant's commitment
+// in one call, or rejects with the SBT's `reject`.
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, U256};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::{MembershipSbtClient, SbtError};
+use membership_tree::MembershipTreeClient;
+
+fn setup(
+    env: &Env,
+) -> (
+    u64,
+    Address,
+    MembershipSbtClient<'_>,
+    MembershipTreeClient<'_>,
+) {
+    let env = env.clone();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Closed DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+
+    (dao_id, admin, sbt, tree)
+}
+
+#[test]
+fn test_approved_application_mints_and_registers() {
+    let env = Env::default();
+    let (dao_id, admin, sbt, tree) = setup(&env);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let cid = String::from_str(&env, "QmWhyIWantToJoin");
+    let alice_commitment = U256::from_u32(&env, 1111);
+
+    sbt.apply(&dao_id, &alice, &cid, &Some(alice_commitment.clone()));
+    sbt.apply(&dao_id, &bob, &cid, &None);
+    sbt.apply(&dao_id, &carol, &cid, &None);
+    assert_eq!(sbt.pending_applications(&dao_id, &0u32, &10u32).len(), 3);
+
+    let root_before = tree.current_root(&dao_id);
+    tree.approve_application(&dao_id, &alice, &admin);
+    assert!(sbt.has(&dao_id, &alice));
+    assert_eq!(tree.get_leaf_index(&dao_id, &alice_commitment), 0);
+    assert_ne!(tree.current_root(&dao_id), root_before);
+
+    // Without a commitment only the SBT is minted; the member registers later
+    let root_before = tree.current_root(&dao_id);
+    tree.approve_application(&dao_id, &bob, &admin);
+    assert!(sbt.has(&dao_id, &bob));
+    assert_eq!(tree.current_root(&dao_id), root_before);
+
+    sbt.reject(&dao_id, &carol, &admin);
+    assert!(!sbt.has(&dao_id, &carol));
+    assert_eq!(
+        tree.try_approve_application(&dao_id, &carol, &admin),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            SbtError::NoApplication as u32
+        )))
+    );
+    assert_eq!(sbt.application_count(&dao_id), 0);
+}
+
+#[test]
+fn test_flooded_queue_is_cleared_in_batches() {
+    let env = Env::default();
+    let (dao_id, admin, sbt, _) = setup(&env);
+    let cid = String::from_str(&env, "QmSpam");
+
+    let mut applicants = vec![&env];
+    for _ in 0..100 {
+        let applicant = Address::generate(&env);
+        sbt.apply(&dao_id, &applicant, &cid, &None);
+        applicants.push_back(applicant);
+    }
+    // A full queue costs the same to touch as an empty one
+    assert!(env.cost_estimate().resources().write_entries <= 5);
+    assert_eq!(
+        sbt.try_apply(&dao_id, &Address::generate(&env), &cid, &None),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            SbtError::ApplicationQueueFull as u32
+        )))
+    );
+
+    assert_eq!(
+        sbt.try_reject_batch(&dao_id, &applicants.slice(0..9), &admin),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            SbtError::BatchTooLarge as u32
+        )))
+    );
+    sbt.reject_batch(&dao_id, &applicants.slice(0..8), &admin);
+    assert_eq!(sbt.application_count(&dao_id), 92);
+    assert_eq!(sbt.application(&dao_id, &applicants.get_unchecked(0)), None);
+
+    // Every remaining application is still listed exactly once
+    let mut pending = sbt.pending_applications(&dao_id, &0u32, &46u32);
+    pending.append(&sbt.pending_applications(&dao_id, &46u32, &46u32));
+    assert_eq!(pending.len(), 92);
+    for applicant in applicants.slice(8..100).iter() {
+        assert!(pending
+            .iter()
+            .any(|application| application.applicant == applicant));
+    }
+    sbt.apply(&dao_id, &Address::generate(&env), &cid, &None);
+}
@@ -0,0 +1,164 @@
+// Membership Expiry Tests
+//
+// DAOs created with `init_expiring_tree` bake each member's SBT expiry into their
+// leaf. Lapsed members drop out without an admin removal, and renewal rebakes the leaf.
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String, U256,
+};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::{MembershipTreeClient, TreeError, NO_EXPIRY};
+use voting::{Proof, VerificationKey, VoteMode, VotingClient};
+
+const YEAR: u64 = 365 * 24 * 3600;
+
+fn g1(env: &Env) -> BytesN<64> {
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1;
+    bytes[63] = 2;
+    BytesN::from_array(env, &bytes)
+}
+
+fn g2(env: &Env) -> BytesN<128> {
+    let bytes: [u8; 128] = [
+        0x18, 0x00, 0x50, 0x6a, 0x06, 0x12, 0x86, 0xeb, 0x6a, 0x84, 0xa5, 0x73, 0x0b, 0x8f, 0x10,
+        0x29, 0x3e, 0x29, 0x81, 0x6c, 0xd1, 0x91, 0x3d, 0x53, 0x38, 0xf7, 0x15, 0xde, 0x3e, 0x98,
+        0xf9, 0xad, 0x19, 0x83, 0x90, 0x42, 0x11, 0xa5, 0x3f, 0x6e, 0x0b, 0x08, 0x53, 0xa9, 0x0a,
+        0x00, 0xef, 0xbf, 0xf1, 0x70, 0x0c, 0x7b, 0x1d, 0xc0, 0x06, 0x32, 0x4d, 0x85, 0x9d, 0x75,
+        0xe3, 0xca, 0xa5, 0xa2, 0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71,
+        0x8e, 0x80, 0x6a, 0x51, 0xa5, 0x66, 0x08, 0x21, 0x4c, 0x3f, 0x62, 0x8b, 0x96, 0x2c, 0xf1,
+        0x91, 0xea, 0xcd, 0xc8, 0x0e, 0x7a, 0x09, 0x0d, 0x97, 0xc0, 0x9c, 0xe1, 0x48, 0x60, 0x63,
+        0xb3, 0x59, 0xf3, 0xdd, 0x89, 0xb7, 0xc4, 0x3c, 0x5f, 0x18, 0x95, 0x8f, 0xb3, 0xe6, 0xb9,
+        0x6d, 0xb5, 0x5e, 0x19, 0xa3, 0xb7, 0xc0, 0xfb,
+    ];
+    BytesN::from_array(env, &bytes)
+}
+
+// Mock VK/proof; proof verification is bypassed in the testutils build
+fn mock_expiry_vk(env: &Env) -> VerificationKey {
+    VerificationKey {
+        alpha: g1(env),
+        beta: g2(env),
+        gamma: g2(env),
+        delta: g2(env),
+        // 7 IC points: 6 public signals including the snapshot time
+        ic: soroban_sdk::vec![
+            env,
+            g1(env),
+            g1(env),
+            g1(env),
+            g1(env),
+            g1(env),
+            g1(env),
+            g1(env)
+        ],
+    }
+}
+
+fn mock_proof(env: &Env) -> Proof {
+    Proof {
+        a: g1(env),
+        b: g2(env),
+        c: g1(env),
+    }
+}
+
+#[test]
+fn test_expiring_dao_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    env.ledger().set_timestamp(1_000_000);
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+    let voting = VotingClient::new(&env, &voting_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Annual Club"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_expiring_tree(&dao_id, &18, &admin);
+    voting.set_vk(&dao_id, &mock_expiry_vk(&env), &admin);
+
+    // Founding admin is permanent; members minted after set_term get annual SBTs
+    sbt.mint(&dao_id, &admin, &admin, &None);
+    sbt.set_term(&dao_id, &YEAR, &admin);
+    let member = Address::generate(&env);
+    sbt.mint(&dao_id, &member, &admin, &None);
+    assert_eq!(sbt.expires_at(&dao_id, &admin), None);
+    assert_eq!(sbt.expires_at(&dao_id, &member), Some(1_000_000 + YEAR));
+
+    let commitment = U256::from_u32(&env, 4242);
+    tree.register_with_caller(&dao_id, &commitment, &member);
+    tree.register_with_caller(&dao_id, &U256::from_u32(&env, 4343), &admin);
+
+    // Leaf 0 commits to the member's expiry, leaf 1 to NO_EXPIRY
+    let (elements, _) = tree.get_merkle_path(&dao_id, &0u64);
+    let admin_leaf = tree.poseidon_hash(&soroban_sdk::vec![
+        &env,
+        U256::from_u32(&env, 4343),
+        U256::from_u128(&env, NO_EXPIRY as u128)
+    ]);
+    assert_eq!(elements.get(0).unwrap(), admin_leaf);
+
+    // Votes carry the proposal's creation time as the expiry snapshot
+    let proposal_id = voting.create_proposal(
+        &dao_id,
+        &String::from_str(&env, "Renew the lease"),
+        &String::from_str(&env, ""),
+        &0u64,
+        &admin,
+        &VoteMode::Trailing,
+    );
+    assert_eq!(voting.get_created_at(&dao_id, &proposal_id), 1_000_000);
+    voting.vote(
+        &dao_id,
+        &proposal_id,
+        &true,
+        &U256::from_u32(&env, 77),
+        &tree.current_root(&dao_id),
+        &mock_proof(&env),
+    );
+
+    // After a year the SBT lapses without any admin action
+    env.ledger().set_timestamp(1_000_000 + YEAR);
+    assert!(!sbt.has(&dao_id, &member));
+    assert_eq!(
+        tree.try_renew_leaf(&dao_id, &member),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            TreeError::NoSbt as u32
+        )))
+    );
+
+    // Renewal restores the SBT and rebakes the leaf with the new expiry
+    let root_before = tree.current_root(&dao_id);
+    sbt.renew(&dao_id, &member, &(1_000_000 + 2 * YEAR), &admin);
+    assert!(sbt.has(&dao_id, &member));
+    tree.renew_leaf(&dao_id, &member);
+    assert_ne!(tree.current_root(&dao_id), root_before);
+    let renewed_leaf = tree.poseidon_hash(&soroban_sdk::vec![
+        &env,
+        commitment,
+        U256::from_u128(&env, (1_000_000 + 2 * YEAR) as u128)
+    ]);
+    let (elements, _) = tree.get_merkle_path(&dao_id, &1u64);
+    assert_eq!(elements.get(0).unwrap(), renewed_leaf);
+}
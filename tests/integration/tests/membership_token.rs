@@ -0,0 +1,74 @@
+// Membership Token View Tests
+//
+// A `MembershipToken` deployed for a DAO shows its SBTs through the standard SEP-41
+// interface, so wallets list the membership as a non-transferable balance of 1.
+
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env, String};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_token::TokenError;
+
+#[test]
+fn test_wallet_view_of_membership() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Wallet DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+
+    let token_id = env.register(membership_token::MembershipToken, (sbt_id.clone(), dao_id));
+    let token = TokenClient::new(&env, &token_id);
+    assert_eq!(token.name(), String::from_str(&env, "Wallet DAO"));
+    assert_eq!(token.symbol(), String::from_str(&env, "ZKV1"));
+    assert_eq!(token.decimals(), 0);
+
+    let alice = Address::generate(&env);
+    assert_eq!(token.balance(&alice), 0);
+    sbt.mint(&dao_id, &alice, &admin, &None);
+    assert_eq!(token.balance(&alice), 1);
+
+    let bob = Address::generate(&env);
+    assert_eq!(
+        token.try_transfer(&alice, &bob, &1),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            TokenError::Soulbound as u32
+        )))
+    );
+
+    sbt.revoke(&dao_id, &alice, &admin);
+    assert_eq!(token.balance(&alice), 0);
+}
+
+#[test]
+fn test_view_requires_existing_dao() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id, Address::generate(&env)),
+    );
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        env.register(membership_token::MembershipToken, (sbt_id, 7u64))
+    }));
+    assert!(result.is_err());
+}
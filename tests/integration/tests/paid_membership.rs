@@ -0,0 +1,72 @@
+// Paid Membership Tests
+//
+// The registry's `set_dues` makes `self_join` charge a join fee plus the first period of
+// dues in a token contract. `pay_dues` extends the SBT's expiry; unpaid dues lapse it.
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    token, Address, Env, String,
+};
+
+use dao_registry::{DaoRegistryClient, DuesConfig};
+use membership_sbt::MembershipSbtClient;
+
+#[test]
+fn test_self_join_charges_fees_and_dues_lapse() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    env.ledger().set_timestamp(1_000);
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Paid DAO"),
+        &admin,
+        &true,
+        &true,
+        &None,
+    );
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let usdc = token::Client::new(&env, &sac.address());
+    let alice = Address::generate(&env);
+    let broke = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&alice, &500);
+
+    registry.set_dues(
+        &dao_id,
+        &Some(DuesConfig {
+            token: sac.address(),
+            treasury: treasury.clone(),
+            join_fee: 200,
+            dues: 100,
+            period: 30 * 86_400,
+        }),
+        &admin,
+    );
+
+    sbt.self_join(&dao_id, &alice, &None);
+    assert_eq!(usdc.balance(&treasury), 300);
+    assert_eq!(sbt.expires_at(&dao_id, &alice), Some(1_000 + 30 * 86_400));
+
+    // Without funds the token transfer fails and nothing is minted
+    assert!(sbt.try_self_join(&dao_id, &broke, &None).is_err());
+    assert!(!sbt.has(&dao_id, &broke));
+
+    env.ledger().set_timestamp(1_000 + 31 * 86_400);
+    assert!(!sbt.has(&dao_id, &alice));
+    sbt.mark_lapsed(&dao_id, &alice);
+
+    sbt.pay_dues(&dao_id, &alice, &1u32);
+    assert!(sbt.has(&dao_id, &alice));
+    assert_eq!(usdc.balance(&alice), 100);
+}
@@ -118,10 +118,14 @@ fn test_pairing_security_boundary() {
     let registry_address = env.register(dao_registry::DaoRegistry, ());
     let registry_client = DaoRegistryClient::new(&env, &registry_address);
 
-    let sbt_address = env.register(membership_sbt::MembershipSbt, (registry_address.clone(),));
+    let sbt_address = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_address.clone(), Address::generate(&env)),
+    );
     let sbt_client = MembershipSbtClient::new(&env, &sbt_address);
 
     let tree_address = env.register(membership_tree::MembershipTree, (sbt_address.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_address).bind_tree(&tree_address);
     let tree_client = MembershipTreeClient::new(&env, &tree_address);
 
     let voting_address = env.register(
@@ -61,8 +61,12 @@ fn test_empty_tree_root() {
 
     // Deploy contracts
     let registry_id = env.register(dao_registry::DaoRegistry, ());
-    let sbt_id = env.register(membership_sbt::MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
     let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
 
     let admin = Address::generate(&env);
 
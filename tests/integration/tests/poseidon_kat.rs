@@ -15,7 +15,10 @@ use membership_tree::MembershipTreeClient;
 fn setup_contracts(env: &Env) -> (Address, Address, Address, Address) {
     // Deploy contracts
     let registry_id = env.register(dao_registry::DaoRegistry, ());
-    let sbt_id = env.register(membership_sbt::MembershipSbt, (registry_id.clone(),));
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(env)),
+    );
     let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
 
     let admin = Address::generate(env);
@@ -248,3 +251,33 @@ fn test_poseidon_zero_leaf_consistency() {
 
     println!("\n✅ Zero leaf consistency test passed!");
 }
+
+#[test]
+fn test_poseidon_kat_commitment_via_hash_view() {
+    // The multi-arity view must reproduce the circomlib commitment used above
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let (_, _, tree_id, _) = setup_contracts(&env);
+    let tree_client = MembershipTreeClient::new(&env, &tree_id);
+
+    let commitment_bytes: [u8; 32] = [
+        0x19, 0x14, 0x87, 0x9b, 0x2a, 0x4e, 0x7f, 0x95, 0x55, 0xf3, 0xeb, 0x55, 0x83, 0x72, 0x43,
+        0xce, 0xfb, 0x13, 0x66, 0xa6, 0x92, 0x79, 0x4a, 0x7e, 0x5b, 0x5b, 0x31, 0x81, 0xfb, 0x14,
+        0xb4, 0x9b,
+    ];
+    let commitment = U256::from_be_bytes(&env, &Bytes::from_array(&env, &commitment_bytes));
+
+    let inputs = soroban_sdk::vec![
+        &env,
+        U256::from_u32(&env, 12345),
+        U256::from_u32(&env, 67890)
+    ];
+    assert_eq!(tree_client.poseidon_hash(&inputs), commitment);
+
+    // The two-input view and the tree's internal pair hash agree
+    assert_eq!(
+        tree_client.test_poseidon_hash(&U256::from_u32(&env, 12345), &U256::from_u32(&env, 67890)),
+        commitment
+    );
+}
@@ -0,0 +1,153 @@
+// Removal Timelock Tests
+//
+// With `set_removal_delay`, `remove_member` only schedules a removal. It is visible via
+// `scheduled_removals`, cancellable by the admin, and executable by anyone once the delay
+// has passed, so an admin cannot disenfranchise voters right before a vote closes.
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, BytesN, Env, String, U256,
+};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::MembershipTreeClient;
+use voting::{Proof, VerificationKey, VoteMode, VotingClient, VotingError};
+
+fn g1(env: &Env) -> BytesN<64> {
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1;
+    bytes[63] = 2;
+    BytesN::from_array(env, &bytes)
+}
+
+fn g2(env: &Env) -> BytesN<128> {
+    let bytes: [u8; 128] = [
+        0x18, 0x00, 0x50, 0x6a, 0x06, 0x12, 0x86, 0xeb, 0x6a, 0x84, 0xa5, 0x73, 0x0b, 0x8f, 0x10,
+        0x29, 0x3e, 0x29, 0x81, 0x6c, 0xd1, 0x91, 0x3d, 0x53, 0x38, 0xf7, 0x15, 0xde, 0x3e, 0x98,
+        0xf9, 0xad, 0x19, 0x83, 0x90, 0x42, 0x11, 0xa5, 0x3f, 0x6e, 0x0b, 0x08, 0x53, 0xa9, 0x0a,
+        0x00, 0xef, 0xbf, 0xf1, 0x70, 0x0c, 0x7b, 0x1d, 0xc0, 0x06, 0x32, 0x4d, 0x85, 0x9d, 0x75,
+        0xe3, 0xca, 0xa5, 0xa2, 0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71,
+        0x8e, 0x80, 0x6a, 0x51, 0xa5, 0x66, 0x08, 0x21, 0x4c, 0x3f, 0x62, 0x8b, 0x96, 0x2c, 0xf1,
+        0x91, 0xea, 0xcd, 0xc8, 0x0e, 0x7a, 0x09, 0x0d, 0x97, 0xc0, 0x9c, 0xe1, 0x48, 0x60, 0x63,
+        0xb3, 0x59, 0xf3, 0xdd, 0x89, 0xb7, 0xc4, 0x3c, 0x5f, 0x18, 0x95, 0x8f, 0xb3, 0xe6, 0xb9,
+        0x6d, 0xb5, 0x5e, 0x19, 0xa3, 0xb7, 0xc0, 0xfb,
+    ];
+    BytesN::from_array(env, &bytes)
+}
+
+// Mock VK/proof; proof verification is bypassed in the testutils build
+fn mock_vk(env: &Env) -> VerificationKey {
+    let mut ic = soroban_sdk::Vec::new(env);
+    for _ in 0..6 {
+        ic.push_back(g1(env));
+    }
+    VerificationKey {
+        alpha: g1(env),
+        beta: g2(env),
+        gamma: g2(env),
+        delta: g2(env),
+        ic,
+    }
+}
+
+fn mock_proof(env: &Env) -> Proof {
+    Proof {
+        a: g1(env),
+        b: g2(env),
+        c: g1(env),
+    }
+}
+
+#[test]
+fn test_scheduled_removal_cannot_cut_off_a_closing_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    env.ledger().set_timestamp(1_000);
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+    let voting = VotingClient::new(&env, &voting_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Timelocked DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+    tree.set_removal_delay(&dao_id, &86_400u64, &admin);
+    voting.set_vk(&dao_id, &mock_vk(&env), &admin);
+
+    let alice = Address::generate(&env);
+    let mallory = Address::generate(&env);
+    sbt.mint(&dao_id, &admin, &admin, &None);
+    sbt.mint(&dao_id, &alice, &admin, &None);
+    sbt.mint(&dao_id, &mallory, &admin, &None);
+    tree.register_with_caller(&dao_id, &U256::from_u32(&env, 1111), &alice);
+    tree.register_with_caller(&dao_id, &U256::from_u32(&env, 6666), &mallory);
+    let root = tree.current_root(&dao_id);
+
+    let title = String::from_str(&env, "Dissolve the council");
+    let cid = String::from_str(&env, "");
+    let proposal = voting.create_proposal(
+        &dao_id,
+        &title,
+        &cid,
+        &2_000u64,
+        &admin,
+        &VoteMode::Trailing,
+    );
+
+    // A removal right before the deadline is only scheduled; alice still votes
+    env.ledger().set_timestamp(1_990);
+    tree.remove_member(&dao_id, &alice, &admin);
+    assert_eq!(
+        tree.scheduled_removals(&dao_id).get(alice.clone()),
+        Some(1_990 + 86_400)
+    );
+    voting.vote(
+        &dao_id,
+        &proposal,
+        &false,
+        &U256::from_u32(&env, 77),
+        &root,
+        &mock_proof(&env),
+    );
+
+    // Once the delay has passed anyone can execute it, invalidating older roots
+    let later = voting.create_proposal(&dao_id, &title, &cid, &0u64, &admin, &VoteMode::Trailing);
+    env.ledger().set_timestamp(1_990 + 86_400);
+    tree.execute_removal(&dao_id, &alice);
+    assert!(!sbt.has(&dao_id, &alice));
+    assert_eq!(
+        voting.try_vote(
+            &dao_id,
+            &later,
+            &true,
+            &U256::from_u32(&env, 78),
+            &root,
+            &mock_proof(&env),
+        ),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            VotingError::RootPredatesRemoval as u32
+        )))
+    );
+
+    // An emergency removal skips the delay
+    tree.emergency_remove(&dao_id, &mallory, &admin);
+    assert!(!sbt.has(&dao_id, &mallory));
+}
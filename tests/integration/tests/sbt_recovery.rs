@@ -0,0 +1,92 @@
+// SBT Recovery Tests
+//
+// `recover` moves a membership to a new address with the admin's and the old address's (or a
+// guardian quorum's) approval. The tree re-keys the member's leaves without touching any
+// commitment, so existing roots, proofs and pending votes stay valid.
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String, U256};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::{Guardians, MembershipSbtClient};
+use membership_tree::{MembershipTreeClient, TreeError};
+
+#[test]
+fn test_guardians_recover_member_keeping_leaf() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Recovery DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+    tree.create_group(&dao_id, &1u32, &10u32, &admin);
+
+    let alice = Address::generate(&env);
+    let guardian_a = Address::generate(&env);
+    let guardian_b = Address::generate(&env);
+    sbt.mint(&dao_id, &alice, &admin, &None);
+    let commitment = U256::from_u32(&env, 1111);
+    tree.register_with_caller(&dao_id, &commitment, &alice);
+    tree.add_group_member(&dao_id, &1u32, &alice, &admin);
+    tree.register_in_group(&dao_id, &1u32, &U256::from_u32(&env, 2222), &alice);
+    sbt.set_guardians(
+        &dao_id,
+        &alice,
+        &Some(Guardians {
+            guardians: soroban_sdk::vec![&env, guardian_a.clone(), guardian_b.clone()],
+            threshold: 2,
+        }),
+    );
+
+    let root = tree.current_root(&dao_id);
+    let group_root = tree.group_root(&dao_id, &1u32);
+
+    let new_wallet = Address::generate(&env);
+    sbt.recover(
+        &dao_id,
+        &alice,
+        &new_wallet,
+        &0u64,
+        &soroban_sdk::vec![&env, guardian_a, guardian_b],
+        &admin,
+    );
+
+    // Same leaves, same roots, new owner
+    assert_eq!(tree.current_root(&dao_id), root);
+    assert_eq!(tree.group_root(&dao_id, &1u32), group_root);
+    assert_eq!(tree.get_leaf_index(&dao_id, &commitment), 0);
+    assert!(tree.is_group_member(&dao_id, &1u32, &new_wallet));
+    assert!(!tree.is_group_member(&dao_id, &1u32, &alice));
+    assert_eq!(
+        sbt.get_active_members(&dao_id, &0u64, &10u64),
+        soroban_sdk::vec![&env, new_wallet.clone()]
+    );
+
+    // The old address no longer owns anything; the new one can be removed as usual
+    assert_eq!(
+        tree.try_remove_member(&dao_id, &alice, &admin),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            TreeError::MemberNotInTree as u32
+        )))
+    );
+    tree.remove_member(&dao_id, &new_wallet, &admin);
+    assert!(!sbt.has(&dao_id, &new_wallet));
+    assert_ne!(tree.current_root(&dao_id), root);
+}
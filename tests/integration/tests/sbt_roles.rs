@@ -0,0 +1,121 @@
+// SBT Role Tests
+//
+// Roles live on the SBT (`set_role`) and the registry maps admin actions to a minimum role
+// (`set_action_role`). Voting then admits role holders alongside the DAO admin.
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+use dao_registry::{DaoRegistryClient, ACTION_CLOSE, ACTION_PROPOSE};
+use membership_sbt::{MembershipSbtClient, ROLE_COUNCIL, ROLE_MODERATOR};
+use membership_tree::MembershipTreeClient;
+use voting::{ProposalState, VerificationKey, VoteMode, VotingClient, VotingError};
+
+fn g1(env: &Env) -> BytesN<64> {
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1;
+    bytes[63] = 2;
+    BytesN::from_array(env, &bytes)
+}
+
+fn g2(env: &Env) -> BytesN<128> {
+    let bytes: [u8; 128] = [
+        0x18, 0x00, 0x50, 0x6a, 0x06, 0x12, 0x86, 0xeb, 0x6a, 0x84, 0xa5, 0x73, 0x0b, 0x8f, 0x10,
+        0x29, 0x3e, 0x29, 0x81, 0x6c, 0xd1, 0x91, 0x3d, 0x53, 0x38, 0xf7, 0x15, 0xde, 0x3e, 0x98,
+        0xf9, 0xad, 0x19, 0x83, 0x90, 0x42, 0x11, 0xa5, 0x3f, 0x6e, 0x0b, 0x08, 0x53, 0xa9, 0x0a,
+        0x00, 0xef, 0xbf, 0xf1, 0x70, 0x0c, 0x7b, 0x1d, 0xc0, 0x06, 0x32, 0x4d, 0x85, 0x9d, 0x75,
+        0xe3, 0xca, 0xa5, 0xa2, 0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71,
+        0x8e, 0x80, 0x6a, 0x51, 0xa5, 0x66, 0x08, 0x21, 0x4c, 0x3f, 0x62, 0x8b, 0x96, 0x2c, 0xf1,
+        0x91, 0xea, 0xcd, 0xc8, 0x0e, 0x7a, 0x09, 0x0d, 0x97, 0xc0, 0x9c, 0xe1, 0x48, 0x60, 0x63,
+        0xb3, 0x59, 0xf3, 0xdd, 0x89, 0xb7, 0xc4, 0x3c, 0x5f, 0x18, 0x95, 0x8f, 0xb3, 0xe6, 0xb9,
+        0x6d, 0xb5, 0x5e, 0x19, 0xa3, 0xb7, 0xc0, 0xfb,
+    ];
+    BytesN::from_array(env, &bytes)
+}
+
+// Mock VK/proof; proof verification is bypassed in the testutils build
+fn mock_vk(env: &Env) -> VerificationKey {
+    let mut ic = soroban_sdk::Vec::new(env);
+    for _ in 0..6 {
+        ic.push_back(g1(env));
+    }
+    VerificationKey {
+        alpha: g1(env),
+        beta: g2(env),
+        gamma: g2(env),
+        delta: g2(env),
+        ic,
+    }
+}
+
+#[test]
+fn test_council_role_proposes_and_closes_without_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+    let voting = VotingClient::new(&env, &voting_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Council DAO"),
+        &admin,
+        &false,
+        &false,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+    voting.set_vk(&dao_id, &mock_vk(&env), &admin);
+
+    let council = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    sbt.mint(&dao_id, &council, &admin, &None);
+    sbt.mint(&dao_id, &moderator, &admin, &None);
+    sbt.set_role(&dao_id, &council, &ROLE_COUNCIL, &admin);
+    sbt.set_role(&dao_id, &moderator, &ROLE_MODERATOR, &admin);
+
+    registry.set_action_role(&dao_id, &ACTION_PROPOSE, &Some(ROLE_COUNCIL), &admin);
+    registry.set_action_role(&dao_id, &ACTION_CLOSE, &Some(ROLE_COUNCIL), &admin);
+
+    let title = String::from_str(&env, "Budget");
+    let cid = String::from_str(&env, "");
+    let proposal = voting.create_proposal(&dao_id, &title, &cid, &0u64, &council, &VoteMode::Fixed);
+    assert_eq!(
+        voting.try_create_proposal(&dao_id, &title, &cid, &0u64, &moderator, &VoteMode::Fixed),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            VotingError::OnlyAdminCanPropose as u32
+        )))
+    );
+    assert_eq!(
+        voting.try_close_proposal(&dao_id, &proposal, &moderator),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            VotingError::NotAdmin as u32
+        )))
+    );
+
+    // Demoting the council member takes the delegated powers away again
+    voting.close_proposal(&dao_id, &proposal, &council);
+    assert_eq!(
+        voting.get_proposal(&dao_id, &proposal).state,
+        ProposalState::Closed
+    );
+    sbt.set_role(&dao_id, &council, &ROLE_MODERATOR, &admin);
+    assert_eq!(
+        voting.try_archive_proposal(&dao_id, &proposal, &council),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            VotingError::NotAdmin as u32
+        )))
+    );
+    voting.archive_proposal(&dao_id, &proposal, &admin);
+}
@@ -142,12 +142,16 @@ fn test_real_groth16_proof_verification() {
     println!("✅ Registry deployed");
 
     // Deploy SBT
-    let sbt_address = env.register(membership_sbt::MembershipSbt, (registry_address.clone(),));
+    let sbt_address = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_address.clone(), Address::generate(&env)),
+    );
     let sbt_client = MembershipSbtClient::new(&env, &sbt_address);
     println!("✅ SBT deployed");
 
     // Deploy Tree
     let tree_address = env.register(membership_tree::MembershipTree, (sbt_address.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_address).bind_tree(&tree_address);
     let tree_client = MembershipTreeClient::new(&env, &tree_address);
     println!("✅ Tree deployed");
 
@@ -0,0 +1,239 @@
+// Strict Revocation Tests
+//
+// With `enable_strict_revocation`, every commitment zeroed by a removal or a voluntary
+// exit also lands in the DAO's sparse Merkle revocation tree. Strict proposals need the
+// circuit that proves non-revocation against the current revocation root, so members
+// removed after a Fixed-mode snapshot lose their vote immediately.
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, U256};
+
+use comments::CommentsClient;
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::{MembershipTreeClient, REVOCATION_DEPTH};
+use voting::{Proof, VerificationKey, VoteMode, VotingClient, VotingError};
+
+const COUNCIL: u32 = 1;
+
+fn g1(env: &Env) -> BytesN<64> {
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1;
+    bytes[63] = 2;
+    BytesN::from_array(env, &bytes)
+}
+
+fn g2(env: &Env) -> BytesN<128> {
+    let bytes: [u8; 128] = [
+        0x18, 0x00, 0x50, 0x6a, 0x06, 0x12, 0x86, 0xeb, 0x6a, 0x84, 0xa5, 0x73, 0x0b, 0x8f, 0x10,
+        0x29, 0x3e, 0x29, 0x81, 0x6c, 0xd1, 0x91, 0x3d, 0x53, 0x38, 0xf7, 0x15, 0xde, 0x3e, 0x98,
+        0xf9, 0xad, 0x19, 0x83, 0x90, 0x42, 0x11, 0xa5, 0x3f, 0x6e, 0x0b, 0x08, 0x53, 0xa9, 0x0a,
+        0x00, 0xef, 0xbf, 0xf1, 0x70, 0x0c, 0x7b, 0x1d, 0xc0, 0x06, 0x32, 0x4d, 0x85, 0x9d, 0x75,
+        0xe3, 0xca, 0xa5, 0xa2, 0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71,
+        0x8e, 0x80, 0x6a, 0x51, 0xa5, 0x66, 0x08, 0x21, 0x4c, 0x3f, 0x62, 0x8b, 0x96, 0x2c, 0xf1,
+        0x91, 0xea, 0xcd, 0xc8, 0x0e, 0x7a, 0x09, 0x0d, 0x97, 0xc0, 0x9c, 0xe1, 0x48, 0x60, 0x63,
+        0xb3, 0x59, 0xf3, 0xdd, 0x89, 0xb7, 0xc4, 0x3c, 0x5f, 0x18, 0x95, 0x8f, 0xb3, 0xe6, 0xb9,
+        0x6d, 0xb5, 0x5e, 0x19, 0xa3, 0xb7, 0xc0, 0xfb,
+    ];
+    BytesN::from_array(env, &bytes)
+}
+
+// Mock VK/proof; proof verification is bypassed in the testutils build
+fn mock_vk(env: &Env, public_signals: u32) -> VerificationKey {
+    let mut ic = soroban_sdk::Vec::new(env);
+    for _ in 0..=public_signals {
+        ic.push_back(g1(env));
+    }
+    VerificationKey {
+        alpha: g1(env),
+        beta: g2(env),
+        gamma: g2(env),
+        delta: g2(env),
+        ic,
+    }
+}
+
+fn mock_proof(env: &Env) -> Proof {
+    Proof {
+        a: g1(env),
+        b: g2(env),
+        c: g1(env),
+    }
+}
+
+#[test]
+fn test_strict_revocation_excludes_removed_members_immediately() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+    let voting = VotingClient::new(&env, &voting_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Strict DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+    tree.create_group(&dao_id, &COUNCIL, &4, &admin);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let alice_commitment = U256::from_u32(&env, 1111);
+    let alice_council = U256::from_u32(&env, 1112);
+    let bob_commitment = U256::from_u32(&env, 2222);
+    sbt.mint(&dao_id, &admin, &admin, &None);
+    sbt.mint(&dao_id, &alice, &admin, &None);
+    sbt.mint(&dao_id, &bob, &admin, &None);
+    tree.register_with_caller(&dao_id, &alice_commitment, &alice);
+    tree.register_with_caller(&dao_id, &bob_commitment, &bob);
+    tree.add_group_member(&dao_id, &COUNCIL, &alice, &admin);
+    tree.register_in_group(&dao_id, &COUNCIL, &alice_council, &alice);
+
+    // A plain proposal from before strict mode keeps snapshot semantics
+    voting.set_vk(&dao_id, &mock_vk(&env, 5), &admin);
+    let title = String::from_str(&env, "Quarterly budget");
+    let cid = String::from_str(&env, "");
+    let plain = voting.create_proposal(&dao_id, &title, &cid, &0u64, &admin, &VoteMode::Fixed);
+    assert!(!voting.get_strict(&dao_id, &plain));
+
+    // Strict proposals need the circuit with the revocation root signal
+    tree.enable_strict_revocation(&dao_id, &admin);
+    assert_eq!(
+        voting.try_create_proposal(&dao_id, &title, &cid, &0u64, &admin, &VoteMode::Fixed),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            VotingError::VkIcLengthMismatch as u32
+        )))
+    );
+    voting.set_vk(&dao_id, &mock_vk(&env, 6), &admin);
+    let strict = voting.create_proposal(&dao_id, &title, &cid, &0u64, &admin, &VoteMode::Fixed);
+    assert!(voting.get_strict(&dao_id, &strict));
+    let snapshot = voting.get_eligible_root(&dao_id, &strict);
+
+    // Removing Alice revokes both her general and her council commitment
+    tree.remove_member(&dao_id, &alice, &admin);
+    assert_ne!(tree.revocation_root(&dao_id), U256::from_u32(&env, 0));
+    for commitment in [&alice_commitment, &alice_council] {
+        let (siblings, leaf) = tree.revocation_proof(&dao_id, commitment);
+        assert_eq!(leaf.as_ref(), Some(commitment));
+        assert_eq!(siblings.len(), REVOCATION_DEPTH);
+    }
+    let (_, leaf) = tree.revocation_proof(&dao_id, &bob_commitment);
+    assert_ne!(leaf, Some(bob_commitment.clone()));
+
+    // Bob still votes against the Fixed snapshot; his proof covers the new revocation root
+    voting.vote(
+        &dao_id,
+        &strict,
+        &true,
+        &U256::from_u32(&env, 31),
+        &snapshot,
+        &mock_proof(&env),
+    );
+
+    // A voluntary exit is revoked the same way
+    sbt.leave(&dao_id, &bob);
+    let (_, leaf) = tree.revocation_proof(&dao_id, &bob_commitment);
+    assert_eq!(leaf, Some(bob_commitment));
+
+    // Re-enabling Alice's commitment clears it; the council one stays revoked
+    tree.reinstate_member(&dao_id, &alice, &None, &admin);
+    let (_, leaf) = tree.revocation_proof(&dao_id, &alice_commitment);
+    assert_ne!(leaf, Some(alice_commitment));
+    let (_, leaf) = tree.revocation_proof(&dao_id, &alice_council);
+    assert_eq!(leaf, Some(alice_council));
+
+    // Her council seat needs a fresh commitment
+    tree.add_group_member(&dao_id, &COUNCIL, &alice, &admin);
+    tree.register_in_group(&dao_id, &COUNCIL, &U256::from_u32(&env, 1113), &alice);
+}
+
+#[test]
+fn test_comments_follow_the_proposal_strict_snapshot() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+    let comments_id = env.register(
+        comments::Comments,
+        (tree_id.clone(), voting_id.clone(), registry_id.clone()),
+    );
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+    let voting = VotingClient::new(&env, &voting_id);
+    let comments = CommentsClient::new(&env, &comments_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Strict DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+    let alice = Address::generate(&env);
+    let alice_commitment = U256::from_u32(&env, 1111);
+    sbt.mint(&dao_id, &admin, &admin, &None);
+    sbt.mint(&dao_id, &alice, &admin, &None);
+    tree.register_with_caller(&dao_id, &alice_commitment, &alice);
+
+    let proof = comments::Proof {
+        a: g1(&env),
+        b: g2(&env),
+        c: g1(&env),
+    };
+    let comment = |proposal: u64| {
+        comments.add_anonymous_comment(
+            &dao_id,
+            &proposal,
+            &String::from_str(&env, "bafy-comment"),
+            &None,
+            &U256::from_u32(&env, 41),
+            &tree.current_root(&dao_id),
+            &alice_commitment,
+            &true,
+            &proof,
+        )
+    };
+
+    // Turning strict mode on does not change the signals of an earlier proposal
+    voting.set_vk(&dao_id, &mock_vk(&env, 5), &admin);
+    let title = String::from_str(&env, "Quarterly budget");
+    let cid = String::from_str(&env, "");
+    let plain = voting.create_proposal(&dao_id, &title, &cid, &0u64, &admin, &VoteMode::Fixed);
+    tree.enable_strict_revocation(&dao_id, &admin);
+    comment(plain);
+
+    // Nor does the VK rotation strict proposals need
+    voting.set_vk(&dao_id, &mock_vk(&env, 6), &admin);
+    let strict = voting.create_proposal(&dao_id, &title, &cid, &0u64, &admin, &VoteMode::Fixed);
+    comment(plain);
+    comment(strict);
+    assert_eq!(comments.comment_count(&dao_id, &plain), 2);
+    assert_eq!(comments.comment_count(&dao_id, &strict), 1);
+}
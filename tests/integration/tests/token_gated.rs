@@ -0,0 +1,147 @@
+// Token-Gated Membership Tests
+//
+// With the registry's `set_token_gate`, holders of enough of a token can `self_join` a DAO
+// whose membership is closed and then `self_register` their commitment, without the admin.
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String, U256};
+
+use dao_registry::{DaoRegistryClient, TokenGate};
+use membership_sbt::{MembershipSbtClient, SbtError};
+use membership_tree::{MembershipTreeClient, TreeError};
+
+struct Setup<'a> {
+    env: Env,
+    dao_id: u64,
+    admin: Address,
+    token: Address,
+    registry: DaoRegistryClient<'a>,
+    sbt: MembershipSbtClient<'a>,
+    tree: MembershipTreeClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let tree = MembershipTreeClient::new(&env, &tree_id);
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Holders DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    tree.init_tree(&dao_id, &18, &admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    registry.set_token_gate(
+        &dao_id,
+        &Some(TokenGate {
+            token: token.clone(),
+            min_balance: 1_000,
+        }),
+        &admin,
+    );
+
+    Setup {
+        env,
+        dao_id,
+        admin,
+        token,
+        registry,
+        sbt,
+        tree,
+    }
+}
+
+#[test]
+fn test_token_holders_join_and_register_without_admin() {
+    let s = setup();
+    let env = &s.env;
+
+    let holder = Address::generate(env);
+    let minnow = Address::generate(env);
+    token::StellarAssetClient::new(env, &s.token).mint(&holder, &5_000);
+    token::StellarAssetClient::new(env, &s.token).mint(&minnow, &10);
+
+    s.sbt.self_join(&s.dao_id, &holder, &None);
+    let commitment = U256::from_u32(env, 4242);
+    s.tree.self_register(&s.dao_id, &commitment, &holder);
+    assert_eq!(s.tree.get_leaf_index(&s.dao_id, &commitment), 0);
+
+    assert_eq!(
+        s.sbt.try_self_join(&s.dao_id, &minnow, &None),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            SbtError::BelowTokenGate as u32
+        )))
+    );
+
+    // Without the gate, closed membership is admin-curated again
+    s.registry.set_token_gate(&s.dao_id, &None, &s.admin);
+    s.sbt.mint(&s.dao_id, &minnow, &s.admin, &None);
+    assert_eq!(
+        s.tree
+            .try_self_register(&s.dao_id, &U256::from_u32(env, 7), &minnow),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            TreeError::NotOpenMembership as u32
+        )))
+    );
+}
+
+#[test]
+fn test_recycled_balance_loses_earlier_memberships() {
+    let s = setup();
+    let env = &s.env;
+    let token = token::Client::new(env, &s.token);
+
+    // One balance passed on after each join admits a second address
+    let first = Address::generate(env);
+    let second = Address::generate(env);
+    token::StellarAssetClient::new(env, &s.token).mint(&first, &1_000);
+    s.sbt.self_join(&s.dao_id, &first, &None);
+    s.tree
+        .self_register(&s.dao_id, &U256::from_u32(env, 11), &first);
+    token.transfer(&first, &second, &1_000);
+    s.sbt.self_join(&s.dao_id, &second, &None);
+
+    // Anyone can revoke the emptied address; its leaf is zeroed and older roots rejected
+    s.sbt.revoke_below_gate(&s.dao_id, &first);
+    assert!(!s.sbt.has(&s.dao_id, &first));
+    assert_eq!(s.tree.get_tree_usage(&s.dao_id).1, 0);
+    assert_eq!(s.tree.min_root(&s.dao_id), s.tree.curr_idx(&s.dao_id));
+    assert_eq!(
+        s.sbt.try_revoke_below_gate(&s.dao_id, &second),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            SbtError::NotBelowTokenGate as u32
+        )))
+    );
+
+    // Members the admin minted are not held to the gate
+    let minted = Address::generate(env);
+    s.sbt.mint(&s.dao_id, &minted, &s.admin, &None);
+    assert_eq!(
+        s.sbt.try_revoke_below_gate(&s.dao_id, &minted),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            SbtError::NotBelowTokenGate as u32
+        )))
+    );
+
+    // Topping up again readmits the revoked address
+    token.transfer(&second, &first, &1_000);
+    s.sbt.self_join(&s.dao_id, &first, &None);
+    assert!(s.sbt.has(&s.dao_id, &first));
+}
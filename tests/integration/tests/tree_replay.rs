@@ -0,0 +1,245 @@
+// Tree Replay Differential Tests
+//
+// Drives the membership tree through inserts, removals, slot refills, groups,
+// reinstatement, growth and expiry renewal, replaying its events with
+// zkvote-tree-replay after every call. Roots, root indices and Merkle paths
+// (current and historical) must match the contract exactly.
+
+use soroban_sdk::{
+    testutils::Address as _, testutils::Events as _, Address, BytesN, Env, String, U256,
+};
+
+use dao_registry::DaoRegistryClient;
+use membership_sbt::MembershipSbtClient;
+use membership_tree::{MembershipTreeClient, GENERAL_GROUP};
+use voting::{VerificationKey, VotingClient};
+use zkvote_tree_replay::{TreeEvent, TreeReplay};
+
+const COUNCIL: u32 = 1;
+
+fn g1(env: &Env) -> BytesN<64> {
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1;
+    bytes[63] = 2;
+    BytesN::from_array(env, &bytes)
+}
+
+fn g2(env: &Env) -> BytesN<128> {
+    let bytes: [u8; 128] = [
+        0x18, 0x00, 0x50, 0x6a, 0x06, 0x12, 0x86, 0xeb, 0x6a, 0x84, 0xa5, 0x73, 0x0b, 0x8f, 0x10,
+        0x29, 0x3e, 0x29, 0x81, 0x6c, 0xd1, 0x91, 0x3d, 0x53, 0x38, 0xf7, 0x15, 0xde, 0x3e, 0x98,
+        0xf9, 0xad, 0x19, 0x83, 0x90, 0x42, 0x11, 0xa5, 0x3f, 0x6e, 0x0b, 0x08, 0x53, 0xa9, 0x0a,
+        0x00, 0xef, 0xbf, 0xf1, 0x70, 0x0c, 0x7b, 0x1d, 0xc0, 0x06, 0x32, 0x4d, 0x85, 0x9d, 0x75,
+        0xe3, 0xca, 0xa5, 0xa2, 0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71,
+        0x8e, 0x80, 0x6a, 0x51, 0xa5, 0x66, 0x08, 0x21, 0x4c, 0x3f, 0x62, 0x8b, 0x96, 0x2c, 0xf1,
+        0x91, 0xea, 0xcd, 0xc8, 0x0e, 0x7a, 0x09, 0x0d, 0x97, 0xc0, 0x9c, 0xe1, 0x48, 0x60, 0x63,
+        0xb3, 0x59, 0xf3, 0xdd, 0x89, 0xb7, 0xc4, 0x3c, 0x5f, 0x18, 0x95, 0x8f, 0xb3, 0xe6, 0xb9,
+        0x6d, 0xb5, 0x5e, 0x19, 0xa3, 0xb7, 0xc0, 0xfb,
+    ];
+    BytesN::from_array(env, &bytes)
+}
+
+// Mock VK; only its presence for the grown depth matters here
+fn mock_vk(env: &Env) -> VerificationKey {
+    VerificationKey {
+        alpha: g1(env),
+        beta: g2(env),
+        gamma: g2(env),
+        delta: g2(env),
+        ic: soroban_sdk::vec![env, g1(env), g1(env), g1(env), g1(env), g1(env), g1(env)],
+    }
+}
+
+struct Harness<'a> {
+    env: Env,
+    tree_id: Address,
+    tree: MembershipTreeClient<'a>,
+    replay: TreeReplay,
+}
+
+impl Harness<'_> {
+    // Feed the tree events of the last invocation into the replay
+    fn sync(&mut self) {
+        for (contract, topics, data) in self.env.events().all().iter() {
+            if contract != self.tree_id {
+                continue;
+            }
+            if let Some(event) = TreeEvent::decode(&self.env, &topics, &data) {
+                self.replay
+                    .apply(&event)
+                    .unwrap_or_else(|e| panic!("replay rejected {:?}: {:?}", event, e));
+            }
+        }
+    }
+
+    // Sync, then compare the replayed tree with the contract's views
+    fn check(&mut self, dao_id: u64, group_id: u32) {
+        self.sync();
+        self.compare(dao_id, group_id);
+    }
+
+    fn compare(&self, dao_id: u64, group_id: u32) {
+        let (depth, _, root) = self.tree.group_info(&dao_id, &group_id);
+        let (next_index, _, _) = self.tree.group_usage(&dao_id, &group_id);
+        assert_eq!(self.replay.root(dao_id, group_id), Some(root));
+        assert_eq!(self.replay.depth(dao_id, group_id), Some(depth));
+        assert_eq!(
+            self.replay.root_index(dao_id, group_id),
+            Some(self.tree.group_curr_idx(&dao_id, &group_id))
+        );
+        for index in 0..next_index {
+            assert_eq!(
+                self.replay.path(dao_id, group_id, index),
+                Ok(self.tree.group_path(&dao_id, &group_id, &index)),
+                "path of leaf {}",
+                index
+            );
+        }
+    }
+}
+
+#[test]
+fn test_replay_matches_contract_through_membership_churn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+    let voting_id = env.register(voting::Voting, (tree_id.clone(), registry_id.clone()));
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let voting = VotingClient::new(&env, &voting_id);
+    let mut h = Harness {
+        env: env.clone(),
+        tree_id: tree_id.clone(),
+        tree: MembershipTreeClient::new(&env, &tree_id),
+        replay: TreeReplay::new(&env),
+    };
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Replay DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    h.tree.init_tree(&dao_id, &2, &admin);
+    h.check(dao_id, GENERAL_GROUP);
+
+    let members: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&env)).collect();
+    let commitments: std::vec::Vec<U256> =
+        (0..5u32).map(|i| U256::from_u32(&env, 1000 + i)).collect();
+    for member in &members {
+        sbt.mint(&dao_id, member, &admin, &None);
+    }
+
+    for i in 0..3 {
+        h.tree
+            .register_with_caller(&dao_id, &commitments[i], &members[i]);
+        h.check(dao_id, GENERAL_GROUP);
+    }
+    let snapshot_root = h.tree.current_root(&dao_id);
+
+    // Removal, then a refill of the zeroed slot
+    h.tree.remove_member(&dao_id, &members[1], &admin);
+    h.check(dao_id, GENERAL_GROUP);
+    h.tree.set_slot_reuse(&dao_id, &true, &admin);
+    h.tree
+        .register_with_caller(&dao_id, &commitments[3], &members[3]);
+    h.check(dao_id, GENERAL_GROUP);
+    assert_eq!(
+        h.replay.leaf_index(dao_id, GENERAL_GROUP, &commitments[3]),
+        Some(1)
+    );
+
+    // Group tree replayed independently
+    h.tree.create_group(&dao_id, &COUNCIL, &2, &admin);
+    h.check(dao_id, COUNCIL);
+    h.tree
+        .add_group_member(&dao_id, &COUNCIL, &members[0], &admin);
+    h.tree
+        .register_in_group(&dao_id, &COUNCIL, &commitments[0], &members[0]);
+    h.check(dao_id, COUNCIL);
+
+    // Reinstatement fills the last slot, growth makes room for more
+    h.tree.reinstate_member(&dao_id, &members[1], &None, &admin);
+    h.check(dao_id, GENERAL_GROUP);
+    voting.set_depth_vk(&dao_id, &3, &mock_vk(&env), &admin);
+    h.tree.grow_tree(&dao_id, &3, &voting_id, &admin);
+    h.check(dao_id, GENERAL_GROUP);
+    h.tree
+        .register_with_caller(&dao_id, &commitments[4], &members[4]);
+    h.check(dao_id, GENERAL_GROUP);
+
+    // Voluntary exit and an admin removal cascading into the group
+    sbt.leave(&dao_id, &members[2]);
+    h.check(dao_id, GENERAL_GROUP);
+    h.tree.remove_member(&dao_id, &members[0], &admin);
+    h.check(dao_id, GENERAL_GROUP);
+    h.compare(dao_id, COUNCIL);
+
+    // Historical paths match the contract's reconstruction at an old root
+    for index in 0..3u64 {
+        assert_eq!(
+            h.replay
+                .path_at(dao_id, GENERAL_GROUP, index, &snapshot_root),
+            Ok(h.tree.get_merkle_path_at(&dao_id, &index, &snapshot_root)),
+            "historical path of leaf {}",
+            index
+        );
+    }
+}
+
+#[test]
+fn test_replay_matches_expiring_leaves_and_renewal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let registry_id = env.register(dao_registry::DaoRegistry, ());
+    let sbt_id = env.register(
+        membership_sbt::MembershipSbt,
+        (registry_id.clone(), Address::generate(&env)),
+    );
+    let tree_id = env.register(membership_tree::MembershipTree, (sbt_id.clone(),));
+    membership_sbt::MembershipSbtClient::new(&env, &sbt_id).bind_tree(&tree_id);
+
+    let registry = DaoRegistryClient::new(&env, &registry_id);
+    let sbt = MembershipSbtClient::new(&env, &sbt_id);
+    let mut h = Harness {
+        env: env.clone(),
+        tree_id: tree_id.clone(),
+        tree: MembershipTreeClient::new(&env, &tree_id),
+        replay: TreeReplay::new(&env),
+    };
+
+    let admin = Address::generate(&env);
+    let dao_id = registry.create_dao(
+        &String::from_str(&env, "Expiring Replay DAO"),
+        &admin,
+        &false,
+        &true,
+        &None,
+    );
+    h.tree.init_expiring_tree(&dao_id, &4, &admin);
+    h.check(dao_id, GENERAL_GROUP);
+
+    sbt.set_term(&dao_id, &3600, &admin);
+    let member = Address::generate(&env);
+    sbt.mint(&dao_id, &member, &admin, &None);
+    h.tree
+        .register_with_caller(&dao_id, &U256::from_u32(&env, 77), &member);
+    h.check(dao_id, GENERAL_GROUP);
+
+    sbt.renew(&dao_id, &member, &7200, &admin);
+    h.tree.renew_leaf(&dao_id, &member);
+    h.check(dao_id, GENERAL_GROUP);
+}